@@ -111,7 +111,13 @@ fn print_traces(tracer: TracingInspector, decoder: Arc<CallTraceDecoder>) {
         })
     });
 
-    let traces = SparsedTraceArena { arena, ignored: Default::default() };
+    let traces = SparsedTraceArena {
+        arena,
+        ignored: Default::default(),
+        zk_pubdata: Default::default(),
+        zk_steps: Default::default(),
+        zk_frames: Default::default(),
+    };
     let trace = render_trace_arena_inner(&traces, false, true);
     node_info!(Traces = %format!("\n{}", trace));
 }
@@ -239,7 +239,7 @@ pub async fn run(self) -> Result<()> {
         let block_overrides = self.get_block_overrides()?;
         config.zksync.compile = is_zk;
 
-        let mut strategy = utils::get_executor_strategy(&config);
+        let mut strategy = utils::get_executor_strategy(&config)?;
 
         let Self {
             to,
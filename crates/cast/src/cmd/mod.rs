@@ -27,3 +27,5 @@
 pub mod trace;
 pub mod txpool;
 pub mod wallet;
+pub mod zk;
+pub mod zks;
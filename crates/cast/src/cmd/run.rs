@@ -146,7 +146,7 @@ pub async fn run(self) -> Result<()> {
         config.zksync.compile = self.zk_force;
         config.zksync.evm_interpreter = self.zk_evm_interpreter;
         config.no_storage_caching = self.no_storage_caching;
-        let strategy = utils::get_executor_strategy(&config);
+        let strategy = utils::get_executor_strategy(&config)?;
 
         let label = self.label;
         let with_local_artifacts = self.with_local_artifacts;
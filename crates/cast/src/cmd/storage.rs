@@ -31,9 +31,15 @@
     figment::{self, Metadata, Profile, value::Dict},
     impl_figment_convert_cast,
 };
+use foundry_zksync_core::{
+    ACCOUNT_CODE_STORAGE_ADDRESS, IMMUTABLE_SIMULATOR_STORAGE_ADDRESS, L2_BASE_TOKEN_ADDRESS,
+    NONCE_HOLDER_ADDRESS,
+    convert::{ConvertH160, ConvertH256, ConvertRU256, ConvertU256},
+};
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
+use zksync_types::utils::decompose_full_nonce;
 
 /// The minimum Solc version for outputting storage layouts.
 ///
@@ -77,6 +83,20 @@ pub struct StorageArgs {
     /// Specify the solc version to compile with. Overrides detected version.
     #[arg(long, value_parser = Version::parse)]
     solc_version: Option<Version>,
+
+    /// Read the ZKsync system storage of the address instead of its storage layout: its nonces,
+    /// code hash and balance, along with the immutables requested with `--immutable`.
+    #[arg(long, conflicts_with_all = ["base_slot", "proxy"])]
+    zksync: bool,
+
+    /// The index of an immutable to read from the `ImmutableSimulator` system contract.
+    #[arg(
+        long = "immutable",
+        value_name = "INDEX",
+        value_parser = str::parse::<U256>,
+        requires = "zksync"
+    )]
+    immutables: Vec<U256>,
 }
 
 impl_figment_convert_cast!(StorageArgs);
@@ -103,6 +123,11 @@ pub async fn run(self) -> Result<()> {
         let provider = utils::get_provider(&config)?;
         let address = address.resolve(&provider).await?;
 
+        if self.zksync {
+            return fetch_and_print_zksync_storage(provider, address, block, &self.immutables)
+                .await;
+        }
+
         // Slot was provided, perform a simple RPC call
         if let Some(slot) = base_slot {
             let cast = Cast::new(provider);
@@ -371,6 +396,98 @@ fn print_storage(layout: StorageLayout, values: Vec<StorageValue>) -> Result<()>
     Ok(())
 }
 
+/// A value of an account held in the storage of a ZKsync system contract.
+#[derive(Clone, Debug, Serialize)]
+struct ZkSystemStorageValue {
+    name: String,
+    contract: Address,
+    slot: B256,
+    value: U256,
+}
+
+async fn fetch_and_print_zksync_storage<P: Provider<AnyNetwork>>(
+    provider: P,
+    address: Address,
+    block: Option<BlockId>,
+    immutables: &[U256],
+) -> Result<()> {
+    let nonce_holder = NONCE_HOLDER_ADDRESS.to_address();
+    let account_code_storage = ACCOUNT_CODE_STORAGE_ADDRESS.to_address();
+    let base_token = L2_BASE_TOKEN_ADDRESS.to_address();
+    let immutable_simulator = IMMUTABLE_SIMULATOR_STORAGE_ADDRESS.to_address();
+
+    let mut slots = vec![
+        ("nonce".to_string(), nonce_holder, foundry_zksync_core::get_nonce_key(address)),
+        (
+            "codeHash".to_string(),
+            account_code_storage,
+            foundry_zksync_core::get_account_code_key(address),
+        ),
+        ("balance".to_string(), base_token, foundry_zksync_core::get_balance_key(address)),
+    ];
+    slots.extend(immutables.iter().map(|&index| {
+        let key = foundry_zksync_core::get_immutable_slot_key(address, index).to_ru256();
+        (format!("immutable[{index}]"), immutable_simulator, key)
+    }));
+
+    let requests = slots.into_iter().map(|(name, contract, slot)| {
+        let provider = &provider;
+        async move {
+            let value =
+                provider.get_storage_at(contract, slot).block_id(block.unwrap_or_default()).await?;
+            eyre::Ok(ZkSystemStorageValue { name, contract, slot: slot.into(), value })
+        }
+    });
+    let mut values = futures::future::try_join_all(requests).await?;
+
+    // The nonce holder packs the deployment nonce in the upper 128 bits of the full nonce.
+    let full_nonce = values.remove(0);
+    let (tx_nonce, deployment_nonce) = decompose_full_nonce(full_nonce.value.to_u256());
+    let nonces =
+        [("txNonce", tx_nonce), ("deploymentNonce", deployment_nonce)].map(|(name, nonce)| {
+            ZkSystemStorageValue {
+                name: name.to_string(),
+                value: nonce.to_ru256(),
+                ..full_nonce.clone()
+            }
+        });
+    values.splice(0..0, nonces);
+
+    if shell::is_json() {
+        sh_println!("{}", serde_json::to_string_pretty(&values)?)?;
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    if shell::is_markdown() {
+        table.load_preset(ASCII_MARKDOWN);
+    } else {
+        table.apply_modifier(UTF8_ROUND_CORNERS);
+    }
+
+    table.set_header(vec![
+        Cell::new("Name"),
+        Cell::new("Contract"),
+        Cell::new("Slot"),
+        Cell::new("Value"),
+        Cell::new("Hex Value"),
+    ]);
+
+    for ZkSystemStorageValue { name, contract, slot, value } in values {
+        table.add_row([
+            name,
+            contract.to_string(),
+            slot.to_string(),
+            value.to_string(),
+            B256::from(value).to_string(),
+        ]);
+    }
+
+    sh_println!("\n{table}\n")?;
+
+    Ok(())
+}
+
 fn add_storage_layout_output<C: Compiler<CompilerContract = Contract>>(project: &mut Project<C>) {
     project.artifacts.additional_values.storage_layout = true;
     project.update_output_selection(|selection| {
@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use alloy_network::{AnyNetwork, EthereumWallet, TransactionBuilder};
+use alloy_primitives::{Address, B256, U256};
+use alloy_provider::{Provider, ProviderBuilder};
+use alloy_rpc_types::TransactionRequest;
+use alloy_serde::WithOtherFields;
+use alloy_signer::Signer;
+use alloy_zksync::{
+    network::{Zksync, transaction_request::TransactionRequest as ZkTransactionRequest},
+    wallet::ZksyncWallet,
+};
+use clap::Parser;
+use eyre::Result;
+use foundry_cli::{
+    opts::EthereumOpts,
+    utils::{self, LoadConfig},
+};
+use foundry_common::provider::RetryProvider;
+use foundry_wallets::WalletSigner;
+use foundry_zksync_core::bridge::{self, BridgeTransaction, DepositRequest};
+
+/// CLI arguments for `cast zk`.
+#[derive(Debug, Parser)]
+pub enum ZkSubcommands {
+    /// Deposit the base token from L1 to an account on L2.
+    Deposit {
+        /// The L2 recipient of the deposit.
+        to: Address,
+        /// The amount to deposit, in wei or with a unit (e.g. `1ether`).
+        #[arg(value_parser = utils::parse_ether_value)]
+        amount: U256,
+        /// The RPC endpoint of the L1 the chain settles on.
+        #[arg(long, value_name = "URL")]
+        l1_rpc_url: String,
+        /// The L2 gas limit of the deposit, estimated if not provided.
+        #[arg(long)]
+        l2_gas_limit: Option<U256>,
+        /// The L2 account refunded with the unused deposit fee, defaults to the sender.
+        #[arg(long)]
+        refund_recipient: Option<Address>,
+        #[command(flatten)]
+        eth: EthereumOpts,
+    },
+    /// Withdraw the base token from L2 to an account on L1.
+    Withdraw {
+        /// The L1 recipient of the withdrawal.
+        to: Address,
+        /// The amount to withdraw, in wei or with a unit (e.g. `1ether`).
+        #[arg(value_parser = utils::parse_ether_value)]
+        amount: U256,
+        #[command(flatten)]
+        eth: EthereumOpts,
+    },
+    /// Finalize a withdrawal on L1, once the L1 batch that includes it has been executed.
+    #[command(visible_alias = "finalize")]
+    FinalizeWithdrawal {
+        /// The hash of the L2 transaction that initiated the withdrawal.
+        tx_hash: B256,
+        /// The index of the withdrawal in the transaction.
+        #[arg(long, default_value_t = 0)]
+        index: usize,
+        /// The RPC endpoint of the L1 the chain settles on.
+        #[arg(long, value_name = "URL")]
+        l1_rpc_url: String,
+        #[command(flatten)]
+        eth: EthereumOpts,
+    },
+}
+
+impl ZkSubcommands {
+    pub async fn run(self) -> Result<()> {
+        match self {
+            Self::Deposit { to, amount, l1_rpc_url, l2_gas_limit, refund_recipient, eth } => {
+                let l2_provider = utils::get_provider_zksync(&eth.load_config()?)?;
+                let l1_provider =
+                    foundry_common::provider::ProviderBuilder::<AnyNetwork>::new(&l1_rpc_url)
+                        .build()?;
+
+                let signer = eth.wallet.signer().await?;
+                let request = DepositRequest {
+                    from: signer.address(),
+                    to,
+                    amount,
+                    l2_gas_limit,
+                    refund_recipient,
+                };
+                let tx = bridge::deposit_transaction(&l1_provider, &l2_provider, &request).await?;
+
+                send_l1_transaction(&l1_provider, signer, tx).await?;
+            }
+            Self::Withdraw { to, amount, eth } => {
+                let l2_provider = utils::get_provider_zksync(&eth.load_config()?)?;
+
+                let signer = eth.wallet.signer().await?;
+                let from = signer.address();
+                let BridgeTransaction { to, value, data, .. } =
+                    bridge::withdraw_transaction(to, amount);
+
+                let zk_provider = ProviderBuilder::<_, _, Zksync>::default()
+                    .wallet(ZksyncWallet::from(Arc::new(signer)))
+                    .connect_provider(&l2_provider);
+                let mut tx = ZkTransactionRequest::default()
+                    .with_from(from)
+                    .with_to(to)
+                    .with_value(value)
+                    .with_input(data);
+                foundry_zksync_core::estimate_fee(&mut tx, &zk_provider, 130, None).await?;
+
+                let pending_tx = zk_provider.send_transaction(tx).await?;
+                sh_println!("{:#x}", pending_tx.tx_hash())?;
+            }
+            Self::FinalizeWithdrawal { tx_hash, index, l1_rpc_url, eth } => {
+                let l2_provider = utils::get_provider_zksync(&eth.load_config()?)?;
+                let l1_provider =
+                    foundry_common::provider::ProviderBuilder::<AnyNetwork>::new(&l1_rpc_url)
+                        .build()?;
+
+                let tx =
+                    bridge::finalize_withdrawal_transaction(&l2_provider, tx_hash, index).await?;
+
+                let signer = eth.wallet.signer().await?;
+                send_l1_transaction(&l1_provider, signer, tx).await?;
+            }
+        };
+
+        Ok(())
+    }
+}
+
+/// Signs and sends a bridge transaction on L1, printing its hash.
+async fn send_l1_transaction(
+    provider: &RetryProvider,
+    signer: WalletSigner,
+    tx: BridgeTransaction,
+) -> Result<()> {
+    let from = signer.address();
+    let provider = ProviderBuilder::<_, _, AnyNetwork>::default()
+        .wallet(EthereumWallet::from(signer))
+        .connect_provider(provider);
+
+    let mut request = TransactionRequest::default()
+        .with_from(from)
+        .with_to(tx.to)
+        .with_value(tx.value)
+        .with_input(tx.data);
+    if let Some(gas_price) = tx.gas_price {
+        request.set_gas_price(gas_price);
+    }
+    let pending_tx = provider.send_transaction(WithOtherFields::new(request)).await?;
+    sh_println!("{:#x}", pending_tx.tx_hash())?;
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use alloy_network::TransactionBuilder;
+use alloy_primitives::{Address, B256, Bytes, U256};
+use alloy_rpc_types::TransactionRequest;
+use alloy_serde::{OtherFields, WithOtherFields};
+use alloy_zksync::provider::ZksyncProvider;
+use clap::Parser;
+use eyre::Result;
+use foundry_cli::{
+    opts::{RpcOpts, ZkTransactionOpts},
+    utils::{self, LoadConfig},
+};
+use foundry_common::{
+    fmt::{EthValue, UIfmt},
+    shell,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::Cast;
+
+/// CLI arguments for `cast zks`.
+#[derive(Debug, Parser, Clone)]
+pub enum ZksSubcommands {
+    /// Get the details of a block, including its L1 batch and commit/prove/execute status.
+    #[command(visible_alias = "bd")]
+    BlockDetails {
+        /// The block number.
+        block: u64,
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+    /// Get the details of a transaction, including its status on L1.
+    #[command(visible_alias = "td")]
+    TxDetails {
+        /// The transaction hash.
+        tx_hash: B256,
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+    /// Get the addresses of the default bridges between L1 and L2.
+    #[command(visible_alias = "bc")]
+    BridgeContracts {
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+    /// Get the proof for an L2 to L1 log sent in a transaction.
+    #[command(visible_alias = "lp")]
+    L2ToL1LogProof {
+        /// The transaction hash.
+        tx_hash: B256,
+        /// The index of the L2 to L1 log in the transaction.
+        #[arg(long)]
+        index: Option<usize>,
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+    /// Get the bytecode stored under a bytecode hash.
+    #[command(visible_alias = "bh")]
+    BytecodeByHash {
+        /// The bytecode hash.
+        hash: B256,
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+    /// Get the current fee parameters of the node.
+    #[command(visible_alias = "fp")]
+    FeeParams {
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+    /// Estimate the fee of a transaction.
+    #[command(visible_alias = "ef")]
+    EstimateFee {
+        /// The destination of the transaction.
+        to: Address,
+        /// The signature of the function to call.
+        sig: Option<String>,
+        /// The arguments of the function to call.
+        #[arg(allow_negative_numbers = true)]
+        args: Vec<String>,
+        /// The sender of the transaction.
+        #[arg(long)]
+        from: Option<Address>,
+        /// Ether to send in the transaction.
+        #[arg(long, value_parser = foundry_cli::utils::parse_ether_value)]
+        value: Option<U256>,
+        #[command(flatten)]
+        zk_tx: ZkTransactionOpts,
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+    /// Get the transactions of a block as they were executed by the node.
+    #[command(visible_alias = "rbt")]
+    RawBlockTransactions {
+        /// The block number.
+        block: u64,
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+}
+
+impl ZksSubcommands {
+    pub async fn run(self) -> Result<()> {
+        match self {
+            Self::BlockDetails { block, rpc } => {
+                let provider = utils::get_provider_zksync(&rpc.load_config()?)?;
+                print_response(&provider.get_block_details(block).await?)?;
+            }
+            Self::TxDetails { tx_hash, rpc } => {
+                let provider = utils::get_provider_zksync(&rpc.load_config()?)?;
+                print_response(&provider.get_transaction_details(tx_hash).await?)?;
+            }
+            Self::BridgeContracts { rpc } => {
+                let provider = utils::get_provider_zksync(&rpc.load_config()?)?;
+                print_response(&provider.get_bridge_contracts().await?)?;
+            }
+            Self::L2ToL1LogProof { tx_hash, index, rpc } => {
+                let provider = utils::get_provider_zksync(&rpc.load_config()?)?;
+                print_response(&provider.get_l2_to_l1_log_proof(tx_hash, index).await?)?;
+            }
+            Self::BytecodeByHash { hash, rpc } => {
+                let provider = utils::get_provider_zksync(&rpc.load_config()?)?;
+                print_response(&provider.get_bytecode_by_hash(hash).await?)?;
+            }
+            Self::FeeParams { rpc } => {
+                let provider = utils::get_provider_zksync(&rpc.load_config()?)?;
+                print_response(&provider.get_fee_params().await?)?;
+            }
+            Self::EstimateFee { to, sig, args, from, value, zk_tx, rpc } => {
+                let provider = utils::get_provider_zksync(&rpc.load_config()?)?;
+
+                let mut tx = TransactionRequest::default().with_to(to);
+                if let Some(sig) = sig {
+                    tx.set_input(Cast::calldata_encode(sig, &args)?.parse::<Bytes>()?);
+                }
+                if let Some(from) = from {
+                    tx.set_from(from);
+                }
+                if let Some(value) = value {
+                    tx.set_value(value);
+                }
+                let tx = crate::zksync::build_zk_tx(&zk_tx, WithOtherFields::new(tx), None)?;
+
+                print_response(&ZksyncProvider::estimate_fee(&provider, tx).await?)?;
+            }
+            Self::RawBlockTransactions { block, rpc } => {
+                let provider = utils::get_provider_zksync(&rpc.load_config()?)?;
+                print_response(&provider.get_raw_block_transactions(block).await?)?;
+            }
+        };
+
+        Ok(())
+    }
+}
+
+/// Prints an RPC response, as JSON with `--json` or in the same layout as other `cast` RPC
+/// outputs otherwise.
+fn print_response(response: &impl Serialize) -> Result<()> {
+    if shell::is_json() {
+        sh_println!("{}", serde_json::to_string_pretty(response)?)?;
+    } else {
+        sh_println!("{}", pretty_value(serde_json::to_value(response)?))?;
+    }
+    Ok(())
+}
+
+fn pretty_value(value: Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Object(fields) => {
+            OtherFields::new(fields.into_iter().collect()).pretty().trim().to_string()
+        }
+        Value::Array(items) => items.into_iter().map(pretty_value).collect::<Vec<_>>().join("\n\n"),
+        value => EthValue::from(value).pretty(),
+    }
+}
@@ -4,7 +4,8 @@
     creation_code::CreationCodeArgs, da_estimate::DAEstimateArgs, erc20::Erc20Subcommand,
     estimate::EstimateArgs, find_block::FindBlockArgs, interface::InterfaceArgs, logs::LogsArgs,
     mktx::MakeTxArgs, rpc::RpcArgs, run::RunArgs, send::SendTxArgs, storage::StorageArgs,
-    trace::TraceArgs, txpool::TxPoolSubcommands, wallet::WalletSubcommands,
+    trace::TraceArgs, txpool::TxPoolSubcommands, wallet::WalletSubcommands, zk::ZkSubcommands,
+    zks::ZksSubcommands,
 };
 use alloy_ens::NameOrAddress;
 use alloy_primitives::{Address, B256, Selector, U256};
@@ -1151,6 +1152,20 @@ pub enum CastSubcommand {
     },
     #[command(name = "trace")]
     Trace(TraceArgs),
+
+    /// Bridge funds between L1 and a ZKsync chain.
+    #[command(name = "zk")]
+    Zk {
+        #[command(subcommand)]
+        command: ZkSubcommands,
+    },
+
+    /// ZKsync-specific RPC methods.
+    #[command(name = "zks")]
+    Zks {
+        #[command(subcommand)]
+        command: ZksSubcommands,
+    },
 }
 
 impl CastSubcommand {
@@ -1271,6 +1286,8 @@ pub fn get_telemetry_props(&self) -> TelemetryProps {
             Self::AbiEncodeEvent { sig: _, args: _ } => "abi-encode-event",
             Self::Erc20Token { command: _ } => "erc20-token",
             Self::Trace(_) => "trace",
+            Self::Zk { command: _ } => "zk",
+            Self::Zks { command: _ } => "zks",
         };
         TelemetryProps::new().insert("command", Some(command_name)).take()
     }
@@ -516,6 +516,162 @@
 "#]]);
 });
 
+casttest!(test_zk_cast_zks_subcommands, async |_prj, cmd| {
+    let node = ZkSyncNode::start().await;
+    let url = node.url();
+
+    let output = cmd
+        .cast_fuse()
+        .args(["zks", "block-details", "0", "--rpc-url", &url])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    assert!(output.contains("l1BatchNumber"), "unexpected output:\n{output}");
+
+    let output = cmd
+        .cast_fuse()
+        .args(["--json", "zks", "fee-params", "--rpc-url", &url])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let fee_params: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert!(fee_params.is_object(), "unexpected output:\n{output}");
+
+    let (addr, private_key) = ZkSyncNode::rich_wallets()
+        .next()
+        .map(|(addr, pk, _)| (addr, pk))
+        .expect("No rich wallets available");
+    let base_token = L2_BASE_TOKEN_ADDRESS.to_address();
+
+    let output = cmd
+        .cast_fuse()
+        .args([
+            "--json",
+            "zks",
+            "estimate-fee",
+            base_token.to_checksum(None).as_str(),
+            "balanceOf(uint256)",
+            addr,
+            "--from",
+            addr,
+            "--rpc-url",
+            &url,
+        ])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let fee: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert!(fee["gas_limit"].is_string(), "{output}");
+
+    let output = cmd
+        .cast_fuse()
+        .args(["--json", "zks", "bridge-contracts", "--rpc-url", &url])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let bridges: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert!(bridges.is_object(), "unexpected output:\n{output}");
+
+    // The bytecode stored under the code hash of a system contract is its deployed code.
+    let code_hash = cmd
+        .cast_fuse()
+        .args([
+            "call",
+            "0x0000000000000000000000000000000000008002",
+            "getRawCodeHash(address)(bytes32)",
+            &base_token.to_checksum(None),
+            "--rpc-url",
+            &url,
+        ])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let code = cmd
+        .cast_fuse()
+        .args(["code", &base_token.to_checksum(None), "--rpc-url", &url])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let output = cmd
+        .cast_fuse()
+        .args(["zks", "bytecode-by-hash", code_hash.trim(), "--rpc-url", &url])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    assert_eq!(output.trim(), code.trim());
+
+    // A withdrawal sends an L2 to L1 message, which can be proven.
+    let tx_hash = cmd
+        .cast_fuse()
+        .args(["zk", "withdraw", addr, "1ether", "--rpc-url", &url, "--private-key", private_key])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let tx_hash = tx_hash.trim();
+
+    // Waits for the withdrawal to be included.
+    let block = cmd
+        .cast_fuse()
+        .args(["receipt", tx_hash, "blockNumber", "--rpc-url", &url])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+
+    let output = cmd
+        .cast_fuse()
+        .args(["--json", "zks", "tx-details", tx_hash, "--rpc-url", &url])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let details: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(details["isL1Originated"], false, "{output}");
+    assert!(details["initiatorAddress"].as_str().unwrap().eq_ignore_ascii_case(addr), "{output}");
+
+    let output = cmd
+        .cast_fuse()
+        .args(["--json", "zks", "raw-block-transactions", block.trim(), "--rpc-url", &url])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let transactions: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(transactions.as_array().unwrap().len(), 1, "{output}");
+
+    let output = cmd
+        .cast_fuse()
+        .args(["--json", "zks", "l2-to-l1-log-proof", tx_hash, "--rpc-url", &url])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let proof: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert!(!proof["proof"].as_array().unwrap().is_empty(), "{output}");
+    assert!(proof["root"].is_string(), "{output}");
+});
+
+casttest!(test_zk_cast_zk_withdraw, async |_prj, cmd| {
+    let node = ZkSyncNode::start().await;
+    let url = node.url();
+
+    let (addr, private_key) = ZkSyncNode::rich_wallets()
+        .next()
+        .map(|(addr, pk, _)| (addr, pk))
+        .expect("No rich wallets available");
+
+    let tx_hash = cmd
+        .cast_fuse()
+        .args(["zk", "withdraw", addr, "1ether", "--rpc-url", &url, "--private-key", private_key])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+
+    cmd.cast_fuse()
+        .args(["receipt", tx_hash.trim(), "status", "--rpc-url", &url])
+        .assert_success()
+        .stdout_eq(str![[r#"
+1 (success)
+
+"#]]);
+});
+
 casttest!(test_zk_cast_custom_signature, async |prj, cmd| {
     util::initialize(prj.root());
     prj.add_script("DeployAA.s.sol", include_str!("../fixtures/zk/DeployAA.s.sol"));
@@ -966,3 +1122,45 @@
         "trace mismatch, got output:\n{output}"
     );
 });
+
+casttest!(test_zk_cast_storage_system_values, async |_prj, cmd| {
+    let node = ZkSyncNode::start().await;
+    let url = node.url();
+
+    let (addr, _, _) = ZkSyncNode::rich_wallets().next().expect("No rich wallets available");
+
+    let balance = cmd
+        .cast_fuse()
+        .args(["balance", addr, "--rpc-url", &url])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let nonce = cmd
+        .cast_fuse()
+        .args(["nonce", addr, "--rpc-url", &url])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+
+    let output = cmd
+        .cast_fuse()
+        .args(["storage", addr, "--zksync", "--immutable", "0", "--rpc-url", &url, "--json"])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let values: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let value = |name: &str| {
+        let entry = values.as_array().unwrap().iter().find(|v| v["name"] == name).unwrap();
+        alloy_primitives::U256::from_str_radix(
+            entry["value"].as_str().unwrap().trim_start_matches("0x"),
+            16,
+        )
+        .unwrap()
+        .to_string()
+    };
+
+    assert_eq!(value("balance"), balance.trim());
+    assert_eq!(value("txNonce"), nonce.trim());
+    assert_eq!(value("codeHash"), "0");
+    assert_eq!(value("immutable[0]"), "0");
+});
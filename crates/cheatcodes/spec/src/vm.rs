@@ -24,6 +24,9 @@
     /// Error thrown by cheatcodes.
     error CheatcodeError(string message);
 
+    /// Error returned by calls halted in the ZK-VM, e.g. on account validation failures.
+    error ZkHalt(string reason, string message);
+
     /// A modification applied to either `msg.sender` or `tx.origin`. Returned by `readCallers`.
     enum CallerMode {
         /// No caller modification is currently active.
@@ -1002,6 +1005,18 @@ struct PotentialRevert {
     #[cheatcode(group = Testing, safety = Safe)]
     function zkUsePaymaster(address paymaster_address, bytes calldata paymaster_input) external pure;
 
+    /// Executes the next CALL or CREATE as a transaction sent by the given custom account, going
+    /// through its `validateTransaction` and `payForTransaction` flow with the given signature.
+    #[cheatcode(group = Testing, safety = Safe)]
+    function zkExecuteAsAccount(address account, bytes calldata signature) external pure;
+
+    /// Executes a priority (L1 to L2) transaction calling `to` with `data` and `value`, sent by the
+    /// L2 alias of the L1 contract `l1Sender`.
+    /// The value and the fee are minted as if deposited on L1. Returns whether the transaction
+    /// succeeded and its return or revert data.
+    #[cheatcode(group = Testing, safety = Unsafe)]
+    function zkExecuteL1Tx(address l1Sender, address to, bytes calldata data, uint256 value, uint256 l2GasLimit) external returns (bool success, bytes memory returnData);
+
     /// Marks the contract to be injected as a factory dependency in the next transaction
     #[cheatcode(group = Testing, safety = Safe)]
     function zkUseFactoryDep(string calldata name) external pure;
@@ -1010,6 +1025,24 @@ struct PotentialRevert {
     #[cheatcode(group = Testing, safety = Safe)]
     function zkRegisterContract(string calldata name, bytes32 evmBytecodeHash, bytes calldata evmDeployedBytecode, bytes calldata evmBytecode, bytes32 zkBytecodeHash, bytes calldata zkDeployedBytecode) external pure;
 
+    /// Expects the next call to halt in the ZK-VM with the given reason, e.g. `validation failed`
+    /// or `paymaster validation failed`.
+    #[cheatcode(group = Testing, safety = Unsafe)]
+    function zkExpectHalt(string calldata reason) external;
+
+    /// Builds the L1 transaction that deposits `amount` of the base token to `to` on the ZKsync
+    /// chain at `l2UrlOrAlias`, through the bridgehub of the L1 at `l1UrlOrAlias`.
+    /// The returned call is meant to be broadcast on L1 as `target.call{value: value}(data)` with a
+    /// gas price of `gasPrice`, which the deposit fee included in `value` is computed for.
+    #[cheatcode(group = Scripting, safety = Unsafe)]
+    function zkL1DepositTransaction(string calldata l1UrlOrAlias, string calldata l2UrlOrAlias, address from, address to, uint256 amount) external returns (address target, uint256 value, bytes memory data, uint256 gasPrice);
+
+    /// Builds the L1 transaction that finalizes the `index`-th withdrawal initiated by the
+    /// transaction `txHash` on the ZKsync chain at `l2UrlOrAlias`.
+    /// The returned call is meant to be broadcast on L1 as `target.call{value: value}(data)`.
+    #[cheatcode(group = Scripting, safety = Unsafe)]
+    function zkL1FinalizeWithdrawalTransaction(string calldata l2UrlOrAlias, bytes32 txHash, uint256 index) external returns (address target, uint256 value, bytes memory data);
+
     /// Gets the transaction nonce of a zksync account.
     #[cheatcode(group = Evm, safety = Safe)]
     function zkGetTransactionNonce(address account) external view returns (uint64 nonce);
@@ -1018,6 +1051,26 @@ struct PotentialRevert {
     #[cheatcode(group = Evm, safety = Safe)]
     function zkGetDeploymentNonce(address account) external view returns (uint64 nonce);
 
+    /// Gets the immutable at `index` of a zksync contract, as stored in the `ImmutableSimulator` system contract.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function zkGetImmutable(address target, uint256 index) external view returns (bytes32 value);
+
+    /// Sets the immutable at `index` of a zksync contract, as stored in the `ImmutableSimulator` system contract.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function zkSetImmutable(address target, uint256 index, bytes32 value) external;
+
+    /// Sets the L1 gas price used for transactions executed in the ZK-VM.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function zkSetL1GasPrice(uint64 gasPrice) external;
+
+    /// Sets the fair L2 gas price used for transactions executed in the ZK-VM.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function zkSetFairL2GasPrice(uint64 gasPrice) external;
+
+    /// Sets the fair pubdata price used for transactions executed in the ZK-VM. Must not be zero.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function zkSetPubdataPrice(uint64 pubdataPrice) external;
+
     /// If the condition is false, discard this run's fuzz inputs and generate new ones.
     #[cheatcode(group = Testing, safety = Safe)]
     function assume(bool condition) external pure;
@@ -3114,10 +3167,17 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+impl fmt::Display for Vm::ZkHalt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "zk vm halted ({}): {}", self.reason, self.message)
+    }
+}
+
 impl fmt::Display for Vm::VmErrors {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::CheatcodeError(err) => err.fmt(f),
+            Self::ZkHalt(err) => err.fmt(f),
         }
     }
 }
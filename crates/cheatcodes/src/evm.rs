@@ -33,6 +33,7 @@
 };
 use foundry_evm_traces::TraceMode;
 use foundry_primitives::FoundryTxEnvelope;
+use foundry_zksync_core::vm::ZkPubdataUsage;
 use itertools::Itertools;
 use rand::Rng;
 use revm::{
@@ -51,7 +52,7 @@
 use foundry_common::fmt::format_token_raw;
 use foundry_config::evm_spec_id;
 use record_debug_step::{convert_call_trace_ctx_to_debug_step, flatten_call_trace};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 mod fork;
 pub(crate) mod mapping;
@@ -69,6 +70,9 @@ pub struct GasRecord {
     pub gas_used: u64,
     /// Depth at which the gas snapshot was taken.
     pub depth: usize,
+    /// Pubdata published in the gas snapshot by calls executed in EraVM.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    pub zk_pubdata: ZkPubdataUsage,
 }
 
 /// Records `deal` cheatcodes
@@ -238,6 +242,42 @@ fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
     }
 }
 
+impl Cheatcode for zkGetImmutableCall {
+    fn apply_stateful(&self, _ccx: &mut CheatsCtxt) -> Result {
+        bail!("`zkGetImmutable` is only available with `--zksync`")
+    }
+}
+
+impl Cheatcode for zkSetImmutableCall {
+    fn apply_stateful(&self, _ccx: &mut CheatsCtxt) -> Result {
+        bail!("`zkSetImmutable` is only available with `--zksync`")
+    }
+}
+
+impl Cheatcode for zkSetL1GasPriceCall {
+    fn apply_stateful(&self, _ccx: &mut CheatsCtxt) -> Result {
+        // Does nothing by default.
+        // ZK-related logic is implemented in the corresponding strategy object.
+        Ok(Default::default())
+    }
+}
+
+impl Cheatcode for zkSetFairL2GasPriceCall {
+    fn apply_stateful(&self, _ccx: &mut CheatsCtxt) -> Result {
+        // Does nothing by default.
+        // ZK-related logic is implemented in the corresponding strategy object.
+        Ok(Default::default())
+    }
+}
+
+impl Cheatcode for zkSetPubdataPriceCall {
+    fn apply_stateful(&self, _ccx: &mut CheatsCtxt) -> Result {
+        // Does nothing by default.
+        // ZK-related logic is implemented in the corresponding strategy object.
+        Ok(Default::default())
+    }
+}
+
 impl Cheatcode for loadCall {
     fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
         let Self { target, slot } = *self;
@@ -1375,6 +1415,7 @@ fn inner_start_gas_snapshot(
         name: name.clone(),
         gas_used: 0,
         depth: ccx.ecx.journaled_state.depth(),
+        zk_pubdata: Default::default(),
     });
 
     ccx.state.gas_metering.active_gas_snapshot = Some((group, name));
@@ -1411,6 +1452,16 @@ fn inner_stop_gas_snapshot(
             .or_default()
             .insert(name.clone(), value.to_string());
 
+        // Record the pubdata published by calls executed in EraVM, if any, in a separate group so
+        // the gas snapshots of the group are left untouched.
+        let pubdata = record.zk_pubdata;
+        if pubdata.published > 0 {
+            ccx.state.gas_snapshots.entry(format!("{group}.zkPubdata")).or_default().extend([
+                (format!("{name}.bytes"), pubdata.published.to_string()),
+                (format!("{name}.l1Cost"), pubdata.l1_cost().to_string()),
+            ]);
+        }
+
         // Stop the gas metering.
         ccx.state.gas_metering.stop();
 
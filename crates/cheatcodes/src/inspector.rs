@@ -39,6 +39,7 @@
 };
 use foundry_evm_traces::{TracingInspectorConfig, identifier::SignaturesIdentifier};
 use foundry_wallets::wallet_multi::MultiWallet;
+use foundry_zksync_core::vm::ZkPubdataUsage;
 use foundry_zksync_inspectors::TraceCollector;
 use itertools::Itertools;
 use proptest::test_runner::{RngAlgorithm, TestRng, TestRunner};
@@ -483,6 +484,11 @@ pub struct Cheatcodes {
     // **Note**: both must a BTreeMap to ensure the order of the keys is deterministic.
     pub gas_snapshots: BTreeMap<String, BTreeMap<String, String>>,
 
+    /// Pubdata published by calls executed in EraVM during the current call, `None` if no call
+    /// was executed in EraVM.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    pub zk_pubdata: Option<ZkPubdataUsage>,
+
     /// Mapping slots.
     pub mapping_slots: Option<AddressHashMap<MappingSlots>>,
 
@@ -566,6 +572,7 @@ pub fn new(config: Arc<CheatsConfig>) -> Self {
             eth_deals: Default::default(),
             gas_metering: Default::default(),
             gas_snapshots: Default::default(),
+            zk_pubdata: Default::default(),
             mapping_slots: Default::default(),
             pc: Default::default(),
             breakpoints: Default::default(),
@@ -277,6 +277,20 @@ fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
     }
 }
 
+impl Cheatcode for zkL1DepositTransactionCall {
+    fn apply_stateful(&self, _ccx: &mut CheatsCtxt) -> Result {
+        // ZK-related logic is implemented in the corresponding strategy object.
+        bail!("`zkL1DepositTransaction` is only available with `--zksync`")
+    }
+}
+
+impl Cheatcode for zkL1FinalizeWithdrawalTransactionCall {
+    fn apply_stateful(&self, _ccx: &mut CheatsCtxt) -> Result {
+        // ZK-related logic is implemented in the corresponding strategy object.
+        bail!("`zkL1FinalizeWithdrawalTransaction` is only available with `--zksync`")
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Broadcast {
     /// Address of the transaction origin
@@ -37,6 +37,20 @@ fn apply_stateful(&self, _ccx: &mut CheatsCtxt) -> Result {
     }
 }
 
+impl Cheatcode for zkExecuteAsAccountCall {
+    fn apply_stateful(&self, _ccx: &mut CheatsCtxt) -> Result {
+        // Does nothing by default.
+        // ZK-related logic is implemented in the corresponding strategy object.
+        Ok(Default::default())
+    }
+}
+
+impl Cheatcode for zkExecuteL1TxCall {
+    fn apply_stateful(&self, _ccx: &mut CheatsCtxt) -> Result {
+        bail!("`zkExecuteL1Tx` is only available with `--zksync`")
+    }
+}
+
 impl Cheatcode for zkUseFactoryDepCall {
     fn apply_stateful(&self, _ccx: &mut CheatsCtxt) -> Result {
         // Does nothing by default.
@@ -55,6 +55,8 @@ pub struct ExpectedRevert {
     pub count: u64,
     /// Actual number of times this revert has been seen.
     pub actual_count: u64,
+    /// If true then only calls halted in the ZK-VM, i.e. reverting with [`ZkHalt`], match.
+    pub zk_halt: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -569,6 +571,25 @@ fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
     }
 }
 
+impl Cheatcode for zkExpectHaltCall {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self { reason } = self;
+        expect_revert(
+            ccx.state,
+            Some(reason.as_bytes()),
+            ccx.ecx.journaled_state.depth(),
+            false,
+            false,
+            None,
+            1,
+        )?;
+        if let Some(expected_revert) = &mut ccx.state.expected_revert {
+            expected_revert.zk_halt = true;
+        }
+        Ok(Default::default())
+    }
+}
+
 impl Cheatcode for _expectCheatcodeRevert_0Call {
     fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
         expect_revert(ccx.state, None, ccx.ecx.journaled_state.depth(), true, false, None, 1)
@@ -1033,6 +1054,7 @@ fn expect_revert(
         max_depth: depth,
         count,
         actual_count: 0,
+        zk_halt: false,
     });
     Ok(Default::default())
 }
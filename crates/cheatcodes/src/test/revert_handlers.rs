@@ -272,6 +272,14 @@ pub(crate) fn handle_expect_revert(
     } else {
         ensure!(!matches!(status, return_ok!()), "next call did not revert as expected");
 
+        // A plain revert with the same reason string must not satisfy `zkExpectHalt`.
+        if expected_revert.zk_halt && Vm::ZkHalt::abi_decode(&retdata).is_err() {
+            bail!(
+                "call reverted with '{}', but was expected to halt in the ZK-VM",
+                stringify(&decode_revert(retdata.to_vec()))
+            );
+        }
+
         handle_revert(
             is_cheatcode,
             expected_revert,
@@ -292,5 +300,9 @@ fn decode_revert(revert: Vec<u8>) -> Vec<u8> {
     {
         return decoded;
     }
+    // Calls halted in the ZK-VM are matched against the halt reason only.
+    if let Ok(halt) = Vm::ZkHalt::abi_decode(&revert) {
+        return halt.reason.into_bytes();
+    }
     revert
 }
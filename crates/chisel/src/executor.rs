@@ -202,7 +202,18 @@ fn infer_inner_expr_type(&self) -> Option<&pt::Expression> {
     async fn build_runner(&mut self, final_pc: usize) -> Result<ChiselRunner> {
         let env = self.config.evm_opts.evm_env().await?;
 
-        let mut strategy = utils::get_executor_strategy(&self.config.foundry_config);
+        let mut strategy = utils::get_executor_strategy(&self.config.foundry_config)?;
+
+        if self.config.foundry_config.zksync.should_compile() {
+            strategy.runner.zksync_set_dual_compiled_contracts(
+                strategy.context.as_mut(),
+                self.zk_build()?.clone(),
+            );
+
+            if let Some(fork_url) = &self.config.evm_opts.fork_url {
+                strategy.runner.zksync_set_fork_env(strategy.context.as_mut(), fork_url, &env)?;
+            }
+        }
 
         // Create an in-memory backend
         let backend = match self.config.backend.clone() {
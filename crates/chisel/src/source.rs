@@ -15,6 +15,7 @@
 };
 use foundry_config::{Config, SolcReq};
 use foundry_evm::{backend::Backend, core::bytecode::InstIter, opts::EvmOpts};
+use foundry_zksync_compilers::dual_compiled_contracts::DualCompiledContracts;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use solang_parser::pt;
@@ -438,6 +439,9 @@ pub struct SessionSource {
     /// The generated output
     #[serde(skip)]
     output: OnceCell<GeneratedOutput>,
+    /// The zksolc output paired with the generated output, only built in zksync mode
+    #[serde(skip)]
+    zk_output: OnceCell<DualCompiledContracts>,
 }
 
 fn vm_source() -> Source {
@@ -455,6 +459,7 @@ fn clone(&self) -> Self {
             config: self.config.clone(),
             vm_source: self.vm_source.clone(),
             output: Default::default(),
+            zk_output: Default::default(),
         }
     }
 }
@@ -485,6 +490,7 @@ pub fn new(mut config: SessionSourceConfig) -> Result<Self> {
             run_code: Default::default(),
             vm_source: vm_source(),
             output: Default::default(),
+            zk_output: Default::default(),
         })
     }
 
@@ -576,6 +582,7 @@ pub fn clear_run(&mut self) -> &mut Self {
 
     fn clear_output(&mut self) {
         self.output.take();
+        self.zk_output.take();
     }
 
     /// Compiles the source if necessary.
@@ -613,6 +620,36 @@ fn compile(&self) -> Result<ProjectCompileOutput> {
         Ok(output)
     }
 
+    /// Compiles the source with zksolc if necessary, and pairs it with the solc output so that
+    /// contracts created in the session are deployed on the zkVM.
+    pub fn zk_build(&self) -> Result<&DualCompiledContracts> {
+        if let Some(zk_output) = self.zk_output.get() {
+            return Ok(zk_output);
+        }
+        let output = self.build()?;
+        let zk_output = self.zk_compile(&output.output)?;
+        Ok(self.zk_output.get_or_init(|| zk_output))
+    }
+
+    #[cold]
+    fn zk_compile(&self, output: &ProjectCompileOutput) -> Result<DualCompiledContracts> {
+        let config = &self.config.foundry_config;
+        let zk_project = foundry_config::zksync::config_create_project(config, false, true)?;
+        let zk_output =
+            ProjectCompiler::with_sources(&zk_project, self.get_sources())?.compile()?;
+
+        if zk_output.has_compiler_errors() {
+            eyre::bail!("{zk_output}");
+        }
+
+        Ok(DualCompiledContracts::new(
+            output,
+            &zk_output,
+            &config.project_paths(),
+            &zk_project.paths,
+        ))
+    }
+
     fn get_sources(&self) -> Sources {
         let mut sources = Sources::new();
 
@@ -274,3 +274,32 @@ fn $name() {
     repl.expect("Hello, World!"); // old log is also printed
     repl.expect("Goodbye, World!");
 });
+
+// Test contracts created in zksync mode are deployed and called on the zkVM.
+repl_test!(zksync_can_create_and_call_contracts, "--zksync", |repl| {
+    repl.sendln(
+        "contract Probe { function codeHash() external view returns (bytes2) { return bytes2(address(this).codehash); } }",
+    );
+    repl.sendln("Probe probe = new Probe()");
+
+    // EraVM bytecode hashes are versioned, unlike the keccak256 hashes of EVM bytecode.
+    repl.sendln("probe.codeHash()");
+    repl.expect("Data: 0x0100");
+});
+
+// Test forking a ZKsync chain in zksync mode runs contracts on the zkVM against the fork.
+repl_test!(zksync_can_fork, "--zksync", |repl| {
+    repl.sendln_raw("!fork https://sepolia.era.zksync.dev");
+    repl.expect("Set fork URL to https://sepolia.era.zksync.dev");
+    repl.expect_prompt();
+
+    repl.sendln(
+        "contract Probe { function codeHash() external view returns (bytes2) { return bytes2(address(this).codehash); } function chainId() external view returns (uint256) { return block.chainid; } }",
+    );
+    repl.sendln("Probe probe = new Probe()");
+
+    repl.sendln("probe.chainId()");
+    repl.expect("Decimal: 300");
+    repl.sendln("probe.codeHash()");
+    repl.expect("Data: 0x0100");
+});
@@ -130,6 +130,19 @@ pub struct ZkSyncArgs {
     )]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub suppressed_errors: Option<Vec<ErrorType>>,
+
+    /// The ZKsync protocol version of the system contracts to run the zkVM with, e.g. `28`.
+    ///
+    /// Defaults to the protocol version of the fork when forking, or the latest supported version.
+    /// The contracts are always executed by the latest EraVM version.
+    #[clap(long = "zk-protocol-version", value_name = "VERSION")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol_version: Option<u16>,
+
+    /// Directory to load compiled system contracts from, instead of the built-in ones.
+    #[clap(long = "zk-system-contracts-path", value_name = "PATH")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_contracts_path: Option<PathBuf>,
 }
 
 impl ZkSyncArgs {
@@ -156,6 +169,8 @@ macro_rules! set_if_some {
         set_if_some!(self.llvm_options.clone(), zksync.llvm_options);
         set_if_some!(self.force_evmla, zksync.force_evmla);
         set_if_some!(self.size_fallback, zksync.size_fallback);
+        set_if_some!(self.protocol_version, zksync.protocol_version);
+        set_if_some!(self.system_contracts_path.clone(), zksync.system_contracts_path);
 
         set_if_some!(self.optimizer.then_some(true), zksync.optimizer);
         set_if_some!(
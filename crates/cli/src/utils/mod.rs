@@ -107,7 +107,8 @@ pub fn get_provider(config: &Config) -> Result<RetryProvider> {
     get_provider_builder(config)?.build()
 }
 
-pub fn get_executor_strategy(config: &Config) -> ExecutorStrategy {
+/// Returns the [ExecutorStrategy] to execute with, as configured by [Config].
+pub fn get_executor_strategy(config: &Config) -> Result<ExecutorStrategy> {
     if config.zksync.should_compile() {
         if config.zksync.evm_interpreter {
             info!("using zksync strategy (EVM-interpreter)");
@@ -115,10 +116,10 @@ pub fn get_executor_strategy(config: &Config) -> ExecutorStrategy {
             info!("using zksync strategy");
         }
 
-        ExecutorStrategy::new_zksync(config.zksync.evm_interpreter)
+        ExecutorStrategy::new_zksync(&config.zksync)
     } else {
         info!("using evm strategy");
-        ExecutorStrategy::new_evm()
+        Ok(ExecutorStrategy::new_evm())
     }
 }
 
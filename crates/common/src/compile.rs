@@ -62,9 +62,6 @@ pub struct ProjectCompiler {
     /// Extra files to include, that are not necessarily in the project's source directory.
     files: Vec<PathBuf>,
 
-    /// Set zksync specific settings based on context
-    zksync: bool,
-
     /// Whether to compile with dynamic linking tests and scripts.
     dynamic_test_linking: bool,
 }
@@ -88,7 +85,6 @@ pub fn new() -> Self {
             bail: None,
             ignore_eip_3860: false,
             files: Vec::new(),
-            zksync: false,
             dynamic_test_linking: false,
         }
     }
@@ -264,7 +260,7 @@ fn handle_output<C: Compiler<CompilerContract = Contract>>(
                 sh_println!()?;
             }
 
-            let mut size_report = SizeReport { contracts: BTreeMap::new(), zksync: self.zksync };
+            let mut size_report = SizeReport { contracts: BTreeMap::new() };
 
             let mut artifacts: BTreeMap<String, Vec<_>> = BTreeMap::new();
             for (id, artifact) in output.artifact_ids().filter(|(id, _)| {
@@ -336,8 +332,6 @@ fn handle_output<C: Compiler<CompilerContract = Contract>>(
 pub struct SizeReport {
     /// `contract name -> info`
     pub contracts: BTreeMap<String, ContractInfo>,
-    /// Using zksync size report
-    pub zksync: bool,
 }
 
 impl SizeReport {
@@ -363,20 +357,12 @@ pub fn max_init_size(&self) -> usize {
 
     /// Returns true if any contract exceeds the runtime size limit, excluding dev contracts.
     pub fn exceeds_runtime_size_limit(&self) -> bool {
-        if self.zksync {
-            self.max_runtime_size() > zksync::ZKSYNC_CONTRACT_SIZE_LIMIT
-        } else {
-            self.max_runtime_size() > CONTRACT_RUNTIME_SIZE_LIMIT
-        }
+        self.max_runtime_size() > CONTRACT_RUNTIME_SIZE_LIMIT
     }
 
     /// Returns true if any contract exceeds the initcode size limit, excluding dev contracts.
     pub fn exceeds_initcode_size_limit(&self) -> bool {
-        if self.zksync {
-            self.max_init_size() > zksync::ZKSYNC_CONTRACT_SIZE_LIMIT
-        } else {
-            self.max_init_size() > CONTRACT_INITCODE_SIZE_LIMIT
-        }
+        self.max_init_size() > CONTRACT_INITCODE_SIZE_LIMIT
     }
 }
 
@@ -435,27 +421,20 @@ fn format_table_output(&self) -> Table {
             .iter()
             .filter(|(_, c)| !c.is_dev_contract && (c.runtime_size > 0 || c.init_size > 0));
         for (name, contract) in contracts {
-            let ((runtime_margin, runtime_color), (init_margin, init_color)) = if self.zksync {
-                Self::zk_limits_table_format(contract)
-            } else {
-                let runtime_margin =
-                    CONTRACT_RUNTIME_SIZE_LIMIT as isize - contract.runtime_size as isize;
-                let init_margin =
-                    CONTRACT_INITCODE_SIZE_LIMIT as isize - contract.init_size as isize;
-
-                let runtime_color = match contract.runtime_size {
-                    ..18_000 => Color::Reset,
-                    18_000..=CONTRACT_RUNTIME_SIZE_LIMIT => Color::Yellow,
-                    _ => Color::Red,
-                };
-
-                let init_color = match contract.init_size {
-                    ..36_000 => Color::Reset,
-                    36_000..=CONTRACT_INITCODE_SIZE_LIMIT => Color::Yellow,
-                    _ => Color::Red,
-                };
-
-                ((runtime_margin, runtime_color), (init_margin, init_color))
+            let runtime_margin =
+                CONTRACT_RUNTIME_SIZE_LIMIT as isize - contract.runtime_size as isize;
+            let init_margin = CONTRACT_INITCODE_SIZE_LIMIT as isize - contract.init_size as isize;
+
+            let runtime_color = match contract.runtime_size {
+                ..18_000 => Color::Reset,
+                18_000..=CONTRACT_RUNTIME_SIZE_LIMIT => Color::Yellow,
+                _ => Color::Red,
+            };
+
+            let init_color = match contract.init_size {
+                ..36_000 => Color::Reset,
+                36_000..=CONTRACT_INITCODE_SIZE_LIMIT => Color::Yellow,
+                _ => Color::Red,
             };
 
             let locale = &Locale::en;
@@ -1,32 +1,31 @@
-use std::{collections::BTreeMap, io::IsTerminal};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    io::IsTerminal,
+};
 
-use comfy_table::Color;
+use comfy_table::{Cell, Color, Table, modifiers::UTF8_ROUND_CORNERS, presets::ASCII_MARKDOWN};
 use eyre::Result;
 use foundry_compilers::{
-    Project, ProjectCompileOutput,
+    Compiler, Project, ProjectCompileOutput,
     artifacts::Source,
     report::{BasicStdoutReporter, NoReporter, Report},
 };
-use foundry_zksync_compilers::compilers::{
-    artifact_output::zk::ZkArtifactOutput,
-    zksolc::{ZKSOLC_UNSUPPORTED_VERSIONS, ZkSolc, ZkSolcCompiler},
+use foundry_zksync_compilers::{
+    artifacts::contract::Contract,
+    compilers::{
+        artifact_output::zk::{ERAVM_BYTECODE_WORDS_LIMIT, ZkArtifactOutput},
+        zksolc::{ZKSOLC_UNSUPPORTED_VERSIONS, ZkSolc, ZkSolcCompiler},
+        zkvyper::{ZkVyper, ZkVyperCompiler},
+    },
 };
+use num_format::{Locale, ToFormattedString};
 
 use crate::{TestFunctionExt, shell, term::SpinnerReporter};
 
-use super::{ContractInfo, ProjectCompiler, SizeReport, contract_size};
-
-// https://docs.zksync.io/build/developer-reference/ethereum-differences/contract-deployment#contract-size-limit-and-format-of-bytecode-hash
-pub(super) const ZKSYNC_CONTRACT_SIZE_LIMIT: usize = 450999;
+use super::ProjectCompiler;
 
 impl ProjectCompiler {
-    /// Enables zksync contract sizes.
-    #[inline]
-    pub fn zksync_sizes(mut self) -> Self {
-        self.zksync = true;
-        self
-    }
-
     /// Compiles the project.
     pub fn zksync_compile(
         self,
@@ -46,6 +45,12 @@ pub fn zksync_compile(
         // for filtering artifacts in missing libraries detection
         let files = self.files.clone();
 
+        // Whether zksolc already tries to reduce the bytecode size, used to hint at the settings
+        // to change when a contract exceeds the EraVM size limit.
+        let optimizer = &project.settings.settings.optimizer;
+        let optimizes_for_size =
+            optimizer.size_fallback.unwrap_or_default() || optimizer.mode == Some('z');
+
         {
             let zksolc_current_version = project.settings.zksolc_version_ref();
             let zksolc_min_supported_version = ZkSolc::zksolc_minimum_supported_version();
@@ -71,7 +76,7 @@ pub fn zksync_compile(
             ));
         }
 
-        self.zksync_compile_with(|| {
+        self.zksync_compile_with(optimizes_for_size, || {
             let files_to_compile =
                 if !files.is_empty() { files } else { project.paths.input_files() };
             let sources = Source::read_all(files_to_compile)?;
@@ -81,13 +86,49 @@ pub fn zksync_compile(
         })
     }
 
+    /// Compiles the Vyper sources of the project with zkvyper.
+    pub fn zksync_compile_vyper(
+        self,
+        project: &Project<ZkVyperCompiler, ZkArtifactOutput>,
+    ) -> Result<ProjectCompileOutput<ZkVyperCompiler, ZkArtifactOutput>> {
+        let optimizer = &project.settings.settings.optimizer;
+        let optimizes_for_size =
+            optimizer.size_fallback.unwrap_or_default() || optimizer.mode == Some('z');
+
+        {
+            let zkvyper_current_version = project.settings.zkvyper_version_ref();
+            let zkvyper_min_supported_version = ZkVyper::zkvyper_minimum_supported_version();
+            let zkvyper_latest_supported_version = ZkVyper::zkvyper_latest_supported_version();
+            if zkvyper_current_version < &zkvyper_min_supported_version
+                || zkvyper_current_version > &zkvyper_latest_supported_version
+            {
+                sh_warn!(
+                    "Compiling with zkvyper v{zkvyper_current_version} which is not supported and may lead to unexpected errors. Supported versions are v{zkvyper_min_supported_version} to v{zkvyper_latest_supported_version}"
+                )?;
+            }
+            Report::new(SpinnerReporter::spawn_with_message(
+                Some(self.project_root.clone()),
+                format!("Using zkvyper-{zkvyper_current_version}"),
+            ));
+        }
+
+        self.zksync_compile_with(optimizes_for_size, || {
+            let sources = Source::read_all(project.paths.input_files())?;
+            foundry_compilers::project::ProjectCompiler::with_sources(project, sources)?
+                .compile()
+                .map_err(Into::into)
+        })
+    }
+
     #[instrument(target = "forge::compile", skip_all)]
-    fn zksync_compile_with<F>(
+    fn zksync_compile_with<C, F>(
         self,
+        optimizes_for_size: bool,
         f: F,
-    ) -> Result<ProjectCompileOutput<ZkSolcCompiler, ZkArtifactOutput>>
+    ) -> Result<ProjectCompileOutput<C, ZkArtifactOutput>>
     where
-        F: FnOnce() -> Result<ProjectCompileOutput<ZkSolcCompiler, ZkArtifactOutput>>,
+        C: Compiler<CompilerContract = Contract>,
+        F: FnOnce() -> Result<ProjectCompileOutput<C, ZkArtifactOutput>>,
     {
         let quiet = self.quiet.unwrap_or(false);
         let bail = self.bail.unwrap_or(true);
@@ -131,16 +172,17 @@ fn zksync_compile_with<F>(
                 sh_println!("{output}")?;
             }
 
-            self.zksync_handle_output(&output)?;
+            self.zksync_handle_output(&output, optimizes_for_size)?;
         }
 
         Ok(output)
     }
 
     /// If configured, this will print sizes or names
-    fn zksync_handle_output(
+    fn zksync_handle_output<C: Compiler<CompilerContract = Contract>>(
         &self,
-        output: &ProjectCompileOutput<ZkSolcCompiler, ZkArtifactOutput>,
+        output: &ProjectCompileOutput<C, ZkArtifactOutput>,
+        optimizes_for_size: bool,
     ) -> Result<()> {
         let print_names = self.print_names.unwrap_or(false);
         let print_sizes = self.print_sizes.unwrap_or(false);
@@ -170,7 +212,14 @@ fn zksync_handle_output(
                 let _ = sh_println!();
             }
 
-            let mut size_report = SizeReport { contracts: BTreeMap::new(), zksync: self.zksync };
+            let mut size_report = ZkSizeReport { contracts: BTreeMap::new() };
+
+            let sizes_by_hash: HashMap<_, _> = output
+                .artifacts()
+                .filter_map(|(_, artifact)| {
+                    Some((artifact.hash.as_ref()?, artifact.bytecode.as_ref()?.size()))
+                })
+                .collect();
 
             let artifacts: BTreeMap<_, _> = output
                 .artifact_ids()
@@ -182,8 +231,18 @@ fn zksync_handle_output(
                 .collect();
 
             for (name, artifact) in artifacts {
-                let runtime_size = contract_size(artifact, false).unwrap_or_default();
-                let init_size = contract_size(artifact, true).unwrap_or_default();
+                let (size, words) = artifact
+                    .bytecode
+                    .as_ref()
+                    .map(|bytecode| (bytecode.size(), bytecode.size_in_words()))
+                    .unwrap_or_default();
+
+                let factory_deps = artifact.factory_dependencies.as_ref();
+                let factory_deps_size = factory_deps
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|(hash, _)| sizes_by_hash.get(hash))
+                    .sum();
 
                 let is_dev_contract = artifact
                     .abi
@@ -195,47 +254,208 @@ fn zksync_handle_output(
                         })
                     })
                     .unwrap_or(false);
-                size_report
-                    .contracts
-                    .insert(name, ContractInfo { runtime_size, init_size, is_dev_contract });
+                size_report.contracts.insert(
+                    name,
+                    ZkContractInfo {
+                        size,
+                        words,
+                        factory_deps: factory_deps.map_or(0, |deps| deps.len()),
+                        factory_deps_size,
+                        is_dev_contract,
+                    },
+                );
             }
 
-            let _ = sh_println!("{size_report}");
-
-            // TODO: avoid process::exit
-            // exit with error if any contract exceeds the size limit, excluding test contracts.
-            if size_report.exceeds_runtime_size_limit() {
-                std::process::exit(1);
-            }
+            sh_println!("{size_report}")?;
 
-            // Check size limits only if not ignoring EIP-3860
-            if !self.ignore_eip_3860 && size_report.exceeds_initcode_size_limit() {
-                std::process::exit(1);
-            }
+            size_report.check_size_limit(optimizes_for_size)?;
         }
         Ok(())
     }
 }
 
-impl SizeReport {
-    pub(super) fn zk_limits_table_format(
-        contract: &ContractInfo,
-    ) -> ((isize, Color), (isize, Color)) {
-        let runtime_margin = ZKSYNC_CONTRACT_SIZE_LIMIT as isize - contract.runtime_size as isize;
-        let init_margin = ZKSYNC_CONTRACT_SIZE_LIMIT as isize - contract.init_size as isize;
-
-        let runtime_color = match contract.runtime_size {
-            0..=329999 => Color::Reset,
-            330000..=ZKSYNC_CONTRACT_SIZE_LIMIT => Color::Yellow,
-            _ => Color::Red,
+/// zksolc contracts with info about their size
+pub struct ZkSizeReport {
+    /// `contract name -> info`
+    pub contracts: BTreeMap<String, ZkContractInfo>,
+}
+
+/// How big a zksolc contract is and how much it publishes along with it
+#[derive(Clone, Copy, Debug)]
+pub struct ZkContractInfo {
+    /// Size of the bytecode in bytes
+    pub size: usize,
+    /// Size of the bytecode in 32-byte words
+    pub words: usize,
+    /// Number of factory dependencies
+    pub factory_deps: usize,
+    /// Total size of the factory dependencies in bytes
+    pub factory_deps_size: usize,
+    /// A development contract is either a Script or a Test contract.
+    pub is_dev_contract: bool,
+}
+
+impl ZkContractInfo {
+    /// Returns how many words can still be added before the bytecode exceeds the EraVM size
+    /// limit, negative if it already does.
+    pub fn margin_words(&self) -> isize {
+        (ERAVM_BYTECODE_WORDS_LIMIT - 1) as isize - self.words as isize
+    }
+}
+
+impl ZkSizeReport {
+    /// Returns the maximum bytecode size in words, excluding dev contracts.
+    pub fn max_words(&self) -> usize {
+        self.contracts.values().filter(|c| !c.is_dev_contract).map(|c| c.words).max().unwrap_or(0)
+    }
+
+    /// Returns true if any contract exceeds the EraVM bytecode size limit, excluding dev
+    /// contracts.
+    pub fn exceeds_size_limit(&self) -> bool {
+        self.max_words() >= ERAVM_BYTECODE_WORDS_LIMIT
+    }
+
+    /// Fails if any contract exceeds the EraVM bytecode size limit, hinting at the zksolc
+    /// settings that reduce the bytecode size unless they are already enabled.
+    pub fn check_size_limit(&self, optimizes_for_size: bool) -> Result<()> {
+        if !self.exceeds_size_limit() {
+            return Ok(());
+        }
+        let hint = if optimizes_for_size {
+            ""
+        } else {
+            "; consider setting `size_fallback = true` or `optimizer_mode = 'z'` in the `zksync` \
+             config to optimize for size"
         };
+        eyre::bail!(
+            "some contracts exceed the EraVM bytecode size limit \
+             (at most {} words){hint}",
+            ERAVM_BYTECODE_WORDS_LIMIT - 1
+        )
+    }
+
+    fn non_dev_contracts(&self) -> impl Iterator<Item = (&String, &ZkContractInfo)> {
+        self.contracts.iter().filter(|(_, c)| !c.is_dev_contract && c.size > 0)
+    }
+
+    fn format_json_output(&self) -> String {
+        let contracts = self
+            .non_dev_contracts()
+            .map(|(name, contract)| {
+                (
+                    name.clone(),
+                    serde_json::json!({
+                        "size": contract.size,
+                        "words": contract.words,
+                        "margin_words": contract.margin_words(),
+                        "factory_deps": contract.factory_deps,
+                        "factory_deps_size": contract.factory_deps_size,
+                    }),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>();
+
+        serde_json::to_string(&contracts).unwrap()
+    }
+
+    fn format_table_output(&self) -> Table {
+        let mut table = Table::new();
+        if shell::is_markdown() {
+            table.load_preset(ASCII_MARKDOWN);
+        } else {
+            table.apply_modifier(UTF8_ROUND_CORNERS);
+        }
 
-        let init_color = match contract.init_size {
-            0..=329999 => Color::Reset,
-            330000..=ZKSYNC_CONTRACT_SIZE_LIMIT => Color::Yellow,
-            _ => Color::Red,
+        table.set_header(vec![
+            Cell::new("Contract"),
+            Cell::new("Size (words)"),
+            Cell::new("Size (B)"),
+            Cell::new("Margin (words)"),
+            Cell::new("Factory Deps"),
+            Cell::new("Factory Deps Size (B)"),
+        ]);
+
+        for (name, contract) in self.non_dev_contracts() {
+            let margin = contract.margin_words();
+            let color = match contract.words {
+                ..48_000 => Color::Reset,
+                48_000..ERAVM_BYTECODE_WORDS_LIMIT => Color::Yellow,
+                _ => Color::Red,
+            };
+
+            let locale = &Locale::en;
+            table.add_row([
+                Cell::new(name),
+                Cell::new(contract.words.to_formatted_string(locale)).fg(color),
+                Cell::new(contract.size.to_formatted_string(locale)).fg(color),
+                Cell::new(margin.to_formatted_string(locale)).fg(color),
+                Cell::new(contract.factory_deps.to_formatted_string(locale)),
+                Cell::new(contract.factory_deps_size.to_formatted_string(locale)),
+            ]);
+        }
+
+        table
+    }
+}
+
+impl Display for ZkSizeReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        if shell::is_json() {
+            writeln!(f, "{}", self.format_json_output())?;
+        } else {
+            writeln!(f, "\n{}", self.format_table_output())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(words: usize) -> ZkSizeReport {
+        let contract = ZkContractInfo {
+            size: words * 32,
+            words,
+            factory_deps: 0,
+            factory_deps_size: 0,
+            is_dev_contract: false,
         };
+        ZkSizeReport { contracts: BTreeMap::from([("Counter".to_string(), contract)]) }
+    }
+
+    #[test]
+    fn margin_is_zero_at_the_largest_valid_size() {
+        let largest = report(ERAVM_BYTECODE_WORDS_LIMIT - 1);
+        assert_eq!(largest.contracts["Counter"].margin_words(), 0);
+        assert!(largest.check_size_limit(false).is_ok());
+
+        let oversized = report(ERAVM_BYTECODE_WORDS_LIMIT);
+        assert_eq!(oversized.contracts["Counter"].margin_words(), -1);
+    }
+
+    #[test]
+    fn oversized_contract_fails_with_size_hint() {
+        let err = report(ERAVM_BYTECODE_WORDS_LIMIT).check_size_limit(false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "some contracts exceed the EraVM bytecode size limit (at most 65535 words); \
+             consider setting `size_fallback = true` or `optimizer_mode = 'z'` in the `zksync` \
+             config to optimize for size"
+        );
+
+        // the hint is dropped when zksolc already optimizes for size
+        let err = report(ERAVM_BYTECODE_WORDS_LIMIT).check_size_limit(true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "some contracts exceed the EraVM bytecode size limit (at most 65535 words)"
+        );
+    }
 
-        ((runtime_margin, runtime_color), (init_margin, init_color))
+    #[test]
+    fn oversized_dev_contract_is_ignored() {
+        let mut report = report(ERAVM_BYTECODE_WORDS_LIMIT);
+        report.contracts.get_mut("Counter").unwrap().is_dev_contract = true;
+        assert!(report.check_size_limit(false).is_ok());
     }
 }
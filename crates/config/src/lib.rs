@@ -993,6 +993,10 @@ fn p(root: &Path, rem: &Path) -> PathBuf {
             self.build_info_path = Some(p(&root, &build_info_path));
         }
 
+        if let Some(system_contracts_path) = self.zksync.system_contracts_path {
+            self.zksync.system_contracts_path = Some(p(&root, &system_contracts_path));
+        }
+
         self.libs = self.libs.into_iter().map(|lib| p(&root, &lib)).collect();
 
         self.remappings =
@@ -56,6 +56,20 @@ fn default() -> Self {
     }
 }
 
+impl LinterConfig {
+    /// Returns the severities to lint with, enabling the `zksync` lints on top of the configured
+    /// ones when compiling with zksolc.
+    ///
+    /// An empty list means all severities, so it is returned as is.
+    pub fn severity_with_zksync(&self, zksync_compile: bool) -> Vec<Severity> {
+        let mut severity = self.severity.clone();
+        if zksync_compile && !severity.is_empty() && !severity.contains(&Severity::Zksync) {
+            severity.push(Severity::Zksync);
+        }
+        severity
+    }
+}
+
 /// Severity of a lint.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum Severity {
@@ -65,6 +79,7 @@ pub enum Severity {
     Info,
     Gas,
     CodeSize,
+    Zksync,
 }
 
 impl Severity {
@@ -76,6 +91,7 @@ fn to_str(self) -> &'static str {
             Self::Info => "Info",
             Self::Gas => "Gas",
             Self::CodeSize => "CodeSize",
+            Self::Zksync => "Zksync",
         }
     }
 
@@ -87,6 +103,7 @@ fn to_str_kebab(self) -> &'static str {
             Self::Info => "info",
             Self::Gas => "gas",
             Self::CodeSize => "code-size",
+            Self::Zksync => "zksync",
         }
     }
 
@@ -98,6 +115,7 @@ pub fn color(&self, message: &str) -> String {
             Self::Info => Paint::cyan(message).bold().to_string(),
             Self::Gas => Paint::green(message).bold().to_string(),
             Self::CodeSize => Paint::green(message).bold().to_string(),
+            Self::Zksync => Paint::magenta(message).bold().to_string(),
         }
     }
 }
@@ -105,7 +123,7 @@ pub fn color(&self, message: &str) -> String {
 impl From<Severity> for Level {
     fn from(severity: Severity) -> Self {
         match severity {
-            Severity::High | Severity::Med | Severity::Low => Self::Warning,
+            Severity::High | Severity::Med | Severity::Low | Severity::Zksync => Self::Warning,
             Severity::Info | Severity::Gas | Severity::CodeSize => Self::Note,
         }
     }
@@ -148,8 +166,9 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "info" => Ok(Self::Info),
             "gas" => Ok(Self::Gas),
             "size" | "codesize" | "code-size" => Ok(Self::CodeSize),
+            "zksync" => Ok(Self::Zksync),
             _ => Err(format!(
-                "unknown variant: found `{s}`, expected `one of `High`, `Med`, `Low`, `Info`, `Gas`, `CodeSize`"
+                "unknown variant: found `{s}`, expected `one of `High`, `Med`, `Low`, `Info`, `Gas`, `CodeSize`, `Zksync`"
             )),
         }
     }
@@ -1,6 +1,6 @@
 use foundry_compilers::{
     Project, ProjectBuilder, ProjectPathsConfig,
-    artifacts::{EvmVersion, Libraries, Severity},
+    artifacts::{EvmVersion, Libraries, Severity, vyper::VyperOptimizationMode},
     error::SolcError,
     solc::{CliSettings, Solc, SolcCompiler, SolcLanguage},
 };
@@ -16,6 +16,10 @@
                 ZkSolcSettings,
             },
         },
+        zkvyper::{
+            ZkVyper, ZkVyperCompiler, ZkVyperInputSettings, ZkVyperSettings,
+            settings::ZkVyperOptimizer,
+        },
     },
 };
 use semver::Version;
@@ -27,6 +31,9 @@
 /// Filename for zksync cache
 pub const ZKSYNC_SOLIDITY_FILES_CACHE_FILENAME: &str = "zksync-solidity-files-cache.json";
 
+/// Filename for the zksync cache of Vyper sources
+pub const ZKSYNC_VYPER_FILES_CACHE_FILENAME: &str = "zksync-vyper-files-cache.json";
+
 /// Directory for zksync artifacts
 pub const ZKSYNC_ARTIFACTS_DIR: &str = "zkout";
 
@@ -45,6 +52,9 @@ pub struct ZkSyncConfig {
     /// The zkSolc instance to use if any.
     pub zksolc: Option<SolcReq>,
 
+    /// The zkVyper instance to use if any, only needed when the project has Vyper sources.
+    pub zkvyper: Option<SolcReq>,
+
     /// solc path to use along the zksolc compiler
     pub solc_path: Option<PathBuf>,
 
@@ -83,6 +93,26 @@ pub struct ZkSyncConfig {
     // zksolc suppressed errors.
     #[serde(deserialize_with = "deserialize_error_set")]
     pub suppressed_errors: HashSet<ErrorType>,
+
+    /// L1 gas price used for transactions executed in zkVM, defaults to 0.
+    pub l1_gas_price: Option<u64>,
+
+    /// Fair L2 gas price used for transactions executed in zkVM, defaults to 0.
+    pub fair_l2_gas_price: Option<u64>,
+
+    /// Fair pubdata price used for transactions executed in zkVM, defaults to 1000. Must not be
+    /// zero.
+    pub fair_pubdata_price: Option<u64>,
+
+    /// Protocol version of the zkVM system contracts and bootloader, e.g. `28`. Defaults to the
+    /// protocol version of the fork when forking, or the latest supported version otherwise.
+    ///
+    /// The latest EraVM version is used to execute them regardless of the protocol version.
+    pub protocol_version: Option<u16>,
+
+    /// Directory to load compiled system contracts from, laid out as in `era-contracts`, instead
+    /// of the built-in ones.
+    pub system_contracts_path: Option<PathBuf>,
 }
 
 impl Default for ZkSyncConfig {
@@ -92,6 +122,7 @@ fn default() -> Self {
             startup: false,
             evm_interpreter: false,
             zksolc: Default::default(),
+            zkvyper: Default::default(),
             solc_path: Default::default(),
             hash_type: Default::default(),
             bytecode_hash: Default::default(),
@@ -104,6 +135,11 @@ fn default() -> Self {
             optimizer_details: Default::default(),
             suppressed_errors: Default::default(),
             suppressed_warnings: Default::default(),
+            l1_gas_price: Default::default(),
+            fair_l2_gas_price: Default::default(),
+            fair_pubdata_price: Default::default(),
+            protocol_version: Default::default(),
+            system_contracts_path: Default::default(),
         }
     }
 }
@@ -184,6 +220,50 @@ pub fn settings(
         ZkSolcSettings::new_from_path(settings, CliSettings::default(), zksolc_path)
     }
 
+    /// Returns the version of the configured zkvyper, or the latest supported one if unset.
+    pub fn zkvyper_version(&self) -> Result<Version, SolcError> {
+        match &self.zkvyper {
+            Some(SolcReq::Version(version)) => Ok(version.clone()),
+            Some(SolcReq::Local(path)) => ZkVyper::get_version_for_path(path),
+            None => Ok(ZkVyper::zkvyper_latest_supported_version()),
+        }
+    }
+
+    /// Convert the zksync config to zkvyper settings
+    pub fn zkvyper_settings(
+        &self,
+        evm_version: EvmVersion,
+        optimize: Option<VyperOptimizationMode>,
+        offline: bool,
+    ) -> Result<ZkVyperSettings, SolcError> {
+        let settings = ZkVyperInputSettings {
+            evm_version: Some(evm_version),
+            optimize,
+            output_selection: OutputSelection {
+                all: FileOutputSelection {
+                    per_file: [].into(),
+                    per_contract: [OutputSelectionFlag::ABI].into(),
+                },
+            },
+            optimizer: ZkVyperOptimizer {
+                mode: self.optimizer.then_some(self.optimizer_mode),
+                size_fallback: Some(self.size_fallback),
+            },
+            llvm_options: self.llvm_options.clone(),
+        };
+
+        let zkvyper_path =
+            if let Some(path) = config_ensure_zkvyper(self.zkvyper.as_ref(), offline)? {
+                path
+            } else if !offline {
+                ZkVyper::get_path_for_version(&ZkVyper::zkvyper_latest_supported_version())?
+            } else {
+                "zkvyper".into()
+            };
+
+        ZkVyperSettings::new_from_path(settings, zkvyper_path)
+    }
+
     pub fn hash_type(&self) -> Option<BytecodeHash> {
         self.hash_type.or(self.bytecode_hash)
     }
@@ -242,6 +322,73 @@ pub fn config_create_project(
     Ok(project)
 }
 
+/// Create a new ZKsync project for the Vyper sources, compiled with zkvyper.
+///
+/// Returns `None` if the project has no Vyper sources or no `vyper` is available.
+pub fn config_create_zkvyper_project(
+    config: &Config,
+    cached: bool,
+    no_artifacts: bool,
+) -> Result<Option<Project<ZkVyperCompiler, ZkArtifactOutput>>, SolcError> {
+    let Some(vyper) = config.vyper_compiler()? else {
+        return Ok(None);
+    };
+
+    let settings = config.zksync.zkvyper_settings(
+        config.evm_version,
+        config.vyper.optimize,
+        config.offline,
+    )?;
+    let mut builder = ProjectBuilder::<ZkVyperCompiler, ZkArtifactOutput>::default()
+        .artifacts(ZkArtifactOutput {})
+        .paths(project_paths(config, ZKSYNC_VYPER_FILES_CACHE_FILENAME))
+        .settings(settings)
+        .ignore_paths(config.ignored_file_paths.clone())
+        .set_compiler_severity_filter(if config.deny_warnings {
+            Severity::Warning
+        } else {
+            Severity::Error
+        })
+        .set_offline(config.offline)
+        .set_cached(cached)
+        .set_build_info(!no_artifacts && config.build_info)
+        .set_no_artifacts(no_artifacts);
+
+    if !config.skip.is_empty() {
+        let filter = SkipBuildFilters::new(config.skip.clone(), config.root.clone());
+        builder = builder.sparse_output(filter);
+    }
+
+    let project = builder.build(ZkVyperCompiler { vyper })?;
+
+    // The artifacts directory is shared with the zksolc project, which cleans it up.
+    let cache_path = project.cache_path();
+    if config.force && cache_path.exists() {
+        std::fs::remove_file(cache_path).map_err(|err| SolcError::io(err, cache_path))?;
+    }
+
+    Ok(Some(project))
+}
+
+/// Create a new ZKsync project that also outputs the EraVM assembly of each contract.
+///
+/// The assembly is used to map EraVM program counters back to source lines.
+pub fn config_create_project_with_eravm_assembly(
+    config: &Config,
+    cached: bool,
+    no_artifacts: bool,
+) -> Result<Project<ZkSolcCompiler, ZkArtifactOutput>, SolcError> {
+    let mut project = config_create_project(config, cached, no_artifacts)?;
+    project
+        .settings
+        .settings
+        .output_selection
+        .all
+        .per_contract
+        .insert(OutputSelectionFlag::EraVMAssembly);
+    Ok(project)
+}
+
 /// Returns solc compiler to use along zksolc using the following rules:
 /// 1. If `solc_path` in zksync config options is set, use it.
 /// 2. If `solc_path` is not set, check the `solc` requirements: a. If a version is specified, use
@@ -316,8 +463,14 @@ fn config_solc_compiler(config: &Config) -> Result<SolcCompiler, SolcError> {
 
 /// Returns the `ProjectPathsConfig` sub set of the config.
 pub fn config_project_paths(config: &Config) -> ProjectPathsConfig<SolcLanguage> {
+    project_paths(config, ZKSYNC_SOLIDITY_FILES_CACHE_FILENAME)
+}
+
+/// Returns the `ProjectPathsConfig` for `L`, with its own cache file and the zksync artifacts
+/// directory shared by all zk compilers.
+fn project_paths<L>(config: &Config, cache_filename: &str) -> ProjectPathsConfig<L> {
     let builder = ProjectPathsConfig::builder()
-        .cache(config.cache_path.join(ZKSYNC_SOLIDITY_FILES_CACHE_FILENAME))
+        .cache(config.cache_path.join(cache_filename))
         .sources(&config.src)
         .tests(&config.test)
         .scripts(&config.script)
@@ -332,6 +485,37 @@ pub fn config_project_paths(config: &Config) -> ProjectPathsConfig<SolcLanguage>
     builder.build_with_root(&config.root)
 }
 
+/// Ensures that the configured `zkvyper` version is installed if explicitly set, see
+/// [`config_ensure_zksolc`].
+pub fn config_ensure_zkvyper(
+    zkvyper: Option<&SolcReq>,
+    offline: bool,
+) -> Result<Option<PathBuf>, SolcError> {
+    let Some(zkvyper) = zkvyper else { return Ok(None) };
+
+    let zkvyper = match zkvyper {
+        SolcReq::Version(version) => match ZkVyper::find_installed_version(version)? {
+            Some(path) => path,
+            None if offline => {
+                return Err(SolcError::msg(format!(
+                    "can't install missing zkvyper {version} in offline mode"
+                )));
+            }
+            None => ZkVyper::blocking_install(version)?,
+        },
+        SolcReq::Local(zkvyper) => {
+            if !zkvyper.is_file() {
+                return Err(SolcError::msg(format!(
+                    "`zkvyper` {} does not exist",
+                    zkvyper.display()
+                )));
+            }
+            zkvyper.clone()
+        }
+    };
+    Ok(Some(zkvyper))
+}
+
 /// Ensures that the configured version is installed if explicitly set
 ///
 /// If `zksolc` is [`SolcReq::Version`] then this will download and install the solc version if
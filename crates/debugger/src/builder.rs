@@ -31,7 +31,7 @@ pub fn new() -> Self {
     #[inline]
     pub fn traces(mut self, traces: Traces) -> Self {
         for (_, arena) in traces {
-            self = self.trace_arena(arena.arena);
+            flatten_call_trace(arena.arena, arena.zk_steps, &mut self.debug_arena);
         }
         self
     }
@@ -39,7 +39,7 @@ pub fn traces(mut self, traces: Traces) -> Self {
     /// Extends the debug arena.
     #[inline]
     pub fn trace_arena(mut self, arena: CallTraceArena) -> Self {
-        flatten_call_trace(arena, &mut self.debug_arena);
+        flatten_call_trace(arena, Default::default(), &mut self.debug_arena);
         self
     }
 
@@ -1,5 +1,5 @@
-use alloy_primitives::{Address, Bytes};
-use foundry_evm_traces::{CallKind, CallTraceArena};
+use alloy_primitives::{Address, Bytes, map::HashMap};
+use foundry_evm_traces::{CallKind, CallTraceArena, CallTraceNode, ZkDebugStep};
 use revm_inspectors::tracing::types::{CallTraceStep, TraceMemberOrder};
 use serde::{Deserialize, Serialize};
 
@@ -18,6 +18,10 @@ pub struct DebugNode {
     pub gas_limit: u64,
     /// The debug steps.
     pub steps: Vec<CallTraceStep>,
+    /// The EraVM debug steps, set instead of `steps` for code executed in EraVM.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub zk_steps: Vec<ZkDebugStep>,
 }
 
 impl DebugNode {
@@ -29,15 +33,30 @@ pub fn new(
         calldata: Bytes,
         gas_limit: u64,
     ) -> Self {
-        Self { address, kind, steps, calldata, gas_limit }
+        Self { address, kind, steps, calldata, gas_limit, zk_steps: Vec::new() }
+    }
+
+    /// Returns whether the node was executed in EraVM.
+    pub fn is_zk(&self) -> bool {
+        !self.zk_steps.is_empty()
+    }
+
+    /// Returns the number of debug steps.
+    pub fn n_steps(&self) -> usize {
+        if self.is_zk() { self.zk_steps.len() } else { self.steps.len() }
     }
 }
 
 /// Flattens given [CallTraceArena] into a list of [DebugNode]s.
 ///
 /// This is done by recursively traversing the call tree and collecting the steps in-between the
-/// calls.
-pub fn flatten_call_trace(arena: CallTraceArena, out: &mut Vec<DebugNode>) {
+/// calls. Calls executed in EraVM have no EVM steps, their EraVM steps from `zk_steps` are
+/// flattened instead, see [flatten_zk_steps].
+pub fn flatten_call_trace(
+    arena: CallTraceArena,
+    mut zk_steps: HashMap<usize, Vec<ZkDebugStep>>,
+    out: &mut Vec<DebugNode>,
+) {
     #[derive(Debug, Clone, Copy)]
     struct PendingNode {
         node_idx: usize,
@@ -74,6 +93,10 @@ fn inner(arena: &CallTraceArena, node_idx: usize, out: &mut Vec<PendingNode>) {
             std::mem::replace(&mut arena_nodes[pending.node_idx].trace.steps, other_steps)
         };
 
+        if let Some(zk_steps) = zk_steps.remove(&pending.node_idx) {
+            flatten_zk_steps(&arena_nodes[pending.node_idx], zk_steps, out);
+        }
+
         // Skip nodes with empty steps as there's nothing to display for them.
         if steps.is_empty() {
             continue;
@@ -86,3 +109,31 @@ fn inner(arena: &CallTraceArena, node_idx: usize, out: &mut Vec<PendingNode>) {
         out.push(node);
     }
 }
+
+/// Splits the EraVM steps executed for the given call into a [DebugNode] per contract.
+///
+/// A new node is started whenever the executed contract changes, i.e. on far calls and returns.
+fn flatten_zk_steps(node: &CallTraceNode, steps: Vec<ZkDebugStep>, out: &mut Vec<DebugNode>) {
+    let call = &node.trace;
+    let mut steps = steps.into_iter().peekable();
+    let mut first = true;
+    while let Some(step) = steps.next() {
+        let address = step.contract;
+        let gas_limit = step.gas_remaining;
+        let mut zk_steps = vec![step];
+        while let Some(step) = steps.next_if(|step| step.contract == address) {
+            zk_steps.push(step);
+        }
+
+        // Only the first node is known to be the intercepted call.
+        let (kind, calldata) = if first {
+            let calldata = if call.kind.is_any_create() { Bytes::new() } else { call.data.clone() };
+            (call.kind, calldata)
+        } else {
+            (CallKind::Call, Bytes::new())
+        };
+        first = false;
+
+        out.push(DebugNode { address, kind, calldata, gas_limit, steps: Vec::new(), zk_steps });
+    }
+}
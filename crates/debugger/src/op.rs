@@ -1,9 +1,11 @@
-/// Named parameter of an EVM opcode.
+/// Named parameter of an EVM opcode, or named register of an EraVM opcode.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub(crate) struct OpcodeParam {
     /// The name of the parameter.
     pub(crate) name: &'static str,
     /// The index of the parameter on the stack. This is relative to the top of the stack.
+    ///
+    /// For EraVM opcodes, this is the index of the register.
     pub(crate) index: usize,
 }
 
@@ -16,6 +18,24 @@ pub(crate) fn of(op: u8) -> &'static [Self] {
     }
 }
 
+impl OpcodeParam {
+    /// Returns the list of named registers for the given EraVM opcode mnemonic. The index is the
+    /// index of the register, starting at `r1`.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    pub(crate) fn of_eravm(opcode: &str) -> &'static [Self] {
+        const fn reg(index: usize, name: &'static str) -> OpcodeParam {
+            OpcodeParam { name, index }
+        }
+
+        match opcode.split_once('.').unwrap_or((opcode, "")) {
+            ("far_call", "mimic") => &[reg(0, "abi"), reg(1, "address"), reg(14, "mimic_address")],
+            ("far_call", _) => &[reg(0, "abi"), reg(1, "address")],
+            ("ret", _) => &[reg(0, "returndata")],
+            _ => &[],
+        }
+    }
+}
+
 static MAP: [&[OpcodeParam]; 256] = {
     let mut table = [[].as_slice(); 256];
     let mut i = 0;
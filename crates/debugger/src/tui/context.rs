@@ -4,6 +4,7 @@
 use alloy_primitives::{Address, hex};
 use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 use foundry_evm_core::buffer::BufferKind;
+use foundry_evm_traces::ZkDebugStep;
 use revm::bytecode::opcode::OpCode;
 use revm_inspectors::tracing::types::{CallKind, CallTraceStep};
 use std::ops::ControlFlow;
@@ -85,13 +86,20 @@ pub(crate) fn current_step(&self) -> &CallTraceStep {
         &self.debug_steps()[self.current_step]
     }
 
+    /// Returns the current EraVM debug step, if the current call was executed in EraVM.
+    pub(crate) fn current_zk_step(&self) -> Option<&ZkDebugStep> {
+        self.debug_call().zk_steps.get(self.current_step)
+    }
+
     fn gen_opcode_list(&mut self) {
         self.opcode_list.clear();
-        let debug_steps =
-            &self.debugger_context.debug_arena[self.draw_memory.inner_call_index].steps;
-        for step in debug_steps {
+        let debug_call = &self.debugger_context.debug_arena[self.draw_memory.inner_call_index];
+        for step in &debug_call.steps {
             self.opcode_list.push(pretty_opcode(step));
         }
+        for step in &debug_call.zk_steps {
+            self.opcode_list.push(step.opcode.clone());
+        }
     }
 
     fn gen_opcode_list_if_necessary(&mut self) {
@@ -102,6 +110,13 @@ fn gen_opcode_list_if_necessary(&mut self) {
     }
 
     fn active_buffer(&self) -> &[u8] {
+        if let Some(step) = self.current_zk_step() {
+            return match self.active_buffer {
+                BufferKind::Memory => &step.heap,
+                BufferKind::Calldata => &self.debug_call().calldata,
+                BufferKind::Returndata => &step.aux_heap,
+            };
+        }
         match self.active_buffer {
             BufferKind::Memory => self.current_step().memory.as_ref().unwrap().as_bytes(),
             BufferKind::Calldata => &self.debug_call().calldata,
@@ -163,8 +178,7 @@ fn handle_key_event(&mut self, event: KeyEvent) -> ControlFlow<ExitReason> {
             }),
             // Scroll down the stack
             KeyCode::Char('J') => self.repeat(|this| {
-                let max_stack =
-                    this.current_step().stack.as_ref().map_or(0, |s| s.len()).saturating_sub(1);
+                let max_stack = this.stack_len().saturating_sub(1);
                 if this.draw_memory.current_stack_startline < max_stack {
                     this.draw_memory.current_stack_startline += 1;
                 }
@@ -205,31 +219,16 @@ fn handle_key_event(&mut self, event: KeyEvent) -> ControlFlow<ExitReason> {
 
             // Step forward
             KeyCode::Char('s') => self.repeat(|this| {
-                let remaining_steps = &this.debug_steps()[this.current_step..];
-                if let Some((i, _)) =
-                    remaining_steps.iter().enumerate().skip(1).find(|(i, step)| {
-                        let prev = &remaining_steps[*i - 1];
-                        is_jump(step, prev)
-                    })
+                if let Some(i) = (this.current_step + 1..this.n_steps()).find(|&i| this.is_jump(i))
                 {
-                    this.current_step += i
+                    this.current_step = i
                 }
             }),
 
             // Step backwards
             KeyCode::Char('a') => self.repeat(|this| {
-                let ops = &this.debug_steps()[..this.current_step];
-                this.current_step = ops
-                    .iter()
-                    .enumerate()
-                    .skip(1)
-                    .rev()
-                    .find(|&(i, op)| {
-                        let prev = &ops[i - 1];
-                        is_jump(op, prev)
-                    })
-                    .map(|(i, _)| i)
-                    .unwrap_or_default();
+                this.current_step =
+                    (1..this.current_step).rev().find(|&i| this.is_jump(i)).unwrap_or_default();
             }),
 
             // Toggle stack labels
@@ -264,7 +263,11 @@ fn handle_breakpoint(&mut self, c: char) {
         if let Some((caller, pc)) = self.debugger_context.breakpoints.get(&c) {
             for (i, node) in self.debug_arena().iter().enumerate() {
                 if node.address == *caller
-                    && let Some(step) = node.steps.iter().position(|step| step.pc == *pc)
+                    && let Some(step) = node
+                        .steps
+                        .iter()
+                        .position(|step| step.pc == *pc)
+                        .or_else(|| node.zk_steps.iter().position(|step| step.pc == *pc))
                 {
                     self.draw_memory.inner_call_index = i;
                     self.current_step = step;
@@ -311,7 +314,25 @@ fn repeat(&mut self, mut f: impl FnMut(&mut Self)) {
     }
 
     fn n_steps(&self) -> usize {
-        self.debug_steps().len()
+        self.debug_call().n_steps()
+    }
+
+    /// Returns the number of stack items of the current step, including the registers for EraVM.
+    pub(crate) fn stack_len(&self) -> usize {
+        match self.current_zk_step() {
+            Some(step) => step.registers.len() + step.stack.len(),
+            None => self.current_step().stack.as_ref().map_or(0, |s| s.len()),
+        }
+    }
+
+    /// Returns whether the step at the given index was reached by jumping from the previous step.
+    fn is_jump(&self, i: usize) -> bool {
+        let call = self.debug_call();
+        if call.is_zk() {
+            is_zk_jump(&call.zk_steps[i], &call.zk_steps[i - 1])
+        } else {
+            is_jump(&call.steps[i], &call.steps[i - 1])
+        }
     }
 }
 
@@ -339,3 +360,11 @@ fn is_jump(step: &CallTraceStep, prev: &CallTraceStep) -> bool {
 
     step.pc != prev.pc + 1 + immediate_len
 }
+
+fn is_zk_jump(step: &ZkDebugStep, prev: &ZkDebugStep) -> bool {
+    if !prev.opcode.starts_with("jump") {
+        return false;
+    }
+
+    step.pc != prev.pc + 1
+}
@@ -4,7 +4,7 @@
 use crate::op::OpcodeParam;
 use foundry_compilers::artifacts::sourcemap::SourceElement;
 use foundry_evm_core::buffer::{BufferKind, get_buffer_accesses};
-use foundry_evm_traces::debug::SourceData;
+use foundry_evm_traces::{ZkDebugStep, debug::SourceData};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -342,6 +342,9 @@ fn src_map(&self) -> Result<(SourceElement, &SourceData), String> {
         let Some(contract_name) = self.debugger_context.identified_contracts.get(address) else {
             return Err(format!("Unknown contract at address {address}"));
         };
+        if self.debug_call().is_zk() {
+            return Err(format!("No source map for EraVM contract {contract_name}"));
+        }
 
         self.debugger_context
             .contracts_sources
@@ -354,16 +357,20 @@ fn src_map(&self) -> Result<(SourceElement, &SourceData), String> {
     }
 
     fn draw_op_list(&self, f: &mut Frame<'_>, area: Rect) {
-        let debug_steps = self.debug_steps();
-        let max_pc = debug_steps.iter().map(|step| step.pc).max().unwrap_or(0);
+        let debug_call = self.debug_call();
+        let pcs = debug_call
+            .steps
+            .iter()
+            .map(|step| step.pc)
+            .chain(debug_call.zk_steps.iter().map(|step| step.pc));
+        let max_pc = pcs.clone().max().unwrap_or(0);
         let max_pc_len = hex_digits(max_pc);
 
-        let items = debug_steps
-            .iter()
+        let items = pcs
             .enumerate()
-            .map(|(i, step)| {
+            .map(|(i, pc)| {
                 let mut content = String::with_capacity(64);
-                write!(content, "{:0>max_pc_len$x}|", step.pc).unwrap();
+                write!(content, "{pc:0>max_pc_len$x}|").unwrap();
                 if let Some(op) = self.opcode_list.get(i) {
                     content.push_str(op);
                 }
@@ -371,13 +378,22 @@ fn draw_op_list(&self, f: &mut Frame<'_>, area: Rect) {
             })
             .collect::<Vec<_>>();
 
-        let title = format!(
-            "Address: {} | PC: {} | Gas used: {} | Gas refund: {}",
-            self.address(),
-            self.current_step().pc,
-            self.debug_call().gas_limit - self.current_step().gas_remaining,
-            self.current_step().gas_refund_counter
-        );
+        let title = if let Some(step) = self.current_zk_step() {
+            format!(
+                "Address: {} | PC: {} | Ergs used: {}",
+                self.address(),
+                step.pc,
+                debug_call.gas_limit.saturating_sub(step.gas_remaining),
+            )
+        } else {
+            format!(
+                "Address: {} | PC: {} | Gas used: {} | Gas refund: {}",
+                self.address(),
+                self.current_step().pc,
+                debug_call.gas_limit - self.current_step().gas_remaining,
+                self.current_step().gas_refund_counter
+            )
+        };
         let block = Block::default().title(title).borders(Borders::ALL);
         let list = List::new(items)
             .block(block)
@@ -389,6 +405,11 @@ fn draw_op_list(&self, f: &mut Frame<'_>, area: Rect) {
     }
 
     fn draw_stack(&self, f: &mut Frame<'_>, area: Rect) {
+        if let Some(step) = self.current_zk_step() {
+            self.draw_zk_stack(f, area, step);
+            return;
+        }
+
         let step = self.current_step();
         let stack = step.stack.as_ref();
         let stack_len = stack.map_or(0, |s| s.len());
@@ -444,13 +465,78 @@ fn draw_stack(&self, f: &mut Frame<'_>, area: Rect) {
         f.render_widget(paragraph, area);
     }
 
+    /// Draws the registers of an EraVM step, followed by its stack.
+    fn draw_zk_stack(&self, f: &mut Frame<'_>, area: Rect, step: &ZkDebugStep) {
+        let min_len = decimal_digits(step.stack.len()).max(2) + 1;
+
+        let params = OpcodeParam::of_eravm(&step.opcode);
+        let registers = step.registers.iter().enumerate().map(|(i, item)| {
+            (format!("r{}", i + 1), item, params.iter().find(|param| param.index == i))
+        });
+        let stack =
+            step.stack.iter().rev().enumerate().map(|(i, item)| (format!("s{i}"), item, None));
+
+        let text: Vec<Line<'_>> = registers
+            .chain(stack)
+            .skip(self.draw_memory.current_stack_startline)
+            .map(|(name, item, param)| {
+                let mut spans = Vec::with_capacity(1 + 32 * 2 + 3);
+
+                // Register name or stack index.
+                spans.push(Span::styled(
+                    format!("{name:>min_len$}| "),
+                    Style::new().fg(Color::White),
+                ));
+
+                // Item hex bytes.
+                hex_bytes_spans(&item.to_be_bytes::<32>(), &mut spans, |_, _| {
+                    if param.is_some() {
+                        Style::new().fg(Color::Cyan)
+                    } else {
+                        Style::new().fg(Color::White)
+                    }
+                });
+
+                if self.stack_labels
+                    && let Some(param) = param
+                {
+                    spans.push(Span::raw("| "));
+                    spans.push(Span::raw(param.name));
+                }
+
+                spans.push(Span::raw("\n"));
+
+                Line::from(spans)
+            })
+            .collect();
+
+        let title = format!("Registers: {} | Stack: {}", step.registers.len(), step.stack.len());
+        let block = Block::default().title(title).borders(Borders::ALL);
+        let paragraph = Paragraph::new(text).block(block).wrap(Wrap { trim: true });
+        f.render_widget(paragraph, area);
+    }
+
     fn draw_buffer(&self, f: &mut Frame<'_>, area: Rect) {
         let call = self.debug_call();
-        let step = self.current_step();
-        let buf = match self.active_buffer {
-            BufferKind::Memory => step.memory.as_ref().unwrap().as_ref(),
-            BufferKind::Calldata => call.calldata.as_ref(),
-            BufferKind::Returndata => step.returndata.as_ref(),
+        let (buf, step) = match self.current_zk_step() {
+            Some(zk_step) => {
+                let buf = match self.active_buffer {
+                    BufferKind::Memory => zk_step.heap.as_ref(),
+                    BufferKind::Calldata => call.calldata.as_ref(),
+                    BufferKind::Returndata => zk_step.aux_heap.as_ref(),
+                };
+                // Buffer accesses are only decoded for EVM opcodes.
+                (buf, None)
+            }
+            None => {
+                let step = self.current_step();
+                let buf = match self.active_buffer {
+                    BufferKind::Memory => step.memory.as_ref().unwrap().as_ref(),
+                    BufferKind::Calldata => call.calldata.as_ref(),
+                    BufferKind::Returndata => step.returndata.as_ref(),
+                };
+                (buf, Some(step))
+            }
         };
 
         let min_len = hex_digits(buf.len());
@@ -461,8 +547,9 @@ fn draw_buffer(&self, f: &mut Frame<'_>, area: Rect) {
         let mut write_offset = None;
         let mut write_size = None;
         let mut color = None;
-        let stack_len = step.stack.as_ref().map_or(0, |s| s.len());
+        let stack_len = step.and_then(|step| step.stack.as_ref()).map_or(0, |s| s.len());
         if stack_len > 0
+            && let Some(step) = step
             && let Some(stack) = step.stack.as_ref()
             && let Some(accesses) = get_buffer_accesses(step.op.get(), stack)
         {
@@ -483,7 +570,7 @@ fn draw_buffer(&self, f: &mut Frame<'_>, area: Rect) {
         // TODO: technically it's possible for this to conflict with the current op, ie, with
         // subsequent MCOPYs, but solc can't seem to generate that code even with high optimizer
         // settings
-        if self.current_step > 0 {
+        if step.is_some() && self.current_step > 0 {
             let prev_step = self.current_step - 1;
             let prev_step = &self.debug_steps()[prev_step];
             if let Some(stack) = prev_step.stack.as_ref()
@@ -571,7 +658,11 @@ fn draw_buffer(&self, f: &mut Frame<'_>, area: Rect) {
             })
             .collect();
 
-        let title = self.active_buffer.title(buf.len());
+        let title = match (call.is_zk(), self.active_buffer) {
+            (true, BufferKind::Memory) => format!("Heap (size: {} bytes)", buf.len()),
+            (true, BufferKind::Returndata) => format!("Aux heap (size: {} bytes)", buf.len()),
+            _ => self.active_buffer.title(buf.len()),
+        };
         let block = Block::default().title(title).borders(Borders::ALL);
         let paragraph = Paragraph::new(text).block(block).wrap(Wrap { trim: true });
         f.render_widget(paragraph, area);
@@ -93,10 +93,67 @@ fn zksync_set_deployer_call_input(
     fn trace_zksync(
         &mut self,
         _context: Ecx<'_, '_, '_>,
-        _call_traces: Box<dyn std::any::Any>, // holds `Vec<Call>`
+        _call_traces: Box<dyn std::any::Any>, // holds `ZkCallTraces`
         _record_top_call: bool,
     ) {
     }
+
+    /// Returns whether line coverage should be recorded for zksync executions.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    fn zksync_record_coverage(&self) -> bool {
+        false
+    }
+
+    /// Appends provided zksync line coverage.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    fn coverage_zksync(
+        &mut self,
+        _hit_maps: Box<dyn std::any::Any>, // holds `HitMaps`
+    ) {
+    }
+
+    /// Returns whether edge coverage should be recorded for zksync executions.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    fn zksync_record_edge_coverage(&self) -> bool {
+        false
+    }
+
+    /// Appends provided zksync edge coverage.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    fn edge_coverage_zksync(
+        &mut self,
+        _edge_hits: Box<dyn std::any::Any>, // holds `HashMap<u64, u32>`
+    ) {
+    }
+
+    /// Records the pubdata published by the zksync call currently being traced.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    fn pubdata_zksync(
+        &mut self,
+        _pubdata: Box<dyn std::any::Any>, // holds `ZkPubdataUsage`
+    ) {
+    }
+
+    /// Returns whether executed instructions should be recorded for zksync executions.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    fn zksync_record_steps(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the near calls and pubdata of every frame should be recorded for zksync
+    /// executions, to render the full call tree.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    fn zksync_record_call_tree(&self) -> bool {
+        false
+    }
+
+    /// Records the instructions executed by the zksync call currently being traced.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    fn steps_zksync(
+        &mut self,
+        _steps: Box<dyn std::any::Any>, // holds `Vec<ZkVmStep>`
+    ) {
+    }
 }
 
 impl InspectorExt for NoOpInspector {}
@@ -56,6 +56,15 @@ pub fn finish(self) -> HitMaps {
         self.maps
     }
 
+    /// Merges externally collected [`HitMaps`] into the collected coverage.
+    pub fn merge(&mut self, other: HitMaps) {
+        self.maps.merge(other);
+        // Merging may reallocate `maps`, so `current_map` has to be looked up again.
+        // See comments on `current_map`.
+        self.current_hash = B256::ZERO;
+        self.current_map = NonNull::dangling();
+    }
+
     /// Gets the hit map for the current contract, or inserts a new one if it doesn't exist.
     ///
     /// The map is stored in `current_map` and returned as a mutable reference.
@@ -133,20 +133,26 @@ pub fn add_hit_map(
         // Add source level hits.
         if let Some(anchors) = self.anchors.get(contract_id) {
             let anchors = if is_deployed_code { &anchors.1 } else { &anchors.0 };
-            for anchor in anchors {
-                if let Some(hits) = hit_map.get(anchor.instruction) {
-                    self.analyses
-                        .get_mut(&contract_id.version)
-                        .and_then(|items| items.all_items_mut().get_mut(anchor.item_id as usize))
-                        .expect("Anchor refers to non-existent coverage item")
-                        .hits += hits.get();
-                }
-            }
+            add_anchored_hits(&mut self.analyses, &contract_id.version, anchors, hit_map);
         }
 
         Ok(())
     }
 
+    /// Processes data from a [`HitMap`] using the given anchors instead of the ones added with
+    /// [`add_anchors`](Self::add_anchors).
+    ///
+    /// This is used for bytecode that is not described by the source maps, e.g. EraVM bytecode.
+    /// Bytecode level hits are not recorded.
+    pub fn add_hit_map_with_anchors(
+        &mut self,
+        version: &Version,
+        anchors: &[ItemAnchor],
+        hit_map: &HitMap,
+    ) {
+        add_anchored_hits(&mut self.analyses, version, anchors, hit_map);
+    }
+
     /// Retains all the coverage items specified by `predicate`.
     ///
     /// This function should only be called after all the sources were used, otherwise, the output
@@ -162,6 +168,35 @@ pub fn retain_sources(&mut self, mut predicate: impl FnMut(&Path) -> bool) {
             !analysis.all_items().is_empty()
         });
     }
+
+    /// Keeps only the coverage items matching `predicate`, which is also given the compiler
+    /// version of the item's analysis. Analyses left without items are removed.
+    ///
+    /// Item IDs are shifted by the removal, so hit maps can't be added afterwards.
+    pub fn retain_items(&mut self, mut predicate: impl FnMut(&Version, &CoverageItem) -> bool) {
+        self.analyses.retain(|version, analysis| {
+            analysis.all_items_mut().retain(|item| predicate(version, item));
+            !analysis.all_items().is_empty()
+        });
+    }
+}
+
+/// Sets hit counts for the coverage items referred to by `anchors`.
+fn add_anchored_hits(
+    analyses: &mut HashMap<Version, SourceAnalysis>,
+    version: &Version,
+    anchors: &[ItemAnchor],
+    hit_map: &HitMap,
+) {
+    for anchor in anchors {
+        if let Some(hits) = hit_map.get(anchor.instruction) {
+            analyses
+                .get_mut(version)
+                .and_then(|items| items.all_items_mut().get_mut(anchor.item_id as usize))
+                .expect("Anchor refers to non-existent coverage item")
+                .hits += hits.get();
+        }
+    }
 }
 
 /// A collection of [`HitMap`]s.
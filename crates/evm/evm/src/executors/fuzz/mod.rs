@@ -379,7 +379,7 @@ fn aggregate_results(
             if self.config.show_logs {
                 result.logs.append(&mut worker.logs);
             }
-            result.gas_report_traces.extend(worker.traces.into_iter().map(|t| t.arena));
+            result.gas_report_traces.append(&mut worker.traces);
             HitMaps::merge_opt(&mut result.line_coverage, worker.coverage);
             result.deprecated_cheatcodes.extend(worker.deprecated_cheatcodes);
         }
@@ -31,7 +31,7 @@
     },
     strategies::{EvmFuzzState, invariant_strat, override_call_strat},
 };
-use foundry_evm_traces::{CallTraceArena, SparsedTraceArena};
+use foundry_evm_traces::SparsedTraceArena;
 use indicatif::ProgressBar;
 use parking_lot::RwLock;
 use proptest::{strategy::Strategy, test_runner::TestRunner};
@@ -132,7 +132,7 @@ struct InvariantTestData {
     // Calldata in the last invariant run.
     last_run_inputs: Vec<BasicTxDetails>,
     // Additional traces for gas report.
-    gas_report_traces: Vec<Vec<CallTraceArena>>,
+    gas_report_traces: Vec<Vec<SparsedTraceArena>>,
     // Last call results of the invariant test.
     last_call_results: Option<RawCallResult>,
     // Line coverage information collected from all fuzzed calls.
@@ -245,9 +245,7 @@ fn end_run(&mut self, run: InvariantTestRun, gas_samples: usize) {
         self.targeted_contracts.clear_created_contracts(run.created_contracts);
 
         if self.test_data.gas_report_traces.len() < gas_samples {
-            self.test_data
-                .gas_report_traces
-                .push(run.run_traces.into_iter().map(|arena| arena.arena).collect());
+            self.test_data.gas_report_traces.push(run.run_traces);
         }
         self.test_data.fuzz_cases.push(FuzzedCases::new(run.fuzz_runs));
 
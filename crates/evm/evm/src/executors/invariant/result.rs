@@ -13,7 +13,7 @@
     BasicTxDetails, FuzzedCases,
     invariant::{FuzzRunIdentifiedContracts, InvariantContract},
 };
-use revm_inspectors::tracing::CallTraceArena;
+use foundry_evm_traces::SparsedTraceArena;
 use std::{borrow::Cow, collections::HashMap};
 
 /// The outcome of an invariant fuzz test
@@ -28,7 +28,7 @@ pub struct InvariantFuzzTestResult {
     /// replaying the run for collecting traces.
     pub last_run_inputs: Vec<BasicTxDetails>,
     /// Additional traces used for gas report construction.
-    pub gas_report_traces: Vec<Vec<CallTraceArena>>,
+    pub gas_report_traces: Vec<Vec<SparsedTraceArena>>,
     /// The coverage info collected during the invariant test runs.
     pub line_coverage: Option<HitMaps>,
     /// Fuzzed selectors metrics collected during the invariant test runs.
@@ -602,6 +602,7 @@ fn commit(&mut self, result: &mut RawCallResult) {
             // Clear broadcastable transactions
             cheats.broadcastable_transactions.clear();
             cheats.ignored_traces.ignored.clear();
+            cheats.zk_pubdata = None;
 
             // if tracing was paused but never unpaused, we should begin next frame with tracing
             // still paused
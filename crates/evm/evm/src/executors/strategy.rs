@@ -149,6 +149,14 @@ fn zksync_set_compilation_output(
     ) {
     }
 
+    /// Get the zksolc compilation output set on the context, if any.
+    fn zksync_compilation_output<'a>(
+        &self,
+        _ctx: &'a dyn ExecutorStrategyContext,
+    ) -> Option<&'a ProjectCompileOutput<ZkSolcCompiler, ZkArtifactOutput>> {
+        None
+    }
+
     /// Set the fork environment on the context.
     fn zksync_set_fork_env(
         &self,
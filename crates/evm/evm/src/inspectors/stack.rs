@@ -68,6 +68,9 @@ pub struct InspectorStackBuilder {
     pub line_coverage: Option<bool>,
     /// Whether to print all opcode traces into the console. Useful for debugging the EVM.
     pub print: Option<bool>,
+    /// Whether the tracer records the full EraVM call tree, including system contract frames.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    pub zk_call_tree: bool,
     /// The chisel state inspector.
     pub chisel_state: Option<usize>,
     /// Whether to enable call isolation.
@@ -169,6 +172,13 @@ pub fn trace_mode(mut self, mode: TraceMode) -> Self {
         self
     }
 
+    /// Set whether the tracer records the full EraVM call tree.
+    #[inline]
+    pub fn zk_call_tree(mut self, yes: bool) -> Self {
+        self.zk_call_tree = yes;
+        self
+    }
+
     /// Set whether to enable the call isolation.
     /// For description of call isolation, see [`InspectorStack::enable_isolation`].
     #[inline]
@@ -202,6 +212,7 @@ pub fn build(self) -> InspectorStack {
             logs,
             line_coverage,
             print,
+            zk_call_tree,
             chisel_state,
             enable_isolation,
             networks,
@@ -235,6 +246,7 @@ pub fn build(self) -> InspectorStack {
         stack.collect_logs(logs);
         stack.print(print.unwrap_or(false));
         stack.tracing(trace_mode);
+        stack.zk_call_tree(zk_call_tree);
 
         stack.enable_isolation(enable_isolation);
         stack.networks(networks);
@@ -334,6 +346,8 @@ pub struct InspectorStackInner {
     // `if let Some` checks.
     pub chisel_state: Option<Box<ChiselState>>,
     pub edge_coverage: Option<Box<EdgeCovInspector>>,
+    /// Edge hits recorded in EraVM, merged into the edge coverage on collect.
+    pub zk_edge_coverage: Option<Box<HashMap<u64, u32>>>,
     pub fuzzer: Option<Box<Fuzzer>>,
     pub line_coverage: Option<Box<LineCoverageCollector>>,
     pub log_collector: Option<Box<LogCollector>>,
@@ -473,6 +487,7 @@ pub fn collect_line_coverage(&mut self, yes: bool) {
     pub fn collect_edge_coverage(&mut self, yes: bool) {
         // TODO: configurable edge size?
         self.edge_coverage = yes.then(EdgeCovInspector::new).map(Into::into);
+        self.zk_edge_coverage = yes.then(Default::default);
     }
 
     /// Set whether to enable call isolation.
@@ -527,6 +542,15 @@ pub fn tracing(&mut self, mode: TraceMode) {
         }
     }
 
+    /// Set whether the tracer records the full EraVM call tree, including the bootloader, account
+    /// and system contract frames along with near calls.
+    #[inline]
+    pub fn zk_call_tree(&mut self, yes: bool) {
+        if let Some(tracer) = &mut self.tracer {
+            tracer.set_record_zk_call_tree(yes);
+        }
+    }
+
     /// Set whether to enable script execution inspector.
     #[inline]
     pub fn script(&mut self, script_address: Address) {
@@ -554,6 +578,7 @@ pub fn collect(self) -> InspectorData {
                     chisel_state,
                     line_coverage,
                     edge_coverage,
+                    zk_edge_coverage,
                     log_collector,
                     tracer,
                     reverter,
@@ -561,23 +586,30 @@ pub fn collect(self) -> InspectorData {
                 },
         } = self;
 
-        let traces = tracer.map(|tracer| tracer.into_traces()).map(|arena| {
-            let ignored = cheatcodes
-                .as_mut()
-                .map(|cheatcodes| {
-                    let mut ignored = std::mem::take(&mut cheatcodes.ignored_traces.ignored);
+        let traces =
+            tracer.map(|tracer| tracer.into_parts()).map(|(arena, pubdata, steps, frames)| {
+                let ignored = cheatcodes
+                    .as_mut()
+                    .map(|cheatcodes| {
+                        let mut ignored = std::mem::take(&mut cheatcodes.ignored_traces.ignored);
 
-                    // If the last pause call was not resumed, ignore the rest of the trace
-                    if let Some(last_pause_call) = cheatcodes.ignored_traces.last_pause_call {
-                        ignored.insert(last_pause_call, (arena.nodes().len(), 0));
-                    }
+                        // If the last pause call was not resumed, ignore the rest of the trace
+                        if let Some(last_pause_call) = cheatcodes.ignored_traces.last_pause_call {
+                            ignored.insert(last_pause_call, (arena.nodes().len(), 0));
+                        }
 
-                    ignored
-                })
-                .unwrap_or_default();
+                        ignored
+                    })
+                    .unwrap_or_default();
 
-            SparsedTraceArena { arena, ignored }
-        });
+                SparsedTraceArena {
+                    arena,
+                    ignored,
+                    zk_pubdata: pubdata,
+                    zk_steps: steps,
+                    zk_frames: frames,
+                }
+            });
 
         InspectorData {
             logs: log_collector.and_then(|logs| logs.into_captured_logs()).unwrap_or_default(),
@@ -587,7 +619,11 @@ pub fn collect(self) -> InspectorData {
                 .unwrap_or_default(),
             traces,
             line_coverage: line_coverage.map(|line_coverage| line_coverage.finish()),
-            edge_coverage: edge_coverage.map(|edge_coverage| edge_coverage.into_hitcount()),
+            edge_coverage: edge_coverage.map(|edge_coverage| {
+                let mut hitcount = edge_coverage.into_hitcount();
+                merge_zk_edge_coverage(&mut hitcount, zk_edge_coverage.as_deref());
+                hitcount
+            }),
             cheatcodes,
             chisel_state: chisel_state.and_then(|state| state.state),
             reverter,
@@ -1197,7 +1233,7 @@ fn trace_zksync(
         &mut self,
         ecx: Ecx<'_, '_, '_>,
         call_traces: Box<dyn std::any::Any>, /* TODO(merge): should be moved elsewhere,
-                                              * represents `Vec<Call>` */
+                                              * represents `ZkCallTraces` */
         record_top_call: bool,
     ) {
         call_inspectors!([&mut self.tracer], |inspector| InspectorExt::trace_zksync(
@@ -1207,6 +1243,52 @@ fn trace_zksync(
             record_top_call
         ));
     }
+
+    fn zksync_record_coverage(&self) -> bool {
+        self.line_coverage.is_some()
+    }
+
+    fn coverage_zksync(&mut self, hit_maps: Box<dyn std::any::Any>) {
+        if let Some(line_coverage) = &mut self.line_coverage
+            && let Ok(hit_maps) = hit_maps.downcast::<HitMaps>()
+        {
+            line_coverage.merge(*hit_maps);
+        }
+    }
+
+    fn zksync_record_edge_coverage(&self) -> bool {
+        self.zk_edge_coverage.is_some()
+    }
+
+    fn edge_coverage_zksync(&mut self, edge_hits: Box<dyn std::any::Any>) {
+        if let Some(zk_edge_coverage) = &mut self.zk_edge_coverage
+            && let Ok(edge_hits) = edge_hits.downcast::<std::collections::HashMap<u64, u32>>()
+        {
+            for (edge, hits) in *edge_hits {
+                *zk_edge_coverage.entry(edge).or_default() += hits;
+            }
+        }
+    }
+
+    fn pubdata_zksync(&mut self, pubdata: Box<dyn std::any::Any>) {
+        call_inspectors!([&mut self.tracer], |inspector| InspectorExt::pubdata_zksync(
+            inspector, pubdata
+        ));
+    }
+
+    fn zksync_record_steps(&self) -> bool {
+        self.tracer.as_ref().is_some_and(|tracer| tracer.zksync_record_steps())
+    }
+
+    fn zksync_record_call_tree(&self) -> bool {
+        self.tracer.as_ref().is_some_and(|tracer| tracer.zksync_record_call_tree())
+    }
+
+    fn steps_zksync(&mut self, steps: Box<dyn std::any::Any>) {
+        call_inspectors!([&mut self.tracer], |inspector| InspectorExt::steps_zksync(
+            inspector, steps
+        ));
+    }
 }
 
 impl Inspector<EthEvmContext<&mut dyn DatabaseExt>> for InspectorStack {
@@ -1315,11 +1397,43 @@ fn trace_zksync(
         &mut self,
         ecx: Ecx<'_, '_, '_>,
         call_traces: Box<dyn std::any::Any>, /* TODO(merge): should be moved elsewhere,
-                                              * represents `Vec<Call>` */
+                                              * represents `ZkCallTraces` */
         record_top_call: bool,
     ) {
         self.as_mut().trace_zksync(ecx, call_traces, record_top_call);
     }
+
+    fn zksync_record_coverage(&self) -> bool {
+        self.line_coverage.is_some()
+    }
+
+    fn coverage_zksync(&mut self, hit_maps: Box<dyn std::any::Any>) {
+        self.as_mut().coverage_zksync(hit_maps);
+    }
+
+    fn zksync_record_edge_coverage(&self) -> bool {
+        self.zk_edge_coverage.is_some()
+    }
+
+    fn edge_coverage_zksync(&mut self, edge_hits: Box<dyn std::any::Any>) {
+        self.as_mut().edge_coverage_zksync(edge_hits);
+    }
+
+    fn pubdata_zksync(&mut self, pubdata: Box<dyn std::any::Any>) {
+        self.as_mut().pubdata_zksync(pubdata);
+    }
+
+    fn zksync_record_steps(&self) -> bool {
+        self.tracer.as_ref().is_some_and(|tracer| tracer.zksync_record_steps())
+    }
+
+    fn zksync_record_call_tree(&self) -> bool {
+        self.tracer.as_ref().is_some_and(|tracer| tracer.zksync_record_call_tree())
+    }
+
+    fn steps_zksync(&mut self, steps: Box<dyn std::any::Any>) {
+        self.as_mut().steps_zksync(steps);
+    }
 }
 
 impl<'a> Deref for InspectorStackRefMut<'a> {
@@ -1349,3 +1463,17 @@ fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.inner
     }
 }
+
+/// Folds the edge hits recorded in EraVM into the EVM edge hitcount map, so coverage-guided
+/// fuzzing sees the branches taken by zk-compiled contracts.
+fn merge_zk_edge_coverage(hitcount: &mut [u8], zk_edge_coverage: Option<&HashMap<u64, u32>>) {
+    let Some(zk_edge_coverage) = zk_edge_coverage else { return };
+    if hitcount.is_empty() {
+        return;
+    }
+    for (edge, hits) in zk_edge_coverage {
+        let idx = (edge % hitcount.len() as u64) as usize;
+        let hits = u8::try_from(*hits).unwrap_or(u8::MAX);
+        hitcount[idx] = hitcount[idx].saturating_add(hits);
+    }
+}
@@ -16,7 +16,7 @@
 use foundry_common::{calc, contracts::ContractsByAddress};
 use foundry_evm_core::Breakpoints;
 use foundry_evm_coverage::HitMaps;
-use foundry_evm_traces::{CallTraceArena, SparsedTraceArena};
+use foundry_evm_traces::SparsedTraceArena;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use std::{fmt, sync::Arc};
@@ -276,7 +276,7 @@ pub struct FuzzTestResult {
 
     /// Additional traces used for gas report construction.
     /// Those traces should not be displayed.
-    pub gas_report_traces: Vec<CallTraceArena>,
+    pub gas_report_traces: Vec<SparsedTraceArena>,
 
     /// Raw line coverage info
     pub line_coverage: Option<HitMaps>,
@@ -27,7 +27,7 @@
     ops::{Deref, DerefMut},
 };
 
-use alloy_primitives::map::HashMap;
+use alloy_primitives::{Address, Bytes, U256, map::HashMap};
 
 pub use revm_inspectors::tracing::{
     CallTraceArena, FourByteInspector, GethTraceBuilder, ParityTraceBuilder, StackSnapshotType,
@@ -66,6 +66,64 @@ pub struct SparsedTraceArena {
     /// See `foundry_cheatcodes::utils::IgnoredTraces` for more information.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub ignored: HashMap<(usize, usize), (usize, usize)>,
+    /// Pubdata published by calls executed in EraVM, keyed by trace node index.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub zk_pubdata: HashMap<usize, ZkPubdata>,
+    /// Instructions executed in EraVM, keyed by the index of the trace node that was executed in
+    /// EraVM. Only recorded by the debugger.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub zk_steps: HashMap<usize, Vec<ZkDebugStep>>,
+    /// EraVM frames of the full call tree, keyed by trace node index. Only recorded at `-vvvvv`.
+    // TODO(merge): Should be moved outside of the upstream codebase
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub zk_frames: HashMap<usize, ZkTraceFrame>,
+}
+
+/// Pubdata published by a call executed in EraVM, as recorded from the `ZkPubdataUsage` of the
+/// zkVM transactions made by the call.
+// TODO(merge): Should be moved outside of the upstream codebase
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZkPubdata {
+    /// Number of pubdata bytes published.
+    pub bytes: u64,
+    /// L1 cost in wei of the published pubdata.
+    pub l1_cost: u128,
+}
+
+/// An EraVM frame recorded in the full call tree.
+// TODO(merge): Should be moved outside of the upstream codebase
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZkTraceFrame {
+    /// Whether the frame is a near call, which runs within the context of its enclosing frame.
+    pub near_call: bool,
+    /// Pubdata bytes spent by the frame, including its successful subcalls.
+    pub pubdata: u64,
+}
+
+/// A single instruction executed in EraVM.
+// TODO(merge): Should be moved outside of the upstream codebase
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZkDebugStep {
+    /// Depth of the callstack, including near calls.
+    pub depth: u64,
+    /// Address of the executed code.
+    pub contract: Address,
+    /// Program counter (instruction index).
+    pub pc: usize,
+    /// Mnemonic of the executed opcode, e.g. `far_call.normal`.
+    pub opcode: String,
+    /// Values of the general purpose registers `r1` to `r15`.
+    pub registers: Vec<U256>,
+    /// Topmost items of the stack, bottom first.
+    pub stack: Vec<U256>,
+    /// Start of the heap of the current frame.
+    pub heap: Bytes,
+    /// Start of the aux heap of the current frame.
+    pub aux_heap: Bytes,
+    /// Ergs remaining in the current frame.
+    pub gas_remaining: u64,
 }
 
 impl SparsedTraceArena {
@@ -207,15 +265,42 @@ pub fn render_trace_arena_inner(
         return serde_json::to_string(&arena.resolve_arena()).expect("Failed to serialize traces");
     }
 
+    let mut resolved = arena.resolve_arena();
+    if !arena.zk_frames.is_empty() {
+        annotate_zk_frames(resolved.to_mut(), &arena.zk_frames);
+    }
+
     let mut w = TraceWriter::new(Vec::<u8>::new())
         .color_cheatcodes(true)
         .use_colors(convert_color_choice(shell::color_choice()))
         .write_bytecodes(with_bytecodes)
         .with_storage_changes(with_storage_changes);
-    w.write_arena(&arena.resolve_arena()).expect("Failed to write traces");
+    w.write_arena(&resolved).expect("Failed to write traces");
     String::from_utf8(w.into_writer()).expect("trace writer wrote invalid UTF-8")
 }
 
+/// Marks near calls and appends the pubdata spent to the decoded frames of the full EraVM call
+/// tree.
+// TODO(merge): Should be moved outside of the upstream codebase
+fn annotate_zk_frames(arena: &mut CallTraceArena, frames: &HashMap<usize, ZkTraceFrame>) {
+    for (idx, frame) in frames {
+        let Some(node) = arena.nodes_mut().get_mut(*idx) else { continue };
+        let decoded = node.trace.decoded.get_or_insert_with(Default::default);
+        if frame.near_call {
+            decoded.call_data =
+                Some(DecodedCallData { signature: "near_call".to_string(), args: vec![] });
+            decoded.return_data = None;
+        }
+        if frame.pubdata > 0 {
+            let pubdata = format!("[pubdata: {} bytes]", frame.pubdata);
+            decoded.return_data = Some(match decoded.return_data.take() {
+                Some(data) if !data.is_empty() => format!("{data} {pubdata}"),
+                _ => pubdata,
+            });
+        }
+    }
+}
+
 fn convert_color_choice(choice: shell::ColorChoice) -> revm_inspectors::ColorChoice {
     match choice {
         shell::ColorChoice::Auto => revm_inspectors::ColorChoice::Auto,
@@ -151,7 +151,6 @@ pub async fn run(self) -> Result<()> {
                 .files(files)
                 .print_names(self.names)
                 .print_sizes(self.sizes)
-                .zksync_sizes()
                 .bail(!format_json);
 
             let zk_output = zk_compiler.zksync_compile(&zk_project)?;
@@ -160,6 +159,26 @@ pub async fn run(self) -> Result<()> {
                 sh_println!("{}", serde_json::to_string_pretty(&zk_output.output())?)?;
             }
 
+            // Vyper sources are compiled with zkvyper into the same artifacts directory.
+            if self.paths.is_none()
+                && let Some(zkvyper_project) =
+                    foundry_config::zksync::config_create_zkvyper_project(
+                        &config,
+                        config.cache,
+                        false,
+                    )?
+            {
+                let zkvyper_output = ProjectCompiler::new()
+                    .print_names(self.names)
+                    .print_sizes(self.sizes)
+                    .bail(!format_json)
+                    .zksync_compile_vyper(&zkvyper_project)?;
+
+                if format_json && !self.names && !self.sizes {
+                    sh_println!("{}", serde_json::to_string_pretty(&zkvyper_output.output())?)?;
+                }
+            }
+
             // TODO(zk): We cannot return the zk_output as it does not match the concrete type for
             // solc output. This is safe currently as the output is simply dropped.
         }
@@ -182,7 +201,7 @@ fn lint(
                 .with_severity(if config.lint.severity.is_empty() {
                     None
                 } else {
-                    Some(config.lint.severity.clone())
+                    Some(config.lint.severity_with_zksync(config.zksync.should_compile()))
                 })
                 .without_lints(if config.lint.exclude_lints.is_empty() {
                     None
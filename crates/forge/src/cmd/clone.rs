@@ -35,6 +35,9 @@
 };
 use tracing::trace;
 use url::Url;
+use zksync::ZkSyncExplorerClient;
+
+mod zksync;
 
 /// CloneMetadata stores the metadata that are not included by `foundry.toml` but necessary for a
 /// cloned contract. The metadata can be serialized to a metadata file in the cloned project root.
@@ -68,12 +71,14 @@ pub enum SourceExplorer {
     Etherscan,
     /// Use Sourcify API.
     Sourcify,
+    /// Use the contract verification API of ZKsync block explorers.
+    Zksync,
 }
 
 /// CLI arguments for `forge clone`.
 ///
-/// `forge clone` clones an on-chain contract from block explorers (e.g., Etherscan, Sourcify) in
-/// the following steps:
+/// `forge clone` clones an on-chain contract from block explorers (e.g., Etherscan, Sourcify,
+/// ZKsync explorers) in the following steps:
 /// 1. Fetch the contract source code from the block explorer.
 /// 2. Initialize a empty foundry project at the `root` directory specified in `CloneArgs`.
 /// 3. Dump the contract sources to the source directory.
@@ -84,6 +89,9 @@ pub enum SourceExplorer {
 ///    modifies the contract, it is possible to quickly check the storage layout compatibility with
 ///    the original on-chain contract.
 /// 6. Dump the `CloneMetadata` to the root directory of the cloned project as `.clone.meta` file.
+/// 7. For contracts cloned from ZKsync explorers, the zksolc settings are also written to the
+///    configuration file, and the cloned contract is compiled with zksolc to check that its
+///    bytecode hash matches the on-chain one.
 #[derive(Clone, Debug, Parser)]
 pub struct CloneArgs {
     /// The contract address to clone.
@@ -107,7 +115,7 @@ pub struct CloneArgs {
 
     /// Source explorer to use for fetching contract data.
     ///
-    /// Can be either "etherscan" (default) or "sourcify".
+    /// Can be either "etherscan" (default), "sourcify" or "zksync".
     #[arg(long, default_value = "etherscan", value_name = "EXPLORER")]
     pub source: SourceExplorer,
 
@@ -117,6 +125,12 @@ pub struct CloneArgs {
     #[arg(long, value_name = "URL")]
     pub sourcify_url: Option<String>,
 
+    /// Custom contract verification API URL of a ZKsync block explorer.
+    ///
+    /// Implies `--source zksync`.
+    #[arg(long, value_name = "URL", conflicts_with = "sourcify_url")]
+    pub zksync_explorer_url: Option<String>,
+
     #[command(flatten)]
     pub etherscan: EtherscanOpts,
 
@@ -135,30 +149,49 @@ pub async fn run(self) -> Result<()> {
             keep_directory_structure,
             source,
             sourcify_url,
+            zksync_explorer_url,
         } = self;
 
         // step 0. get the chain and api key from the config
         let config = etherscan.load_config()?;
         let chain = config.chain.unwrap_or_default();
 
-        // If sourcify_url is specified, use Sourcify as the source
-        let source = if sourcify_url.is_some() { SourceExplorer::Sourcify } else { source };
+        // If sourcify_url or zksync_explorer_url is specified, use the corresponding source
+        let source = if sourcify_url.is_some() {
+            SourceExplorer::Sourcify
+        } else if zksync_explorer_url.is_some() {
+            SourceExplorer::Zksync
+        } else {
+            source
+        };
 
         // step 1. get the metadata from client based on source type
-        let (meta, explorer_name, sourcify_client) = match source {
+        let mut sourcify_client = None;
+        let mut zksync_client = None;
+        let (meta, explorer_name) = match source {
             SourceExplorer::Etherscan => {
                 let etherscan_api_key =
                     config.get_etherscan_api_key(Some(chain)).unwrap_or_default();
                 let client = Client::new(chain, etherscan_api_key.clone())?;
                 sh_println!("Downloading the source code of {address} from Etherscan...")?;
                 let meta = Self::collect_metadata_from_client(address, &client).await?;
-                (meta, "Etherscan", None)
+                (meta, "Etherscan")
             }
             SourceExplorer::Sourcify => {
                 let client = SourcifyClient::with_url(chain, sourcify_url.as_deref());
                 sh_println!("Downloading the source code of {address} from Sourcify...")?;
                 let meta = Self::collect_metadata_from_client(address, &client).await?;
-                (meta, "Sourcify", Some(client))
+                sourcify_client = Some(client);
+                (meta, "Sourcify")
+            }
+            SourceExplorer::Zksync => {
+                let client = ZkSyncExplorerClient::with_url(chain, zksync_explorer_url.as_deref())?;
+                sh_println!(
+                    "Downloading the source code of {address} from the ZKsync explorer..."
+                )?;
+                let meta = Self::collect_metadata_from_client(address, &client).await?;
+                zksync_client = Some(client);
+                (meta, "the ZKsync explorer")
             }
         };
 
@@ -171,6 +204,15 @@ pub async fn run(self) -> Result<()> {
         // step 3. parse the metadata
         Self::parse_metadata(&meta, chain, &root, no_remappings_txt, keep_directory_structure)
             .await?;
+        if let Some(client) = &zksync_client {
+            let info = client.verification_info()?;
+            let mut result = Ok(());
+            Config::update_at(&root, |config, doc| {
+                result = zksync::update_config_by_zksync_metadata(config, doc, &info.request);
+                result.is_ok()
+            })?;
+            result?;
+        }
 
         // step 4. collect the compilation metadata
         sh_println!("Collecting the creation information of {address} from {explorer_name}...")?;
@@ -191,6 +233,20 @@ pub async fn run(self) -> Result<()> {
                 let client = sourcify_client.expect("Sourcify client should exist");
                 Self::collect_compilation_metadata(&meta, chain, address, &root, &client).await?;
             }
+            SourceExplorer::Zksync => {
+                let client = zksync_client.expect("ZKsync explorer client should exist");
+                Self::collect_compilation_metadata(&meta, chain, address, &root, &client).await?;
+
+                // check that the zksolc build reproduces the deployed bytecode
+                sh_println!(
+                    "Checking the zksolc build of {address} against the on-chain bytecode..."
+                )?;
+                zksync::check_bytecode_hash(
+                    &root,
+                    &meta.contract_name,
+                    &client.verification_info()?,
+                )?;
+            }
         }
 
         // step 5. git add and commit the changes if needed
@@ -0,0 +1,520 @@
+//! ZKsync block explorer support for `forge clone`.
+//!
+//! Contracts verified on ZKsync explorers carry the zksolc version and settings they were compiled
+//! with, which are written to the `[profile.<profile>.zksync]` section of the cloned project so that
+//! a `--zksync` build reproduces the deployed EraVM bytecode.
+
+use super::ExplorerClient;
+use alloy_chains::NamedChain;
+use alloy_primitives::{Address, Bytes, TxHash};
+use eyre::{OptionExt, Result};
+use foundry_block_explorers::{
+    contract::{
+        ContractCreationData, ContractMetadata, Metadata, SourceCodeEntry, SourceCodeLanguage,
+        SourceCodeMetadata,
+    },
+    errors::EtherscanError,
+};
+use foundry_common::compile::ProjectCompiler;
+use foundry_config::{Chain, Config};
+use foundry_zksync_compilers::compilers::zksolc::settings::BytecodeHash;
+use foundry_zksync_core::{H256, hash_bytecode};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    path::Path,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+use tracing::trace;
+
+/// Contract verification API of the ZKsync Era mainnet block explorer.
+const ZKSYNC_MAINNET_VERIFICATION_URL: &str =
+    "https://zksync2-mainnet-explorer.zksync.io/contract_verification";
+
+/// Contract verification API of the ZKsync Era sepolia block explorer.
+const ZKSYNC_SEPOLIA_VERIFICATION_URL: &str =
+    "https://explorer.sepolia.era.zksync.dev/contract_verification";
+
+/// Block explorer API of ZKsync Era mainnet, used to look up contract creations.
+const ZKSYNC_MAINNET_API_URL: &str = "https://block-explorer-api.mainnet.zksync.io/api";
+
+/// Block explorer API of ZKsync Era sepolia, used to look up contract creations.
+const ZKSYNC_SEPOLIA_API_URL: &str = "https://block-explorer-api.sepolia.zksync.dev/api";
+
+/// Client for the contract verification API of ZKsync block explorers.
+pub(crate) struct ZkSyncExplorerClient {
+    client: reqwest::Client,
+    base_url: String,
+    /// Block explorer API of the chain, if known, to look up the creation transaction.
+    api_url: Option<&'static str>,
+    /// Verification info fetched by `contract_source_code`, holding the zksolc settings and the
+    /// verified bytecode of the contract.
+    cached_info: Arc<Mutex<Option<ZkVerificationInfo>>>,
+}
+
+impl ZkSyncExplorerClient {
+    /// Creates a client for the given verification API URL, or for the explorer of `chain` if
+    /// none is provided.
+    pub fn with_url(chain: Chain, verifier_url: Option<&str>) -> Result<Self> {
+        let base_url = match verifier_url {
+            Some(url) => url,
+            None => match chain.named() {
+                Some(NamedChain::ZkSync) => ZKSYNC_MAINNET_VERIFICATION_URL,
+                Some(NamedChain::ZkSyncTestnet) => ZKSYNC_SEPOLIA_VERIFICATION_URL,
+                _ => eyre::bail!(
+                    "no ZKsync explorer known for chain {chain}, please provide one with `--zksync-explorer-url`"
+                ),
+            },
+        };
+        let api_url = match chain.named() {
+            Some(NamedChain::ZkSync) => Some(ZKSYNC_MAINNET_API_URL),
+            Some(NamedChain::ZkSyncTestnet) => Some(ZKSYNC_SEPOLIA_API_URL),
+            _ => None,
+        };
+        Ok(Self {
+            client: reqwest::Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_url,
+            cached_info: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Returns the verification info fetched by [`ExplorerClient::contract_source_code`].
+    pub fn verification_info(&self) -> Result<ZkVerificationInfo> {
+        self.cached_info
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_eyre("contract verification info has not been fetched")
+    }
+}
+
+/// Verification info returned by the `contract_verification/info/{address}` endpoint.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ZkVerificationInfo {
+    pub request: ZkVerificationRequest,
+    pub artifacts: ZkCompilationArtifacts,
+}
+
+/// The verification request the contract was verified with.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ZkVerificationRequest {
+    #[serde(flatten)]
+    pub source_code: ZkSourceCode,
+    /// Fully qualified name of the contract, e.g. `src/Counter.sol:Counter`.
+    pub contract_name: String,
+    #[serde(default)]
+    pub compiler_zksolc_version: Option<String>,
+    #[serde(default)]
+    pub compiler_solc_version: Option<String>,
+    #[serde(default)]
+    pub optimization_used: bool,
+    #[serde(default)]
+    pub optimizer_mode: Option<String>,
+    #[serde(default)]
+    pub constructor_arguments: Bytes,
+    /// Whether EraVM extensions were enabled, formerly known as system mode.
+    #[serde(default, alias = "enableEraVMExtensions")]
+    pub is_system: bool,
+    #[serde(default)]
+    pub force_evmla: bool,
+}
+
+/// Sources of a verified contract, tagged by their `codeFormat`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "codeFormat", content = "sourceCode")]
+pub(crate) enum ZkSourceCode {
+    #[serde(rename = "solidity-single-file")]
+    SolidityFile(String),
+    #[serde(rename = "solidity-standard-json-input")]
+    StandardJsonInput(ZkStandardJsonInput),
+    #[serde(rename = "vyper-multi-file")]
+    VyperFiles(HashMap<String, String>),
+    #[serde(rename = "yul-single-file")]
+    YulFile(String),
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ZkStandardJsonInput {
+    #[serde(default)]
+    pub sources: HashMap<String, SourceCodeEntry>,
+    #[serde(default)]
+    pub settings: Option<serde_json::Value>,
+}
+
+/// Compilation artifacts of a verified contract.
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ZkCompilationArtifacts {
+    /// The verified EraVM bytecode.
+    pub bytecode: Vec<u8>,
+}
+
+impl ZkVerificationRequest {
+    /// Returns the `metadata.hashType` zksolc setting of standard JSON inputs, if any.
+    fn hash_type(&self) -> Option<BytecodeHash> {
+        let ZkSourceCode::StandardJsonInput(input) = &self.source_code else { return None };
+        let metadata = input.settings.as_ref()?.get("metadata")?;
+        let hash_type = metadata.get("hashType").or_else(|| metadata.get("bytecodeHash"))?;
+        BytecodeHash::from_str(hash_type.as_str()?).ok()
+    }
+
+    /// Converts the request into the block explorer [`Metadata`] the project is cloned from.
+    fn into_metadata(self) -> std::result::Result<Metadata, EtherscanError> {
+        let source_code = match self.source_code {
+            ZkSourceCode::SolidityFile(source) => SourceCodeMetadata::SourceCode(source),
+            ZkSourceCode::StandardJsonInput(input) => SourceCodeMetadata::Metadata {
+                language: Some(SourceCodeLanguage::Solidity),
+                sources: input.sources,
+                settings: input.settings,
+            },
+            ZkSourceCode::VyperFiles(_) => {
+                return Err(EtherscanError::Unknown(
+                    "Vyper contracts are not supported".to_string(),
+                ));
+            }
+            ZkSourceCode::YulFile(_) => {
+                return Err(EtherscanError::Unknown("Yul contracts are not supported".to_string()));
+            }
+        };
+
+        let contract_name = self
+            .contract_name
+            .rsplit_once(':')
+            .map(|(_, name)| name.to_string())
+            .unwrap_or(self.contract_name);
+
+        // the solc version of the matter-labs fork is reported as `zkVM-<solc>-<revision>`
+        let solc_version = self.compiler_solc_version.unwrap_or_default();
+        let solc_version = solc_version.strip_prefix("zkVM-").unwrap_or(&solc_version);
+        let solc_version = solc_version.split('-').next().unwrap_or_default();
+
+        Ok(Metadata {
+            source_code,
+            abi: String::new(),
+            contract_name,
+            compiler_version: format!("v{solc_version}"),
+            optimization_used: self.optimization_used as u64,
+            runs: 0,
+            constructor_arguments: self.constructor_arguments,
+            evm_version: String::new(),
+            library: String::new(),
+            license_type: String::new(),
+            proxy: 0,
+            implementation: None,
+            swarm_source: String::new(),
+        })
+    }
+}
+
+impl ExplorerClient for ZkSyncExplorerClient {
+    async fn contract_source_code(
+        &self,
+        address: Address,
+    ) -> std::result::Result<ContractMetadata, EtherscanError> {
+        let url = format!("{}/info/{address}", self.base_url);
+        let response = self.client.get(&url).send().await?;
+
+        let status = response.status();
+        trace!("ZKsync explorer API response: status={:?}, url={}", status, url);
+
+        match status {
+            StatusCode::NOT_FOUND => return Err(EtherscanError::ContractCodeNotVerified(address)),
+            StatusCode::TOO_MANY_REQUESTS => return Err(EtherscanError::RateLimitExceeded),
+            _ => {}
+        }
+
+        let response_text = response.text().await?;
+        trace!("ZKsync explorer API response body: {}", response_text);
+
+        if !status.is_success() {
+            return Err(EtherscanError::Unknown(format!(
+                "ZKsync explorer API error (status {status}): {response_text}"
+            )));
+        }
+
+        let info: ZkVerificationInfo = serde_json::from_str(&response_text).map_err(|e| {
+            EtherscanError::Unknown(format!("Failed to parse ZKsync explorer response: {e}"))
+        })?;
+        if info.request.compiler_zksolc_version.is_none() {
+            return Err(EtherscanError::Unknown(format!(
+                "contract {address} was not compiled with zksolc"
+            )));
+        }
+        *self.cached_info.lock().unwrap() = Some(info.clone());
+
+        Ok(ContractMetadata { items: vec![info.request.into_metadata()?] })
+    }
+
+    async fn contract_creation_data(
+        &self,
+        address: Address,
+    ) -> std::result::Result<ContractCreationData, EtherscanError> {
+        // The verification API does not expose the creation transaction, it's looked up with the
+        // etherscan-compatible block explorer API instead.
+        let Some(api_url) = self.api_url else {
+            let _ = sh_warn!(
+                "the creation transaction and deployer of {address} are unavailable: no ZKsync block explorer API is known for the chain, they are left as zero in the clone metadata"
+            );
+            return Ok(ContractCreationData {
+                contract_address: address,
+                contract_creator: Address::ZERO,
+                transaction_hash: TxHash::ZERO,
+            });
+        };
+
+        let response = self
+            .client
+            .get(api_url)
+            .query(&[
+                ("module", "contract"),
+                ("action", "getcontractcreation"),
+                ("contractaddresses", &address.to_string()),
+            ])
+            .send()
+            .await?;
+        let status = response.status();
+        let response_text = response.text().await?;
+        trace!("ZKsync block explorer API response: status={:?}, body={}", status, response_text);
+
+        if !status.is_success() {
+            return Err(EtherscanError::Unknown(format!(
+                "ZKsync block explorer API error (status {status}): {response_text}"
+            )));
+        }
+        parse_creation_data(address, &response_text)
+    }
+}
+
+/// A contract creation returned by the `getcontractcreation` action of the block explorer API.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ZkContractCreation {
+    contract_address: Address,
+    contract_creator: Address,
+    tx_hash: TxHash,
+}
+
+/// Parses the creation data of `address` from a `getcontractcreation` response.
+fn parse_creation_data(
+    address: Address,
+    response: &str,
+) -> std::result::Result<ContractCreationData, EtherscanError> {
+    #[derive(Deserialize)]
+    struct Response {
+        result: serde_json::Value,
+    }
+
+    let response: Response = serde_json::from_str(response).map_err(|e| {
+        EtherscanError::Unknown(format!("Failed to parse ZKsync block explorer response: {e}"))
+    })?;
+    let creations: Vec<ZkContractCreation> = serde_json::from_value(response.result.clone())
+        .map_err(|_| {
+            EtherscanError::Unknown(format!("ZKsync block explorer API error: {}", response.result))
+        })?;
+    let creation = creations
+        .into_iter()
+        .find(|creation| creation.contract_address == address)
+        .ok_or_else(|| EtherscanError::Unknown(format!("creation data of {address} not found")))?;
+
+    Ok(ContractCreationData {
+        contract_address: address,
+        contract_creator: creation.contract_creator,
+        transaction_hash: creation.tx_hash,
+    })
+}
+
+/// Update the `zksync` section of the configuration file with the zksolc settings the contract was
+/// verified with:
+/// - `zksolc` to the zksolc version
+/// - `optimizer` and `optimizer_mode` to the EraVM optimizer settings
+/// - `enable_eravm_extensions` to whether system mode was enabled
+/// - `force_evmla` to whether the EVM legacy assembly codegen was used
+/// - `hash_type` to the metadata hash type, if set
+pub(crate) fn update_config_by_zksync_metadata(
+    config: &Config,
+    doc: &mut toml_edit::DocumentMut,
+    request: &ZkVerificationRequest,
+) -> Result<()> {
+    let profile = config.profile.as_str().as_str();
+
+    let zksolc_version = request
+        .compiler_zksolc_version
+        .as_deref()
+        .ok_or_eyre("contract was not compiled with zksolc")?;
+    let zksolc_version = zksolc_version.strip_prefix('v').unwrap_or(zksolc_version);
+
+    if doc[Config::PROFILE_SECTION][profile].get("zksync").is_none() {
+        doc[Config::PROFILE_SECTION][profile]["zksync"] = toml_edit::table();
+    }
+    let zksync = &mut doc[Config::PROFILE_SECTION][profile]["zksync"];
+
+    zksync["zksolc"] = toml_edit::value(zksolc_version);
+    zksync["optimizer"] = toml_edit::value(request.optimization_used);
+    if let Some(mode) = &request.optimizer_mode {
+        zksync["optimizer_mode"] = toml_edit::value(mode.as_str());
+    }
+    zksync["enable_eravm_extensions"] = toml_edit::value(request.is_system);
+    zksync["force_evmla"] = toml_edit::value(request.force_evmla);
+    if let Some(hash_type) = request.hash_type() {
+        zksync["hash_type"] = toml_edit::value(hash_type.to_string());
+    }
+
+    Ok(())
+}
+
+/// Compile the project in the root directory with zksolc, and check that the EraVM bytecode hash
+/// of the contract matches the verified one.
+pub(crate) fn check_bytecode_hash(
+    root: &Path,
+    contract_name: &str,
+    info: &ZkVerificationInfo,
+) -> Result<()> {
+    let config = Config::load_with_root(root)?.sanitized();
+    let project = foundry_config::zksync::config_create_project(&config, false, false)?;
+    let output = ProjectCompiler::new().quiet(true).zksync_compile(&project)?;
+
+    let artifact = output
+        .find_first(contract_name)
+        .ok_or_else(|| eyre::eyre!("contract {contract_name} not found in zksolc output"))?;
+    let local_hash = artifact.hash.as_deref().ok_or_eyre("zksolc artifact has no bytecode hash")?;
+    let onchain_hash = compare_bytecode_hash(
+        contract_name,
+        H256::from_str(local_hash)?,
+        &info.artifacts.bytecode,
+    )?;
+    sh_println!(
+        "The zksolc build of {contract_name} matches the on-chain bytecode hash {onchain_hash:?}"
+    )?;
+
+    Ok(())
+}
+
+/// Checks that the local bytecode hash of the contract matches the hash of the on-chain bytecode,
+/// and returns the latter.
+fn compare_bytecode_hash(contract_name: &str, local_hash: H256, bytecode: &[u8]) -> Result<H256> {
+    let onchain_hash = hash_bytecode(bytecode);
+    eyre::ensure!(
+        local_hash == onchain_hash,
+        "bytecode hash mismatch for {contract_name}: local build {local_hash:?}, on-chain {onchain_hash:?}"
+    );
+    Ok(onchain_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use foundry_config::SolcReq;
+
+    const VERIFICATION_INFO: &str = r#"{
+        "request": {
+            "id": 1,
+            "contractAddress": "0x0000000000000000000000000000000000000001",
+            "codeFormat": "solidity-standard-json-input",
+            "sourceCode": {
+                "language": "Solidity",
+                "sources": {
+                    "src/Counter.sol": { "content": "contract Counter {}" }
+                },
+                "settings": {
+                    "optimizer": { "enabled": true, "mode": "z" },
+                    "metadata": { "hashType": "none" }
+                }
+            },
+            "contractName": "src/Counter.sol:Counter",
+            "compilerZksolcVersion": "v1.5.7",
+            "compilerSolcVersion": "zkVM-0.8.24-1.0.1",
+            "optimizationUsed": true,
+            "optimizerMode": "z",
+            "constructorArguments": "0x",
+            "isSystem": true,
+            "forceEvmla": false
+        },
+        "artifacts": {
+            "bytecode": [0, 0, 0, 1],
+            "abi": []
+        },
+        "verified_at": "2024-01-01T00:00:00Z"
+    }"#;
+
+    #[test]
+    fn test_zksync_verification_info_to_metadata() {
+        let info: ZkVerificationInfo = serde_json::from_str(VERIFICATION_INFO).unwrap();
+        assert_eq!(info.request.hash_type(), Some(BytecodeHash::None));
+
+        let meta = info.request.into_metadata().unwrap();
+        assert_eq!(meta.contract_name, "Counter");
+        assert_eq!(meta.compiler_version().unwrap(), semver::Version::new(0, 8, 24));
+        assert_eq!(meta.optimization_used, 1);
+        assert_eq!(meta.source_code.sources().len(), 1);
+    }
+
+    #[test]
+    fn test_zksync_metadata_updates_config() {
+        let info: ZkVerificationInfo = serde_json::from_str(VERIFICATION_INFO).unwrap();
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join(Config::FILE_NAME), "[profile.default]\n").unwrap();
+
+        let mut result = Ok(());
+        Config::update_at(root.path(), |config, doc| {
+            result = update_config_by_zksync_metadata(config, doc, &info.request);
+            result.is_ok()
+        })
+        .unwrap();
+        result.unwrap();
+
+        let config = Config::load_with_root(root.path()).unwrap();
+        assert_eq!(config.zksync.zksolc, Some(SolcReq::Version(semver::Version::new(1, 5, 7))));
+        assert!(config.zksync.optimizer);
+        assert_eq!(config.zksync.optimizer_mode, 'z');
+        assert!(config.zksync.enable_eravm_extensions);
+        assert!(!config.zksync.force_evmla);
+        assert_eq!(config.zksync.hash_type, Some(BytecodeHash::None));
+    }
+
+    #[test]
+    fn test_zksync_metadata_without_zksolc_fails() {
+        let mut info: ZkVerificationInfo = serde_json::from_str(VERIFICATION_INFO).unwrap();
+        info.request.compiler_zksolc_version = None;
+        let mut doc = "[profile.default]\n".parse::<toml_edit::DocumentMut>().unwrap();
+
+        let err = update_config_by_zksync_metadata(&Config::default(), &mut doc, &info.request)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "contract was not compiled with zksolc");
+    }
+
+    #[test]
+    fn test_zksync_parse_creation_data() {
+        let address = Address::with_last_byte(1);
+        let response = r#"{
+            "status": "1",
+            "message": "OK",
+            "result": [{
+                "contractAddress": "0x0000000000000000000000000000000000000001",
+                "contractCreator": "0x0000000000000000000000000000000000000002",
+                "txHash": "0x0000000000000000000000000000000000000000000000000000000000000003"
+            }]
+        }"#;
+        let creation = parse_creation_data(address, response).unwrap();
+        assert_eq!(creation.contract_address, address);
+        assert_eq!(creation.contract_creator, Address::with_last_byte(2));
+        assert_eq!(creation.transaction_hash, TxHash::with_last_byte(3));
+
+        let response = r#"{"status": "0", "message": "No data found", "result": []}"#;
+        assert!(parse_creation_data(address, response).is_err());
+    }
+
+    #[test]
+    fn test_zksync_compare_bytecode_hash() {
+        let bytecode = [1; 32];
+        let hash = hash_bytecode(&bytecode);
+        assert_eq!(compare_bytecode_hash("Counter", hash, &bytecode).unwrap(), hash);
+
+        // a different on-chain bytecode must be rejected
+        let err = compare_bytecode_hash("Counter", hash, &[1; 96]).unwrap_err();
+        assert!(err.to_string().contains("bytecode hash mismatch for Counter"), "{err}");
+    }
+}
@@ -1,7 +1,7 @@
 use super::{install, test::TestArgs, watch::WatchArgs};
 use crate::coverage::{
-    BytecodeReporter, ContractId, CoverageReport, CoverageReporter, CoverageSummaryReporter,
-    DebugReporter, ItemAnchor, LcovReporter,
+    BytecodeReporter, ContractId, CoverageItemKind, CoverageReport, CoverageReporter,
+    CoverageSummaryReporter, DebugReporter, ItemAnchor, LcovReporter,
     analysis::{SourceAnalysis, SourceFiles},
     anchors::find_anchors,
 };
@@ -15,11 +15,13 @@
     artifacts::{CompactBytecode, CompactDeployedBytecode, sourcemap::SourceMap},
 };
 use foundry_config::Config;
-use foundry_evm::{core::ic::IcPcMap, opts::EvmOpts};
+use foundry_evm::{core::ic::IcPcMap, executors::strategy::ExecutorStrategyExt, opts::EvmOpts};
 use rayon::prelude::*;
 use semver::{Version, VersionReq};
 use std::path::{Path, PathBuf};
 
+mod zksync;
+
 // Loads project's figment and merges the build cli arguments into it
 foundry_config::impl_figment_convert!(CoverageArgs, test);
 
@@ -249,6 +251,7 @@ async fn collect(
         evm_opts: EvmOpts,
     ) -> Result<()> {
         let filter = self.test.filter(&config)?;
+        let zksync = config.zksync.should_compile();
         let outcome =
             self.test.run_tests(project_root, config, evm_opts, output, &filter, true).await?;
 
@@ -288,6 +291,39 @@ async fn collect(
             }
         }
 
+        // Add hit data of the contracts executed in EraVM.
+        if zksync {
+            let runner = outcome
+                .runner
+                .as_ref()
+                .ok_or_else(|| eyre::eyre!("missing test runner to collect EraVM coverage"))?;
+            let zk_output = runner
+                .strategy
+                .runner
+                .zksync_compilation_output(runner.strategy.context.as_ref())
+                .ok_or_else(|| eyre::eyre!("missing zksolc compilation output"))?;
+            let hit_maps = outcome
+                .results
+                .values()
+                .flat_map(|suite| suite.test_results.values())
+                .filter_map(|result| result.line_coverage.as_ref())
+                .flat_map(|hit_maps| hit_maps.0.iter());
+            let zk_sources = zksync::add_hit_maps(zk_output, project_root, &mut report, hit_maps)?;
+
+            // Branches can't be mapped to EraVM bytecode, so they are left out of the report
+            // instead of being reported as not covered.
+            if !zk_sources.is_empty() {
+                sh_warn!(
+                    "branch coverage is not supported for contracts executed in EraVM, their \
+                     branches are not reported"
+                )?;
+                report.retain_items(|version, item| {
+                    !matches!(item.kind, CoverageItemKind::Branch { .. })
+                        || !zk_sources.contains(&(version.clone(), item.loc.source_id))
+                });
+            }
+        }
+
         // Filter out ignored sources from the report.
         if let Some(not_re) = &filter.args().coverage_pattern_inverse {
             let file_root = filter.paths().root.as_path();
@@ -0,0 +1,230 @@
+//! Coverage for contracts executed in EraVM.
+//!
+//! EraVM bytecode has no source maps, so program counters are mapped back to source lines using
+//! the `.loc` directives of the EraVM assembly emitted by zksolc. Only line, statement and function
+//! items can be mapped this way: branches are anchored to jump instructions, which have no
+//! counterpart in the assembly, so branch items of the sources executed in EraVM are removed from
+//! the report.
+
+use crate::coverage::{CoverageItemKind, CoverageReport, HitMap, ItemAnchor};
+use alloy_primitives::{
+    B256,
+    map::{HashMap, HashSet},
+};
+use eyre::Result;
+use foundry_compilers::ProjectCompileOutput;
+use foundry_zksync_compilers::compilers::{
+    artifact_output::zk::ZkArtifactOutput, zksolc::ZkSolcCompiler,
+};
+use semver::Version;
+use std::path::{Path, PathBuf};
+
+/// Adds the hits recorded for EraVM bytecode to the coverage report, and returns the compiler
+/// version and ID of the sources that were executed in EraVM.
+///
+/// Hit maps are matched against the zksolc artifacts of the test run by their bytecode hash. The
+/// artifacts must have been compiled with EraVM assembly output.
+pub(super) fn add_hit_maps<'a>(
+    output: &ProjectCompileOutput<ZkSolcCompiler, ZkArtifactOutput>,
+    root: &Path,
+    report: &mut CoverageReport,
+    hit_maps: impl IntoIterator<Item = (&'a B256, &'a HitMap)>,
+) -> Result<HashSet<(Version, usize)>> {
+    let mut anchors = HashMap::<B256, ContractAnchors>::default();
+    for (id, artifact) in output.artifact_ids() {
+        let (Some(hash), Some(assembly)) = (&artifact.hash, &artifact.assembly) else { continue };
+        let Ok(hash) = hash.parse::<B256>() else { continue };
+        if let Some(contract_anchors) = find_anchors(report, root, &id.version, assembly) {
+            anchors.insert(hash, contract_anchors);
+        }
+    }
+
+    let mut executed_sources = HashSet::default();
+    for (hash, hit_map) in hit_maps {
+        if let Some(ContractAnchors { version, anchors, source_ids }) = anchors.get(hash) {
+            report.add_hit_map_with_anchors(version, anchors, hit_map);
+            executed_sources
+                .extend(source_ids.iter().map(|source_id| (version.clone(), *source_id)));
+        }
+    }
+
+    Ok(executed_sources)
+}
+
+/// The item anchors of an EraVM contract.
+struct ContractAnchors {
+    /// The compiler version of the analyses the anchors refer to.
+    version: Version,
+    /// The item anchors.
+    anchors: Vec<ItemAnchor>,
+    /// The IDs of the sources the anchored items belong to.
+    source_ids: HashSet<usize>,
+}
+
+/// Finds the item anchors for the given EraVM assembly.
+///
+/// Line, statement and function items are anchored to the first instruction emitted for the line
+/// they start at.
+fn find_anchors(
+    report: &CoverageReport,
+    root: &Path,
+    version: &Version,
+    assembly: &str,
+) -> Option<ContractAnchors> {
+    let mut version_used = None;
+    let mut anchors = Vec::new();
+    let mut source_ids = HashSet::default();
+    for line_anchor in parse_assembly(assembly) {
+        let path = line_anchor.path.strip_prefix(root).unwrap_or(&line_anchor.path);
+        let Some((source_version, source_id)) = find_source(report, path, version) else {
+            continue;
+        };
+        let Some(analysis) = report.analyses.get(&source_version) else { continue };
+
+        for (item_id, item) in analysis.items_for_source_enumerated(source_id as u32) {
+            if item.loc.lines.start != line_anchor.line {
+                continue;
+            }
+            if matches!(
+                item.kind,
+                CoverageItemKind::Line
+                    | CoverageItemKind::Statement
+                    | CoverageItemKind::Function { .. }
+            ) {
+                anchors.push(ItemAnchor { instruction: line_anchor.pc, item_id });
+                source_ids.insert(source_id);
+            }
+        }
+        version_used.get_or_insert(source_version);
+    }
+
+    version_used.map(|version| ContractAnchors { version, anchors, source_ids })
+}
+
+/// Returns the compiler version and source ID of the given source path, preferring the given
+/// version if the source was compiled with multiple versions.
+fn find_source(
+    report: &CoverageReport,
+    path: &Path,
+    version: &Version,
+) -> Option<(Version, usize)> {
+    let mut found = None;
+    for ((source_version, source_path), source_id) in &report.source_paths_to_ids {
+        if source_path != path {
+            continue;
+        }
+        if (source_version.major, source_version.minor, source_version.patch)
+            == (version.major, version.minor, version.patch)
+        {
+            return Some((source_version.clone(), *source_id));
+        }
+        found.get_or_insert_with(|| (source_version.clone(), *source_id));
+    }
+    found
+}
+
+/// The first instruction emitted for a source line.
+#[derive(Debug, PartialEq, Eq)]
+struct LineAnchor {
+    /// The source path.
+    path: PathBuf,
+    /// The source line, 1-based.
+    line: u32,
+    /// The program counter of the instruction.
+    pc: u32,
+}
+
+/// Parses EraVM assembly and returns the first instruction emitted for each source line.
+///
+/// Each instruction in the text section is a single EraVM instruction, so the program counter is
+/// the index of the instruction.
+fn parse_assembly(assembly: &str) -> Vec<LineAnchor> {
+    let mut files = HashMap::<u32, PathBuf>::default();
+    let mut seen = HashSet::<(u32, u32)>::default();
+    let mut anchors = Vec::new();
+    let mut location: Option<(u32, u32)> = None;
+    let mut in_text = true;
+    let mut pc = 0u32;
+
+    for line in assembly.lines() {
+        let line = line.split(';').next().unwrap_or_default().trim();
+        if line.is_empty() || line.ends_with(':') {
+            continue;
+        }
+
+        if let Some(directive) = line.strip_prefix('.') {
+            let mut parts = directive.split_whitespace();
+            match parts.next() {
+                Some("file") => {
+                    let Some(Ok(id)) = parts.next().map(str::parse::<u32>) else { continue };
+                    let path = parts.map(|part| part.trim_matches('"')).collect::<PathBuf>();
+                    files.insert(id, path);
+                }
+                Some("loc") => {
+                    let mut numbers = parts.filter_map(|part| part.parse::<u32>().ok());
+                    location = match (numbers.next(), numbers.next()) {
+                        (Some(file), Some(line)) if line != 0 => Some((file, line)),
+                        _ => None,
+                    };
+                }
+                Some("text") => in_text = true,
+                Some("rodata" | "data" | "bss") => in_text = false,
+                Some("section") => {
+                    in_text = parts.next().is_some_and(|section| section.starts_with(".text"))
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        if !in_text {
+            continue;
+        }
+
+        if let Some((file, line)) = location
+            && seen.insert((file, line))
+            && let Some(path) = files.get(&file)
+        {
+            anchors.push(LineAnchor { path: path.clone(), line, pc });
+        }
+        pc += 1;
+    }
+
+    anchors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_eravm_assembly_locations() {
+        let assembly = r#"
+	.text
+	.file	"Counter.sol:Counter"
+	.file	1 "src" "Counter.sol"
+	.globl	__entry
+__entry:
+.func_begin0:
+	add	128, r0, r3
+	.loc	1 8 0
+	st.1	64, r3
+	add	r1, r0, r4 ; copy
+	.loc	1 9 4 prologue_end
+	sub.s!	1, r2, r0
+	.loc	1 8 0
+	jump.eq	@.BB0_1
+	.rodata
+CPI0_0:
+	.cell	1
+"#;
+
+        assert_eq!(
+            parse_assembly(assembly),
+            vec![
+                LineAnchor { path: PathBuf::from("src/Counter.sol"), line: 8, pc: 1 },
+                LineAnchor { path: PathBuf::from("src/Counter.sol"), line: 9, pc: 3 },
+            ]
+        );
+    }
+}
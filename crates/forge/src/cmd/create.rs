@@ -244,6 +244,7 @@ async fn verify_preflight_check(
             address: Default::default(),
             contract: Some(self.contract.clone()),
             compiler_version: Some(id.version.to_string()),
+            zk_compiler_version: None,
             constructor_args,
             constructor_args_path: None,
             no_auto_detect: false,
@@ -446,6 +447,7 @@ async fn deploy<P: Provider<AnyNetwork>>(
             address,
             contract: Some(self.contract),
             compiler_version: Some(id.version.to_string()),
+            zk_compiler_version: None,
             constructor_args,
             constructor_args_path: None,
             no_auto_detect: false,
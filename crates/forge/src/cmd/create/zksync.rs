@@ -1,13 +1,13 @@
 //! Contains zksync-specific code to run `forge create`
 
 use std::{
-    collections::{HashSet, VecDeque},
+    collections::{BTreeSet, HashSet, VecDeque},
     path::PathBuf,
     sync::Arc,
 };
 
 use super::{ContractDeploymentError, ContractFactory, CreateArgs, DeploymentTxFactory};
-use alloy_dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy_dyn_abi::{DynSolValue, FunctionExt, JsonAbiExt};
 use alloy_json_abi::JsonAbi;
 use alloy_network::{Network, ReceiptResponse, TransactionBuilder};
 use alloy_primitives::{Address, Bytes, hex};
@@ -26,9 +26,15 @@
     utils,
     utils::{LoadConfig, read_constructor_args_file, remove_zk_contract},
 };
-use foundry_common::{compile::ProjectCompiler, shell};
-use foundry_compilers::{ArtifactId, Project, artifacts::BytecodeObject, utils::canonicalize};
-use foundry_zksync_compilers::compilers::artifact_output::zk::ZkContractArtifact;
+use foundry_common::{abi::get_func, compile::ProjectCompiler, shell};
+use foundry_compilers::{
+    Artifact, ArtifactId, Project, ProjectCompileOutput, artifacts::Libraries, utils::canonicalize,
+};
+use foundry_linking::{LinkOutput, ZkLinker, ensure_deploy_time_linking_supported};
+use foundry_zksync_compilers::compilers::{
+    artifact_output::zk::{ZkArtifactOutput, ZkContractArtifact},
+    zksolc::ZkSolcCompiler,
+};
 use foundry_zksync_core::convert::ConvertH160;
 use serde_json::json;
 
@@ -39,6 +45,21 @@ pub struct ZkSyncData {
     bytecode: Vec<u8>,
     factory_deps: Vec<Vec<u8>>,
     paymaster_params: Option<PaymasterParams>,
+    /// Present if the contract has to be linked against libraries at deploy time
+    link_data: Option<ZkLinkData>,
+}
+
+#[derive(Debug)]
+/// Data used to link a contract against libraries at deploy time
+pub struct ZkLinkData {
+    /// Root of the project
+    root: PathBuf,
+    /// Path to the zksolc binary used for linking
+    zksolc: PathBuf,
+    /// Compilation output, including the contract to link
+    output: ProjectCompileOutput<ZkSolcCompiler, ZkArtifactOutput>,
+    /// Libraries provided by the user
+    libraries: Libraries,
 }
 
 impl CreateArgs {
@@ -63,6 +84,25 @@ pub(super) async fn run_zksync(mut self, project: Project) -> Result<()> {
         let zk_compiler = ProjectCompiler::new().files([target_path.clone()]);
         let mut zk_output = zk_compiler.zksync_compile(&zk_project)?;
 
+        // the linker needs the full output, including the target contract
+        let link_data = if zk_output
+            .find(&target_path, &self.contract.name)
+            .and_then(|artifact| artifact.bytecode.as_ref())
+            .is_some_and(|bytecode| bytecode.object().is_unlinked())
+        {
+            let zksolc_settings = foundry_config::zksync::config_zksolc_settings(&config)?;
+            ensure_deploy_time_linking_supported(zksolc_settings.zksolc_version_ref())?;
+
+            Some(ZkLinkData {
+                root: project.root().to_path_buf(),
+                zksolc: zksolc_settings.zksolc_path(),
+                output: zk_output.clone(),
+                libraries: config.libraries_with_remappings()?,
+            })
+        } else {
+            None
+        };
+
         let (artifact, id) = remove_zk_contract(&mut zk_output, &target_path, &self.contract.name)?;
 
         let ZkContractArtifact { bytecode, abi, factory_dependencies, .. } = &artifact;
@@ -70,28 +110,8 @@ pub(super) async fn run_zksync(mut self, project: Project) -> Result<()> {
         let abi = abi.clone().expect("Abi not found");
         let bin = bytecode.as_ref().expect("Bytecode not found");
 
-        let bytecode = match bin.object() {
-            BytecodeObject::Bytecode(bytes) => bytes.to_vec(),
-            _ => {
-                let link_refs = bin
-                    .missing_libraries
-                    .iter()
-                    .map(|library| {
-                        let mut parts = library.split(':');
-                        let path = parts.next().unwrap();
-                        let name = parts.next().unwrap();
-                        format!("\t{name}: {path}")
-                    })
-                    .collect::<HashSet<String>>()
-                    .into_iter()
-                    .collect::<Vec<String>>()
-                    .join("\n");
-                eyre::bail!(
-                    "Dynamic linking not supported in `create` command - deploy the following library contracts first, then provide the address to link at compile time\n{}",
-                    link_refs
-                )
-            }
-        };
+        // unlinked bytecode is replaced once the missing libraries are known, see `zk_link`
+        let bytecode = bin.object().into_bytes().map(|bytes| bytes.to_vec()).unwrap_or_default();
 
         // Add arguments to constructor
         let config = self.eth.load_config()?;
@@ -163,17 +183,18 @@ pub(super) async fn run_zksync(mut self, project: Project) -> Result<()> {
                     visited_bytecodes.insert(fdep_bytecode);
                 }
             }
-            visited_bytecodes.insert(bytecode.clone());
+            if link_data.is_none() {
+                visited_bytecodes.insert(bytecode.clone());
+            }
             visited_bytecodes.into_iter().collect()
         };
-        let zk_data = ZkSyncData { bytecode, factory_deps, paymaster_params };
+        let zk_data = ZkSyncData { bytecode, factory_deps, paymaster_params, link_data };
 
         if self.unlocked {
             // Deploy with unlocked account
             let sender = self.eth.wallet.from.expect("required");
             self.deploy_zk(
                 abi,
-                bin.object(),
                 params,
                 provider,
                 chain_id,
@@ -194,7 +215,6 @@ pub(super) async fn run_zksync(mut self, project: Project) -> Result<()> {
                 .connect_provider(provider);
             self.deploy_zk(
                 abi,
-                bin.object(),
                 params,
                 provider,
                 chain_id,
@@ -212,20 +232,82 @@ pub(super) async fn run_zksync(mut self, project: Project) -> Result<()> {
     async fn deploy_zk<P: Provider<Zksync>>(
         self,
         abi: JsonAbi,
-        bin: BytecodeObject,
         args: Vec<DynSolValue>,
         provider: P,
         chain: u64,
         deployer_address: Address,
         timeout: u64,
         id: ArtifactId,
-        zk_data: ZkSyncData,
+        mut zk_data: ZkSyncData,
     ) -> Result<()> {
-        let bin = bin.into_bytes().unwrap_or_else(|| {
-            panic!("no bytecode found in bin object for {}", self.contract.name)
-        });
         let provider = Arc::new(provider);
-        let factory = ContractFactory::new_zk(abi.clone(), bin.clone(), provider.clone(), timeout);
+
+        let mut nonce = if let Some(nonce) = self.tx.nonce {
+            nonce.to()
+        } else {
+            provider.get_transaction_count(deployer_address).await?
+        };
+        let gas_price = if let Some(gas_price) = self.tx.gas_price {
+            gas_price.to()
+        } else {
+            provider.get_gas_price().await?
+        };
+
+        // Whether to broadcast the transactions or not
+        let dry_run = !self.broadcast;
+
+        let mut libraries = self.build.libraries.clone();
+        let mut library_txs = vec![];
+        if let Some(link_data) = zk_data.link_data.take() {
+            let deployment_nonce = get_deployment_nonce(&provider, deployer_address).await?;
+            let link_output =
+                self.zk_link(&mut zk_data, link_data, &id, deployer_address, deployment_nonce)?;
+
+            for library in link_output.libs_to_deploy {
+                let library_data = ZkSyncData {
+                    bytecode: library.to_vec(),
+                    factory_deps: vec![library.to_vec()],
+                    paymaster_params: zk_data.paymaster_params.clone(),
+                    link_data: None,
+                };
+                let mut deployer =
+                    ContractFactory::new_zk(JsonAbi::default(), library, provider.clone(), timeout)
+                        .deploy_tokens_zk(vec![], &library_data)?;
+                self.fill_zk_tx(
+                    &mut deployer.tx,
+                    &provider,
+                    chain,
+                    deployer_address,
+                    nonce,
+                    gas_price,
+                    library_data.paymaster_params,
+                )
+                .await?;
+                nonce += 1;
+
+                if dry_run {
+                    library_txs.push(deployer.tx);
+                } else {
+                    let (address, receipt) = deployer.send_with_receipt().await?;
+                    if !shell::is_json() {
+                        sh_println!("Deployed library to: {address}")?;
+                        sh_println!("Transaction hash: {:?}", receipt.transaction_hash())?;
+                    }
+                }
+            }
+
+            libraries.extend(link_output.libraries.libs.into_iter().flat_map(|(file, libs)| {
+                libs.into_iter()
+                    .map(move |(name, address)| format!("{}:{name}:{address}", file.display()))
+            }));
+        }
+
+        let factory = ContractFactory::new_zk(
+            abi.clone(),
+            zk_data.bytecode.clone().into(),
+            provider.clone(),
+            timeout,
+        );
 
         let is_args_empty = args.is_empty();
         let mut deployer =
@@ -240,39 +322,18 @@ async fn deploy_zk<P: Provider<Zksync>>(
         deployer.tx = deployer.tx.with_factory_deps(
             zk_data.factory_deps.clone().into_iter().map(|dep| dep.into()).collect(),
         );
-        if let Some(paymaster_params) = zk_data.paymaster_params {
-            deployer.tx.set_paymaster_params(paymaster_params);
-        }
-        deployer.tx.set_from(deployer_address);
-        deployer.tx.set_chain_id(chain);
-        // `to` field must be set explicitly, cannot be None.
-        if deployer.tx.to().is_none() {
-            deployer.tx.set_create();
-        }
-        deployer.tx.set_nonce(if let Some(nonce) = self.tx.nonce {
-            Ok(nonce.to())
-        } else {
-            provider.get_transaction_count(deployer_address).await
-        }?);
-
         // set tx value if specified
         if let Some(value) = self.tx.value {
             deployer.tx.set_value(value);
         }
-
-        let gas_price = if let Some(gas_price) = self.tx.gas_price {
-            gas_price.to()
-        } else {
-            provider.get_gas_price().await?
-        };
-        deployer.tx.set_gas_price(gas_price);
-
-        // estimate fee
-        foundry_zksync_core::estimate_fee(
+        self.fill_zk_tx(
             &mut deployer.tx,
             &provider,
-            130,
-            self.zk_tx.gas_per_pubdata,
+            chain,
+            deployer_address,
+            nonce,
+            gas_price,
+            zk_data.paymaster_params,
         )
         .await?;
 
@@ -294,13 +355,13 @@ async fn deploy_zk<P: Provider<Zksync>>(
             self.verify_preflight_check(constructor_args.clone(), chain, &id).await?;
         }
 
-        // Whether to broadcast the transaction or not
-        let dry_run = !self.broadcast;
-
         if dry_run {
             if !shell::is_json() {
                 sh_warn!("Dry run enabled, not broadcasting transaction\n")?;
 
+                for tx in &library_txs {
+                    sh_println!("Library transaction: {}", serde_json::to_string_pretty(tx)?)?;
+                }
                 sh_println!("Contract: {}", &self.contract.name)?;
                 sh_println!("Transaction: {}", serde_json::to_string_pretty(&deployer.tx)?)?;
                 sh_println!("ABI: {}\n", serde_json::to_string_pretty(&abi)?)?;
@@ -309,11 +370,14 @@ async fn deploy_zk<P: Provider<Zksync>>(
                     "To broadcast this transaction, add --broadcast to the previous command. See forge create --help for more."
                 )?;
             } else {
-                let output = json!({
+                let mut output = json!({
                     "contract": &self.contract.name,
                     "transaction": &deployer.tx,
                     "abi": &abi
                 });
+                if !library_txs.is_empty() {
+                    output["libraryTransactions"] = json!(library_txs);
+                }
                 sh_println!("{}", serde_json::to_string_pretty(&output)?)?;
             }
 
@@ -353,6 +417,7 @@ async fn deploy_zk<P: Provider<Zksync>>(
             address,
             contract: Some(self.contract),
             compiler_version: None,
+            zk_compiler_version: None,
             constructor_args,
             constructor_args_path: None,
             num_of_optimizations,
@@ -363,7 +428,7 @@ async fn deploy_zk<P: Provider<Zksync>>(
             skip_is_verified_check: true,
             watch: true,
             retry: self.retry,
-            libraries: self.build.libraries.clone(),
+            libraries,
             root: None,
             verifier: self.verifier,
             via_ir: self.build.via_ir,
@@ -380,6 +445,114 @@ async fn deploy_zk<P: Provider<Zksync>>(
         sh_println!("Waiting for {} to detect contract deployment...", verify.verifier.verifier)?;
         verify.run().await
     }
+
+    /// Links the target contract against its libraries, computing the address of the missing
+    /// ones from the deployer and its deployment nonce.
+    ///
+    /// Updates `zk_data` with the linked bytecode and factory dependencies, and returns the
+    /// libraries that have to be deployed before the contract, in order.
+    fn zk_link(
+        &self,
+        zk_data: &mut ZkSyncData,
+        link_data: ZkLinkData,
+        id: &ArtifactId,
+        deployer: Address,
+        deployment_nonce: u64,
+    ) -> Result<LinkOutput> {
+        let ZkLinkData { root, zksolc, output, libraries } = link_data;
+
+        let Some(target) = output
+            .artifact_ids()
+            .map(|(id, _)| id)
+            .find(|artifact| artifact.source == id.source && artifact.name == id.name)
+        else {
+            eyre::bail!("unable to find zk target artifact for linking");
+        };
+        let target = &target;
+
+        let linker = ZkLinker::new(root, output.artifact_ids().collect(), zksolc, &output);
+        let link_output = linker.zk_link_with_nonce_or_address(
+            libraries,
+            deployer,
+            deployment_nonce,
+            [target],
+        )?;
+
+        let mut factory_deps = BTreeSet::new();
+        let mut libs = BTreeSet::new();
+        linker.zk_collect_dependencies(target, &mut libs, Some(&mut factory_deps))?;
+
+        let linked_contracts = linker
+            .zk_get_linked_artifacts(
+                factory_deps.iter().chain(libs.iter()).copied().chain([target]),
+                &link_output.libraries,
+            )
+            .context("retrieving all fully linked contracts")?;
+
+        let linked_bytecode = |id: &ArtifactId| {
+            linked_contracts
+                .get(id)
+                .and_then(|contract| contract.get_bytecode_bytes())
+                .map(|bytecode| bytecode.to_vec())
+                .ok_or_else(|| eyre::eyre!("{} remained unlinked", id.identifier()))
+        };
+
+        zk_data.bytecode = linked_bytecode(target)?;
+        let mut all_factory_deps = zk_data.factory_deps.drain(..).collect::<HashSet<_>>();
+        all_factory_deps.insert(zk_data.bytecode.clone());
+        for id in factory_deps {
+            all_factory_deps.insert(linked_bytecode(id)?);
+        }
+        zk_data.factory_deps = all_factory_deps.into_iter().collect();
+
+        Ok(link_output)
+    }
+
+    /// Fills in the sender, nonce and fees of a ZKsync transaction.
+    #[allow(clippy::too_many_arguments)]
+    async fn fill_zk_tx<P: Provider<Zksync>>(
+        &self,
+        tx: &mut TransactionRequest,
+        provider: &P,
+        chain: u64,
+        from: Address,
+        nonce: u64,
+        gas_price: u128,
+        paymaster_params: Option<PaymasterParams>,
+    ) -> Result<()> {
+        if let Some(paymaster_params) = paymaster_params {
+            tx.set_paymaster_params(paymaster_params);
+        }
+        tx.set_from(from);
+        tx.set_chain_id(chain);
+        // `to` field must be set explicitly, cannot be None.
+        if tx.to().is_none() {
+            tx.set_create();
+        }
+        tx.set_nonce(nonce);
+        tx.set_gas_price(gas_price);
+
+        // estimate fee
+        foundry_zksync_core::estimate_fee(tx, provider, 130, self.zk_tx.gas_per_pubdata).await?;
+
+        Ok(())
+    }
+}
+
+/// Returns the deployment nonce of `address`, used by the ContractDeployer to compute the
+/// address of contracts deployed with CREATE.
+async fn get_deployment_nonce<P: Provider<Zksync>>(provider: &P, address: Address) -> Result<u64> {
+    let func = get_func("getDeploymentNonce(address)(uint256)")?;
+    let tx = TransactionRequest::default()
+        .with_to(foundry_zksync_core::NONCE_HOLDER_ADDRESS.to_address())
+        .with_input(func.abi_encode_input(&[DynSolValue::Address(address)])?);
+    let output = provider.call(tx).await?;
+
+    func.abi_decode_output(&output)?
+        .first()
+        .and_then(DynSolValue::as_uint)
+        .map(|(nonce, _)| nonce.to())
+        .ok_or_else(|| eyre::eyre!("invalid deployment nonce returned for {address}"))
 }
 
 /// Helper which manages the deployment transaction of a smart contract
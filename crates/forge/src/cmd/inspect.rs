@@ -87,8 +87,10 @@ pub fn run(self) -> Result<()> {
             let should_compile_with_zksolc = zksync::check_command_for_field(&field)?;
             if should_compile_with_zksolc {
                 let config = Config { ..Default::default() };
-                return zksync::inspect(&field, config, target_path, contract.name());
+                return zksync::inspect(&field, config, target_path, contract.name(), wrap);
             }
+        } else if field.is_zk_only() {
+            eyre::bail!("`{field}` is only available when compiling with zksolc, pass `--zksync`");
         }
 
         let mut output = compiler.files([target_path.clone()]).compile(&project)?;
@@ -182,6 +184,12 @@ pub fn run(self) -> Result<()> {
                     )?;
                 }
             }
+            ContractArtifactField::EravmAssembly
+            | ContractArtifactField::ZkBytecodeHash
+            | ContractArtifactField::FactoryDeps
+            | ContractArtifactField::ZkSize => {
+                unreachable!("zksolc-only fields are inspected with zksolc")
+            }
         };
 
         Ok(())
@@ -441,6 +449,10 @@ pub enum ContractArtifactField {
     Events,
     StandardJson,
     Libraries,
+    EravmAssembly,
+    ZkBytecodeHash,
+    FactoryDeps,
+    ZkSize,
 }
 
 macro_rules! impl_value_enum {
@@ -525,6 +537,13 @@ enum ContractArtifactField {
         Events            => "events" | "ev",
         StandardJson      => "standardJson" | "standard-json" | "standard_json",
         Libraries         => "libraries" | "lib" | "libs",
+        EravmAssembly     => "eravm-assembly" | "eravmAssembly" | "eravm_assembly"
+                             | "eravm-asm",
+        ZkBytecodeHash    => "zk-bytecode-hash" | "zkBytecodeHash" | "zk_bytecode_hash"
+                             | "zk-hash",
+        FactoryDeps       => "factory-deps" | "factoryDeps" | "factory_deps"
+                             | "factory-dependencies",
+        ZkSize            => "zk-size" | "zkSize" | "zk_size",
     }
 }
 
@@ -558,6 +577,9 @@ fn try_from(field: ContractArtifactField) -> Result<Self, Self::Error> {
                 Err(eyre!("StandardJson is not supported for ContractOutputSelection"))
             }
             Caf::Libraries => Err(eyre!("Libraries is not supported for ContractOutputSelection")),
+            Caf::EravmAssembly | Caf::ZkBytecodeHash | Caf::FactoryDeps | Caf::ZkSize => {
+                Err(eyre!("{field} is only supported with zksolc"))
+            }
         }
     }
 }
@@ -599,6 +621,14 @@ pub const fn can_skip_field(&self) -> bool {
         matches!(
             self,
             Self::Bytecode | Self::DeployedBytecode | Self::StandardJson | Self::Libraries
+        ) || self.is_zk_only()
+    }
+
+    /// Returns true if this field is only available for zksolc artifacts.
+    pub const fn is_zk_only(&self) -> bool {
+        matches!(
+            self,
+            Self::EravmAssembly | Self::ZkBytecodeHash | Self::FactoryDeps | Self::ZkSize
         )
     }
 }
@@ -676,6 +706,12 @@ fn contract_output_selection() {
                         .to_string()
                         .eq("Libraries is not supported for ContractOutputSelection")
                 );
+            } else if field.is_zk_only() {
+                let selection: Result<ContractOutputSelection, _> = field.try_into();
+                assert_eq!(
+                    selection.unwrap_err().to_string(),
+                    format!("{field} is only supported with zksolc")
+                );
             } else {
                 let selection: ContractOutputSelection = field.try_into().unwrap();
                 assert_eq!(field, selection);
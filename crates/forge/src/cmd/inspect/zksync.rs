@@ -1,23 +1,46 @@
-use eyre::Result;
-use foundry_common::compile::ProjectCompiler;
+use comfy_table::Cell;
+use eyre::{OptionExt, Result};
+use foundry_common::{compile::ProjectCompiler, shell};
+use foundry_compilers::{ProjectCompileOutput, info::ContractInfo};
 use foundry_config::Config;
-use std::path::PathBuf;
+use foundry_zksync_compilers::{
+    artifacts::output_selection::OutputSelectionFlag,
+    compilers::{
+        artifact_output::zk::{ERAVM_BYTECODE_WORDS_LIMIT, ZkArtifactOutput, ZkContractArtifact},
+        zksolc::ZkSolcCompiler,
+    },
+    dual_compiled_contracts::{DualCompiledContract, DualCompiledContracts},
+};
+use foundry_zksync_core::{H256, IMMUTABLE_SIMULATOR_STORAGE_ADDRESS};
+use serde::Serialize;
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    str::FromStr,
+};
 
-use super::{ContractArtifactField, print_json_str};
+use super::{
+    ContractArtifactField, missing_error, print_json, print_json_str, print_storage_layout,
+    print_table,
+};
 
 pub fn check_command_for_field(field: &ContractArtifactField) -> Result<bool> {
     // NOTE(zk): Fields that should have specific behavior for zksolc
     // TODO(zk): we should eventually migrate all fields from fields_zksolc_unimplemented_warn
     // to this array
-    let fields_zksolc_specific_behavior =
-        [ContractArtifactField::Bytecode, ContractArtifactField::DeployedBytecode];
-
-    let fields_zksolc_unimplemented_warn = [
-        ContractArtifactField::GasEstimates,
+    let fields_zksolc_specific_behavior = [
+        ContractArtifactField::Bytecode,
+        ContractArtifactField::DeployedBytecode,
+        ContractArtifactField::EravmAssembly,
+        ContractArtifactField::ZkBytecodeHash,
+        ContractArtifactField::FactoryDeps,
+        ContractArtifactField::ZkSize,
         ContractArtifactField::StorageLayout,
-        ContractArtifactField::Metadata,
     ];
 
+    let fields_zksolc_unimplemented_warn =
+        [ContractArtifactField::GasEstimates, ContractArtifactField::Metadata];
+
     let fields_zksolc_should_error = [
         ContractArtifactField::Assembly,
         ContractArtifactField::AssemblyOptimized,
@@ -45,8 +68,22 @@ pub fn inspect(
     config: Config,
     target_path: PathBuf,
     contract_name: Option<&str>,
+    wrap: bool,
 ) -> Result<()> {
-    let project = foundry_config::zksync::config_create_project(&config, false, true)?;
+    let mut project = if *field == ContractArtifactField::EravmAssembly {
+        foundry_config::zksync::config_create_project_with_eravm_assembly(&config, false, true)?
+    } else {
+        foundry_config::zksync::config_create_project(&config, false, true)?
+    };
+    if *field == ContractArtifactField::StorageLayout {
+        project
+            .settings
+            .settings
+            .output_selection
+            .all
+            .per_contract
+            .insert(OutputSelectionFlag::StorageLayout);
+    }
     let compiler = ProjectCompiler::new().quiet(true);
     let output = compiler.files([target_path.clone()]).zksync_compile(&project)?;
 
@@ -96,9 +133,222 @@ pub fn inspect(
         }
     }?;
 
-    if matches!(field, ContractArtifactField::Bytecode | ContractArtifactField::DeployedBytecode) {
-        print_json_str(&artifact.bytecode, Some("object"))?;
+    match field {
+        ContractArtifactField::Bytecode | ContractArtifactField::DeployedBytecode => {
+            print_json_str(&artifact.bytecode, Some("object"))?;
+        }
+        ContractArtifactField::EravmAssembly => {
+            let assembly = artifact.assembly.as_ref().ok_or_else(|| missing_error("assembly"))?;
+            sh_println!("{assembly}")?;
+        }
+        ContractArtifactField::ZkBytecodeHash => {
+            let hash = artifact.hash.as_ref().ok_or_else(|| missing_error("bytecode hash"))?;
+            sh_println!("0x{}", hash.trim_start_matches("0x"))?;
+        }
+        ContractArtifactField::ZkSize => print_zk_size(artifact)?,
+        ContractArtifactField::FactoryDeps => print_factory_deps(&output, artifact, wrap)?,
+        ContractArtifactField::StorageLayout => {
+            print_storage_layout(artifact.storage_layout.as_ref(), wrap)?;
+            if !shell::is_json() {
+                sh_eprintln!(
+                    "Note: immutables are not part of the storage layout on EraVM, they are stored \
+                     by the ImmutableSimulator system contract ({IMMUTABLE_SIMULATOR_STORAGE_ADDRESS:?}) \
+                     and can be read with `cast storage <address> --zksync --immutable <index>` or \
+                     `vm.zkGetImmutable`."
+                )?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Size of a zksolc artifact, as reported by `forge inspect <contract> zk-size`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ZkSize {
+    bytes: usize,
+    words: usize,
+    limit_words: usize,
+    exceeds_limit: bool,
+}
+
+fn print_zk_size(artifact: &ZkContractArtifact) -> Result<()> {
+    let bytecode = artifact.bytecode.as_ref().ok_or_else(|| missing_error("bytecode"))?;
+    let size = ZkSize {
+        bytes: bytecode.size(),
+        words: bytecode.size_in_words(),
+        limit_words: ERAVM_BYTECODE_WORDS_LIMIT,
+        exceeds_limit: bytecode.exceeds_size_limit(),
+    };
+
+    if shell::is_json() {
+        return print_json(&size);
+    }
+
+    sh_println!(
+        "{} words ({} bytes), EraVM limit is {} words",
+        size.words,
+        size.bytes,
+        size.limit_words
+    )?;
+    if size.exceeds_limit {
+        sh_warn!("contract exceeds the EraVM bytecode size limit and cannot be deployed")?;
+    }
+    Ok(())
+}
+
+/// A factory dependency of a zksolc artifact, along with its own factory dependencies.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FactoryDep {
+    name: String,
+    hash: String,
+    words: usize,
+    factory_deps: Vec<FactoryDep>,
+}
+
+/// Factory dependencies published when deploying a contract, as reported by
+/// `forge inspect <contract> factory-deps`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FactoryDeps {
+    factory_deps: Vec<FactoryDep>,
+    /// Number of unique bytecodes published with the deployment, including the contract itself.
+    published_count: usize,
+    /// Total size of the unique bytecodes published with the deployment, in bytes.
+    published_bytes: usize,
+}
+
+fn print_factory_deps(
+    output: &ProjectCompileOutput<ZkSolcCompiler, ZkArtifactOutput>,
+    artifact: &ZkContractArtifact,
+    wrap: bool,
+) -> Result<()> {
+    let hash = artifact.hash.as_ref().ok_or_else(|| missing_error("bytecode hash"))?;
+
+    let artifacts_by_hash: HashMap<_, _> = output
+        .artifact_ids()
+        .filter_map(|(id, artifact)| {
+            let info = ContractInfo {
+                name: id.name,
+                path: Some(id.source.to_string_lossy().into_owned()),
+            };
+            Some((artifact.hash.clone()?, (info, artifact)))
+        })
+        .collect();
+
+    let mut visited = HashSet::from([hash.clone()]);
+    let factory_deps = factory_deps_tree(&artifacts_by_hash, artifact, &mut visited);
+
+    // Publishing deduplicates the bytecodes of the whole tree
+    let contracts = zk_contracts(&artifacts_by_hash)?;
+    let (_, root) = contracts
+        .find_by_zk_bytecode_hash(H256::from_str(hash)?)
+        .ok_or_eyre("contract not found in the compiled artifacts")?;
+    let published = contracts.fetch_all_factory_deps(root);
+    let deps = FactoryDeps {
+        factory_deps,
+        published_count: published.len(),
+        published_bytes: published.iter().map(Vec::len).sum(),
+    };
+
+    if shell::is_json() {
+        return print_json(&deps);
     }
 
+    let headers = vec![Cell::new("Factory dependency"), Cell::new("Hash"), Cell::new("Words")];
+    print_table(
+        headers,
+        |table| {
+            let mut rows = vec![];
+            flatten_factory_deps(&deps.factory_deps, "", &mut rows);
+            for (name, dep) in rows {
+                table.add_row([name, dep.hash.clone(), dep.words.to_string()]);
+            }
+        },
+        wrap,
+    )?;
+    sh_println!(
+        "{} bytecodes ({} bytes) published on deployment, including the contract itself",
+        deps.published_count,
+        deps.published_bytes
+    )?;
     Ok(())
 }
+
+/// Recursively collects the factory dependencies of `artifact`.
+///
+/// Dependencies already present in the tree are not expanded again.
+fn factory_deps_tree(
+    artifacts_by_hash: &HashMap<String, (ContractInfo, &ZkContractArtifact)>,
+    artifact: &ZkContractArtifact,
+    visited: &mut HashSet<String>,
+) -> Vec<FactoryDep> {
+    let mut deps = vec![];
+    for (hash, path_and_name) in artifact.factory_dependencies.iter().flatten() {
+        let found = artifacts_by_hash.get(hash);
+        let name = found.map_or_else(|| path_and_name.clone(), |(info, _)| info.name.clone());
+        let words = found
+            .and_then(|(_, dep)| dep.bytecode.as_ref())
+            .map(|bytecode| bytecode.size_in_words())
+            .unwrap_or_default();
+        let factory_deps = match found {
+            Some((_, dep)) if visited.insert(hash.clone()) => {
+                factory_deps_tree(artifacts_by_hash, dep, visited)
+            }
+            _ => vec![],
+        };
+        deps.push(FactoryDep { name, hash: format!("0x{hash}"), words, factory_deps });
+    }
+    deps
+}
+
+fn flatten_factory_deps<'a>(
+    deps: &'a [FactoryDep],
+    indent: &str,
+    rows: &mut Vec<(String, &'a FactoryDep)>,
+) {
+    for (i, dep) in deps.iter().enumerate() {
+        let last = i == deps.len() - 1;
+        let branch = if last { "└── " } else { "├── " };
+        rows.push((format!("{indent}{branch}{}", dep.name), dep));
+        let indent = format!("{indent}{}", if last { "    " } else { "│   " });
+        flatten_factory_deps(&dep.factory_deps, &indent, rows);
+    }
+}
+
+/// Collects the zksolc artifacts as [DualCompiledContracts], to resolve the bytecodes published
+/// on deployment.
+fn zk_contracts(
+    artifacts_by_hash: &HashMap<String, (ContractInfo, &ZkContractArtifact)>,
+) -> Result<DualCompiledContracts> {
+    let bytecode = |artifact: &ZkContractArtifact| {
+        artifact.bytecode.as_ref().and_then(|bytecode| bytecode.object().into_bytes())
+    };
+
+    let mut contracts = DualCompiledContracts::default();
+    for (hash, (info, artifact)) in artifacts_by_hash {
+        let Some(zk_deployed_bytecode) = bytecode(artifact) else { continue };
+        let mut zk_factory_deps: Vec<Vec<u8>> = artifact
+            .factory_dependencies
+            .iter()
+            .flatten()
+            .filter_map(|(hash, _)| bytecode(artifacts_by_hash.get(hash)?.1))
+            .map(|bytecode| bytecode.to_vec())
+            .collect();
+        zk_factory_deps.push(zk_deployed_bytecode.to_vec());
+
+        contracts.insert(
+            info.clone(),
+            DualCompiledContract {
+                zk_bytecode_hash: H256::from_str(hash)?,
+                zk_deployed_bytecode: zk_deployed_bytecode.to_vec(),
+                zk_factory_deps,
+                ..Default::default()
+            },
+        );
+    }
+    Ok(contracts)
+}
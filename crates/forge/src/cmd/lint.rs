@@ -22,7 +22,9 @@ pub struct LintArgs {
 
     /// Specifies which lints to run based on severity. Overrides the `severity` project config.
     ///
-    /// Supported values: `high`, `med`, `low`, `info`, `gas`.
+    /// Supported values: `high`, `med`, `low`, `info`, `gas`, `code-size`, `zksync`.
+    ///
+    /// `zksync` lints are enabled by default when compiling with zksolc.
     #[arg(long, value_name = "SEVERITY", num_args(1..))]
     pub(crate) severity: Option<Vec<Severity>>,
 
@@ -90,7 +92,9 @@ pub fn run(self) -> Result<()> {
         let (include, exclude, severity) = match &self.lint {
             Some(cli_lints) => (Some(parse_lints(cli_lints)?), None, vec![]),
             None => {
-                let severity = self.severity.clone().unwrap_or(config.lint.severity.clone());
+                let severity = self.severity.clone().unwrap_or_else(|| {
+                    config.lint.severity_with_zksync(config.zksync.should_compile())
+                });
                 (None, Some(parse_lints(&config.lint.exclude_lints)?), severity)
             }
         };
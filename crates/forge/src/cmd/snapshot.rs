@@ -8,6 +8,7 @@
 use eyre::{Context, Result};
 use foundry_cli::utils::STATIC_FUZZ_SEED;
 use foundry_common::shell;
+use foundry_zksync_core::vm::ZkPubdataUsage;
 use regex::Regex;
 use std::{
     cmp::Ordering,
@@ -22,7 +23,7 @@
 /// A regex that matches a basic snapshot entry like
 /// `Test:testDeposit() (gas: 58804)`
 pub static RE_BASIC_SNAPSHOT_ENTRY: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"(?P<file>(.*?)):(?P<sig>(\w+)\s*\((.*?)\))\s*\(((gas:)?\s*(?P<gas>\d+)|(runs:\s*(?P<runs>\d+),\s*μ:\s*(?P<avg>\d+),\s*~:\s*(?P<med>\d+))|(runs:\s*(?P<invruns>\d+),\s*calls:\s*(?P<calls>\d+),\s*reverts:\s*(?P<reverts>\d+)))\)").unwrap()
+    Regex::new(r"(?P<file>(.*?)):(?P<sig>(\w+)\s*\((.*?)\))\s*\(((gas:)?\s*(?P<gas>\d+)(,\s*pubdata:\s*(?P<pubdata>\d+),\s*l1 cost:\s*(?P<l1cost>\d+))?|(runs:\s*(?P<runs>\d+),\s*μ:\s*(?P<avg>\d+),\s*~:\s*(?P<med>\d+))|(runs:\s*(?P<invruns>\d+),\s*calls:\s*(?P<calls>\d+),\s*reverts:\s*(?P<reverts>\d+)))\)").unwrap()
 });
 
 /// CLI arguments for `forge snapshot`.
@@ -215,6 +216,7 @@ fn apply(&self, outcome: TestOutcome) -> Vec<SuiteTestResult> {
 ///
 /// Has the form:
 ///   `<signature>(gas:? 40181)` for normal tests
+///   `<signature>(gas: 40181, pubdata: 224, l1 cost: 224000)` for normal tests in zkEVM
 ///   `<signature>(runs: 256, μ: 40181, ~: 40181)` for fuzz tests
 ///   `<signature>(runs: 256, calls: 40181, reverts: 40181)` for invariant tests
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -239,6 +241,11 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
                                 signature: sig.as_str().to_string(),
                                 gas_used: TestKindReport::Unit {
                                     gas: gas.as_str().parse().unwrap(),
+                                    zk_pubdata: cap.name("pubdata").zip(cap.name("l1cost")).map(
+                                        |(bytes, l1_cost)| {
+                                            parse_zk_pubdata(bytes.as_str(), l1_cost.as_str())
+                                        },
+                                    ),
                                 },
                             })
                         } else if let Some(runs) = cap.name("runs") {
@@ -281,6 +288,21 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
     }
 }
 
+/// Parses the pubdata of a gas snapshot entry.
+///
+/// Only the published bytes and their L1 cost are part of the snapshot, so the pubdata price is
+/// derived from them.
+fn parse_zk_pubdata(bytes: &str, l1_cost: &str) -> ZkPubdataUsage {
+    let published: u64 = bytes.parse().unwrap();
+    let l1_cost: u128 = l1_cost.parse().unwrap();
+    let pubdata_price = l1_cost.checked_div(published.into()).unwrap_or_default();
+    ZkPubdataUsage {
+        published,
+        pubdata_price: pubdata_price.try_into().unwrap_or(u64::MAX),
+        ..Default::default()
+    }
+}
+
 /// Reads a list of gas snapshot entries from a gas snapshot file.
 fn read_gas_snapshot(path: impl AsRef<Path>) -> Result<Vec<GasSnapshotEntry>> {
     let path = path.as_ref();
@@ -587,7 +609,28 @@ fn can_parse_basic_gas_snapshot_entry() {
             GasSnapshotEntry {
                 contract_name: "Test".to_string(),
                 signature: "deposit()".to_string(),
-                gas_used: TestKindReport::Unit { gas: 7222 }
+                gas_used: TestKindReport::Unit { gas: 7222, zk_pubdata: None }
+            }
+        );
+    }
+
+    #[test]
+    fn can_parse_zk_gas_snapshot_entry() {
+        let s = "Test:deposit() (gas: 7222, pubdata: 224, l1 cost: 224000)";
+        let entry = GasSnapshotEntry::from_str(s).unwrap();
+        assert_eq!(
+            entry,
+            GasSnapshotEntry {
+                contract_name: "Test".to_string(),
+                signature: "deposit()".to_string(),
+                gas_used: TestKindReport::Unit {
+                    gas: 7222,
+                    zk_pubdata: Some(ZkPubdataUsage {
+                        published: 224,
+                        pubdata_price: 1000,
+                        ..Default::default()
+                    })
+                }
             }
         );
     }
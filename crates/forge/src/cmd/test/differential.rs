@@ -0,0 +1,205 @@
+//! Differential testing of the EVM against EraVM, see `forge test --zk-differential`.
+
+use crate::result::{SuiteResult, TestKind, TestResult, TestStatus};
+use alloy_primitives::Log;
+use eyre::Result;
+use std::collections::BTreeMap;
+use yansi::Paint;
+
+/// A test whose outcome differs between the EVM and the EraVM runs.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TestDivergence {
+    /// The test suite identifier, `path:contract`.
+    pub suite: String,
+    /// The test function signature.
+    pub test: String,
+    /// How the outcomes differ.
+    pub differences: Vec<String>,
+}
+
+/// Compares the results of the EVM and the EraVM runs of the same tests, and returns the tests
+/// whose status, revert reason, emitted logs or return data differ.
+///
+/// Only the status of fuzz and invariant tests is compared, as their inputs depend on the state
+/// collected during each run.
+pub fn find_divergences(
+    evm: &BTreeMap<String, SuiteResult>,
+    zk: &BTreeMap<String, SuiteResult>,
+) -> Vec<TestDivergence> {
+    let mut divergences = Vec::new();
+
+    for (suite, zk_suite) in zk {
+        let evm_suite = evm.get(suite);
+        for (test, zk_result) in &zk_suite.test_results {
+            let differences = match evm_suite.and_then(|suite| suite.test_results.get(test)) {
+                Some(evm_result) => compare_results(evm_result, zk_result),
+                None => vec!["not run on the EVM".to_string()],
+            };
+            if !differences.is_empty() {
+                divergences.push(TestDivergence {
+                    suite: suite.clone(),
+                    test: test.clone(),
+                    differences,
+                });
+            }
+        }
+    }
+
+    for (suite, evm_suite) in evm {
+        let zk_suite = zk.get(suite);
+        for test in evm_suite.test_results.keys() {
+            if zk_suite.is_none_or(|suite| !suite.test_results.contains_key(test)) {
+                divergences.push(TestDivergence {
+                    suite: suite.clone(),
+                    test: test.clone(),
+                    differences: vec!["not run on EraVM".to_string()],
+                });
+            }
+        }
+    }
+
+    divergences
+}
+
+/// Prints the divergences found between the EVM and the EraVM runs, failing if there are any.
+pub fn report_divergences(divergences: &[TestDivergence], num_tests: usize) -> Result<()> {
+    if divergences.is_empty() {
+        sh_println!("\nNo divergences found between the EVM and EraVM in {num_tests} tests")?;
+        return Ok(());
+    }
+
+    for TestDivergence { suite, test, differences } in divergences {
+        let contract = suite.split(':').next_back().unwrap_or(suite);
+        sh_eprintln!(
+            "{}",
+            format!("\n[{contract}] {test} diverges between the EVM and EraVM:").red().bold()
+        )?;
+        for difference in differences {
+            sh_eprintln!("{}", format!("- {difference}").red())?;
+        }
+    }
+    sh_eprintln!()?;
+
+    let len = divergences.len();
+    let tests = if len > 1 { "tests" } else { "test" };
+    eyre::bail!("{len} {tests} diverged between the EVM and EraVM")
+}
+
+fn compare_results(evm: &TestResult, zk: &TestResult) -> Vec<String> {
+    if evm.status != zk.status {
+        return vec![format!(
+            "status: {} on the EVM, {} on EraVM",
+            status_name(evm.status),
+            status_name(zk.status)
+        )];
+    }
+
+    let mut differences = Vec::new();
+    if !matches!(zk.kind, TestKind::Unit { .. }) {
+        return differences;
+    }
+
+    if evm.reason != zk.reason {
+        differences.push(format!(
+            "revert reason: {:?} on the EVM, {:?} on EraVM",
+            evm.reason.as_deref().unwrap_or_default(),
+            zk.reason.as_deref().unwrap_or_default()
+        ));
+    }
+
+    let evm_logs = user_logs(&evm.logs);
+    let zk_logs = user_logs(&zk.logs);
+    if evm_logs.len() != zk_logs.len() {
+        differences.push(format!(
+            "emitted logs: {} on the EVM, {} on EraVM",
+            evm_logs.len(),
+            zk_logs.len()
+        ));
+    } else if let Some(index) = evm_logs.iter().zip(&zk_logs).position(|(evm, zk)| evm != zk) {
+        differences.push(format!("emitted logs: log #{index} differs"));
+    }
+
+    // Revert data is already compared through the decoded revert reason.
+    if evm.status == TestStatus::Success && evm.return_data != zk.return_data {
+        differences.push(format!(
+            "return data: {} on the EVM, {} on EraVM",
+            evm.return_data, zk.return_data
+        ));
+    }
+
+    differences
+}
+
+/// Returns the logs emitted by user contracts, leaving out the events of EraVM system contracts.
+fn user_logs(logs: &[Log]) -> Vec<&Log> {
+    logs.iter().filter(|log| !foundry_zksync_core::is_system_address(log.address)).collect()
+}
+
+fn status_name(status: TestStatus) -> &'static str {
+    match status {
+        TestStatus::Success => "passed",
+        TestStatus::Failure => "failed",
+        TestStatus::Skipped => "skipped",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, Bytes, LogData};
+    use std::time::Duration;
+
+    fn suite(tests: impl IntoIterator<Item = (&'static str, TestResult)>) -> SuiteResult {
+        let tests = tests.into_iter().map(|(name, result)| (name.to_string(), result)).collect();
+        SuiteResult::new(Duration::default(), tests, vec![])
+    }
+
+    fn result(status: TestStatus, reason: Option<&str>, logs: Vec<Log>) -> TestResult {
+        TestResult {
+            status,
+            reason: reason.map(str::to_string),
+            logs,
+            kind: TestKind::Unit { gas: 0, zk_pubdata: None },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_differential_finds_divergences() {
+        let log = Log { address: Address::repeat_byte(0x11), data: LogData::default() };
+        let system_log = Log { address: Address::with_last_byte(0x80), data: LogData::default() };
+
+        let evm = BTreeMap::from([(
+            "src/Test.t.sol:Test".to_string(),
+            suite([
+                ("testSame()", result(TestStatus::Success, None, vec![log.clone()])),
+                ("testStatus()", result(TestStatus::Success, None, vec![])),
+                ("testReason()", result(TestStatus::Failure, Some("a"), vec![])),
+                ("testLogs()", result(TestStatus::Success, None, vec![log.clone()])),
+                ("testReturn()", result(TestStatus::Success, None, vec![])),
+            ]),
+        )]);
+        let zk = BTreeMap::from([(
+            "src/Test.t.sol:Test".to_string(),
+            suite([
+                ("testSame()", result(TestStatus::Success, None, vec![system_log, log])),
+                ("testStatus()", result(TestStatus::Failure, Some("a"), vec![])),
+                ("testReason()", result(TestStatus::Failure, Some("b"), vec![])),
+                ("testLogs()", result(TestStatus::Success, None, vec![])),
+                (
+                    "testReturn()",
+                    TestResult {
+                        return_data: Bytes::from_static(&[1]),
+                        ..result(TestStatus::Success, None, vec![])
+                    },
+                ),
+            ]),
+        )]);
+
+        let divergences = find_divergences(&evm, &zk);
+        let diverged = divergences.iter().map(|d| d.test.as_str()).collect::<Vec<_>>();
+        assert_eq!(diverged, ["testLogs()", "testReason()", "testReturn()", "testStatus()"]);
+        assert_eq!(divergences[2].differences, ["return data: 0x on the EVM, 0x01 on EraVM"]);
+        assert_eq!(divergences[3].differences, ["status: passed on the EVM, failed on EraVM"]);
+    }
+}
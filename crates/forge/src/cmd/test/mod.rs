@@ -40,6 +40,7 @@
 };
 use foundry_debugger::Debugger;
 use foundry_evm::{
+    executors::strategy::ExecutorStrategy,
     opts::EvmOpts,
     traces::{backtrace::BacktraceBuilder, identifier::TraceIdentifiers, prune_trace_depth},
 };
@@ -55,6 +56,7 @@
 };
 use yansi::Paint;
 
+mod differential;
 mod filter;
 mod summary;
 use crate::{result::TestKind, traces::render_trace_arena_inner};
@@ -194,6 +196,13 @@ pub struct TestArgs {
     #[arg(long, help_heading = "Display options")]
     pub disable_labels: bool,
 
+    /// Run each test both on the EVM and on EraVM, and report the tests whose status, revert
+    /// reason, emitted logs or return data differ.
+    ///
+    /// Implies `--zksync`.
+    #[arg(long, conflicts_with_all = ["debug", "flamegraph", "flamechart", "list", "junit"])]
+    pub zk_differential: bool,
+
     #[command(flatten)]
     filter: FilterArgs,
 
@@ -297,7 +306,15 @@ pub async fn run_tests(
         filter: &ProjectPathsAwareFilter,
         coverage: bool,
     ) -> Result<TestOutcome> {
-        let mut strategy = utils::get_executor_strategy(&config);
+        if self.zk_differential {
+            if shell::is_json() {
+                bail!("--zk-differential does not support JSON output");
+            }
+            // Both the EVM and the EraVM artifacts are needed.
+            config.zksync.compile = true;
+        }
+
+        let mut strategy = utils::get_executor_strategy(&config)?;
 
         // Explicitly enable isolation for gas reports for more correct gas accounting.
         if self.gas_report {
@@ -346,16 +363,32 @@ pub async fn run_tests(
         let (zk_output, dual_compiled_contracts) = if config.zksync.should_compile() {
             // Build EVM ProjectPathsConfig from config to avoid needing a Project instance here.
             let evm_paths = config.project_paths();
-            let zk_project =
-                foundry_config::zksync::config_create_project(&config, config.cache, false)?;
+            // EraVM assembly is needed to map coverage hits back to source lines.
+            let zk_project = if coverage {
+                foundry_config::zksync::config_create_project_with_eravm_assembly(
+                    &config,
+                    config.cache,
+                    false,
+                )?
+            } else {
+                foundry_config::zksync::config_create_project(&config, config.cache, false)?
+            };
 
             let sources_to_compile = self.get_sources_to_compile(&config, filter)?;
             let zk_compiler = ProjectCompiler::new().files(sources_to_compile);
 
             let zk_output = zk_compiler.zksync_compile(&zk_project)?;
-            let dual_compiled_contracts =
+            let mut dual_compiled_contracts =
                 DualCompiledContracts::new(output, &zk_output, &evm_paths, &zk_project.paths);
 
+            if let Some(zkvyper_project) =
+                foundry_config::zksync::config_create_zkvyper_project(&config, config.cache, false)?
+            {
+                let zkvyper_output =
+                    ProjectCompiler::new().zksync_compile_vyper(&zkvyper_project)?;
+                dual_compiled_contracts.extend_with_zkvyper(output, &zkvyper_output);
+            }
+
             (Some(zk_output), Some(dual_compiled_contracts))
         } else {
             (None, None)
@@ -366,22 +399,48 @@ pub async fn run_tests(
             dual_compiled_contracts.unwrap_or_default(),
         );
 
-        let runner = MultiContractRunnerBuilder::new(config.clone())
-            .set_debug(should_debug)
-            .set_decode_internal(decode_internal)
-            .initial_balance(evm_opts.initial_balance)
-            .evm_spec(config.evm_spec_id())
-            .sender(evm_opts.sender)
-            .with_fork(evm_opts.get_fork(&config, env.clone()))
-            .enable_isolation(evm_opts.isolate)
-            .networks(evm_opts.networks)
-            .fail_fast(self.fail_fast)
-            .set_coverage(coverage)
-            .build::<MultiCompiler>(output, zk_output, env, evm_opts, strategy)?;
+        let runner_builder = || {
+            MultiContractRunnerBuilder::new(config.clone())
+                .set_debug(should_debug)
+                .set_decode_internal(decode_internal)
+                .initial_balance(evm_opts.initial_balance)
+                .evm_spec(config.evm_spec_id())
+                .sender(evm_opts.sender)
+                .with_fork(evm_opts.get_fork(&config, env.clone()))
+                .enable_isolation(evm_opts.isolate)
+                .networks(evm_opts.networks)
+                .fail_fast(self.fail_fast)
+                .set_coverage(coverage)
+        };
+
+        // Run the tests on the EVM first, the results are compared with the EraVM ones below.
+        let evm_results = if self.zk_differential {
+            let mut evm_runner = runner_builder().build::<MultiCompiler>(
+                output,
+                None,
+                env.clone(),
+                evm_opts.clone(),
+                ExecutorStrategy::new_evm(),
+            )?;
+            let filter = filter.clone();
+            sh_println!("Running tests on the EVM for differential testing...")?;
+            Some(tokio::task::spawn_blocking(move || evm_runner.test_collect(&filter)).await??)
+        } else {
+            None
+        };
+
+        let runner =
+            runner_builder().build::<MultiCompiler>(output, zk_output, env, evm_opts, strategy)?;
 
         let libraries = runner.libraries.clone();
         let mut outcome = self.run_tests_inner(runner, config, verbosity, filter, output).await?;
 
+        if let Some(evm_results) = evm_results {
+            let divergences = differential::find_divergences(&evm_results, &outcome.results);
+            let num_tests = outcome.results.values().map(|suite| suite.test_results.len()).sum();
+            differential::report_divergences(&divergences, num_tests)?;
+        }
+
         if should_draw {
             let (suite_name, test_name, mut test_result) =
                 outcome.remove_first().ok_or_eyre("no tests were executed")?;
@@ -583,6 +642,13 @@ async fn run_tests_inner(
                 builder.with_signature_identifier(SignaturesIdentifier::from_config(&config)?);
         }
 
+        // Identify the system contracts in EraVM call traces.
+        if config.zksync.run_in_zk_mode() {
+            builder = builder
+                .with_labels(foundry_zksync_core::system_contract_labels())
+                .with_abi(&foundry_zksync_core::system_contracts_abi());
+        }
+
         if self.decode_internal {
             let sources =
                 ContractSources::from_project_output(output, &config.root, Some(&libraries))?;
@@ -734,7 +800,7 @@ async fn run_tests_inner(
                 }
 
                 if let Some(gas_report) = &mut gas_report {
-                    gas_report.analyze(result.traces.iter().map(|(_, a)| &a.arena), &decoder).await;
+                    gas_report.analyze(result.traces.iter().map(|(_, a)| a), &decoder).await;
 
                     for trace in &result.gas_report_traces {
                         decoder.clear_addresses();
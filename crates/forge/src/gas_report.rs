@@ -2,7 +2,7 @@
 
 use crate::{
     constants::CHEATCODE_ADDRESS,
-    traces::{CallTraceArena, CallTraceDecoder, CallTraceNode, DecodedCallData},
+    traces::{CallTraceDecoder, CallTraceNode, DecodedCallData, SparsedTraceArena, ZkPubdata},
 };
 use alloy_primitives::map::HashSet;
 use comfy_table::{
@@ -65,15 +65,22 @@ fn should_report(&self, contract_name: &str) -> bool {
     /// Analyzes the given traces and generates a gas report.
     pub async fn analyze(
         &mut self,
-        arenas: impl IntoIterator<Item = &CallTraceArena>,
+        arenas: impl IntoIterator<Item = &SparsedTraceArena>,
         decoder: &CallTraceDecoder,
     ) {
-        for node in arenas.into_iter().flat_map(|arena| arena.nodes()) {
-            self.analyze_node(node, decoder).await;
+        for arena in arenas {
+            for node in arena.nodes() {
+                self.analyze_node(node, arena.zk_pubdata.get(&node.idx), decoder).await;
+            }
         }
     }
 
-    async fn analyze_node(&mut self, node: &CallTraceNode, decoder: &CallTraceDecoder) {
+    async fn analyze_node(
+        &mut self,
+        node: &CallTraceNode,
+        zk_pubdata: Option<&ZkPubdata>,
+        decoder: &CallTraceDecoder,
+    ) {
         let trace = &node.trace;
 
         if trace.address == CHEATCODE_ADDRESS || trace.address == HARDHAT_CONSOLE_ADDRESS {
@@ -111,6 +118,8 @@ async fn analyze_node(&mut self, node: &CallTraceNode, decoder: &CallTraceDecode
         if is_create_call {
             trace!(contract_name, "adding create gas info");
             contract_info.gas = trace.gas_used;
+            contract_info.pubdata = zk_pubdata.map(|pubdata| pubdata.bytes);
+            contract_info.l1_cost = zk_pubdata.map(|pubdata| pubdata.l1_cost);
         } else if let Some(DecodedCallData { signature, .. }) = decoded().await.call_data {
             let name = signature.split('(').next().unwrap();
             // ignore any test/setup functions
@@ -123,6 +132,9 @@ async fn analyze_node(&mut self, node: &CallTraceNode, decoder: &CallTraceDecode
                     .entry(signature.clone())
                     .or_default();
                 gas_info.frames.push(trace.gas_used);
+                if let Some(pubdata) = zk_pubdata {
+                    gas_info.pubdata_frames.push(*pubdata);
+                }
             }
         }
     }
@@ -140,6 +152,17 @@ pub fn finalize(mut self) -> Self {
                     func.mean = calc::mean(&func.frames);
                     func.median = calc::median_sorted(&func.frames);
                     func.calls = func.frames.len() as u64;
+                    if !func.pubdata_frames.is_empty() {
+                        let bytes = func
+                            .pubdata_frames
+                            .iter()
+                            .map(|pubdata| pubdata.bytes)
+                            .collect::<Vec<_>>();
+                        let l1_cost =
+                            func.pubdata_frames.iter().map(|pubdata| pubdata.l1_cost).sum::<u128>();
+                        func.pubdata = Some(calc::mean(&bytes));
+                        func.l1_cost = Some(l1_cost / func.pubdata_frames.len() as u128);
+                    }
                 }
             }
         }
@@ -190,12 +213,18 @@ fn format_json_output(&self) -> String {
                         })
                         .collect::<BTreeMap<_, _>>();
 
+                    let mut deployment = json!({
+                        "gas": contract.gas,
+                        "size": contract.size,
+                    });
+                    if contract.has_zk_pubdata() {
+                        deployment["pubdata"] = json!(contract.pubdata);
+                        deployment["l1_cost"] = json!(contract.l1_cost);
+                    }
+
                     Some(json!({
                         "contract": name,
-                        "deployment": {
-                            "gas": contract.gas,
-                            "size": contract.size,
-                        },
+                        "deployment": deployment,
                         "functions": functions,
                     }))
                 })
@@ -214,26 +243,46 @@ fn format_table_output(&self, contract: &ContractInfo, name: &str) -> Table {
 
         table.set_header(vec![Cell::new(format!("{name} Contract")).fg(Color::Magenta)]);
 
-        table.add_row(vec![
+        // Pubdata columns are only shown for contracts executed in zkEVM.
+        let with_pubdata = contract.has_zk_pubdata();
+
+        let mut deployment_header = vec![
             Cell::new("Deployment Cost").fg(Color::Cyan),
             Cell::new("Deployment Size").fg(Color::Cyan),
-        ]);
-        table.add_row(vec![
+        ];
+        let mut deployment_row = vec![
             Cell::new(contract.gas.to_string()).set_alignment(CellAlignment::Right),
             Cell::new(contract.size.to_string()).set_alignment(CellAlignment::Right),
-        ]);
+        ];
+        if with_pubdata {
+            deployment_header.extend([
+                Cell::new("Deployment Pubdata").fg(Color::Cyan),
+                Cell::new("Deployment L1 Cost").fg(Color::Cyan),
+            ]);
+            deployment_row
+                .extend([optional_cell(contract.pubdata), optional_cell(contract.l1_cost)]);
+        }
+        table.add_row(deployment_header);
+        table.add_row(deployment_row);
 
         // Add a blank row to separate deployment info from function info.
         table.add_row(vec![Cell::new("")]);
 
-        table.add_row(vec![
+        let mut function_header = vec![
             Cell::new("Function Name"),
             Cell::new("Min").fg(Color::Green),
             Cell::new("Avg").fg(Color::Yellow),
             Cell::new("Median").fg(Color::Yellow),
             Cell::new("Max").fg(Color::Red),
             Cell::new("# Calls").fg(Color::Cyan),
-        ]);
+        ];
+        if with_pubdata {
+            function_header.extend([
+                Cell::new("Pubdata").fg(Color::Cyan),
+                Cell::new("L1 Cost").fg(Color::Cyan),
+            ]);
+        }
+        table.add_row(function_header);
 
         contract.functions.iter().for_each(|(fname, sigs)| {
             sigs.iter().for_each(|(sig, gas_info)| {
@@ -241,7 +290,7 @@ fn format_table_output(&self, contract: &ContractInfo, name: &str) -> Table {
                 let display_name =
                     if sigs.len() == 1 { fname.to_string() } else { sig.replace(':', "") };
 
-                table.add_row(vec![
+                let mut row = vec![
                     Cell::new(display_name),
                     Cell::new(gas_info.min.to_string())
                         .fg(Color::Green)
@@ -256,7 +305,11 @@ fn format_table_output(&self, contract: &ContractInfo, name: &str) -> Table {
                         .fg(Color::Red)
                         .set_alignment(CellAlignment::Right),
                     Cell::new(gas_info.calls.to_string()).set_alignment(CellAlignment::Right),
-                ]);
+                ];
+                if with_pubdata {
+                    row.extend([optional_cell(gas_info.pubdata), optional_cell(gas_info.l1_cost)]);
+                }
+                table.add_row(row);
             })
         });
 
@@ -264,14 +317,38 @@ fn format_table_output(&self, contract: &ContractInfo, name: &str) -> Table {
     }
 }
 
+/// Returns a right-aligned cell with the given value, or an empty cell if there is no value.
+fn optional_cell(value: Option<impl Display>) -> Cell {
+    Cell::new(value.map(|value| value.to_string()).unwrap_or_default())
+        .set_alignment(CellAlignment::Right)
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ContractInfo {
     pub gas: u64,
     pub size: usize,
+    /// Pubdata bytes published by the deployment in zkEVM.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pubdata: Option<u64>,
+    /// L1 cost in wei of the pubdata published by the deployment in zkEVM.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l1_cost: Option<u128>,
     /// Function name -> Function signature -> GasInfo
     pub functions: BTreeMap<String, BTreeMap<String, GasInfo>>,
 }
 
+impl ContractInfo {
+    /// Returns whether the contract was deployed or called in zkEVM.
+    fn has_zk_pubdata(&self) -> bool {
+        self.pubdata.is_some()
+            || self
+                .functions
+                .values()
+                .flat_map(|sigs| sigs.values())
+                .any(|gas| gas.pubdata.is_some())
+    }
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct GasInfo {
     pub calls: u64,
@@ -279,7 +356,15 @@ pub struct GasInfo {
     pub mean: u64,
     pub median: u64,
     pub max: u64,
+    /// Average pubdata bytes published in zkEVM.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pubdata: Option<u64>,
+    /// Average L1 cost in wei of the pubdata published in zkEVM.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l1_cost: Option<u128>,
 
     #[serde(skip)]
     pub frames: Vec<u64>,
+    #[serde(skip)]
+    pub pubdata_frames: Vec<ZkPubdata>,
 }
@@ -357,6 +357,7 @@ pub fn configure_executor(&self, executor: &mut Executor) {
                 Arc::new(cheatcodes.config.clone_with(&self.config, self.evm_opts.clone()));
         }
         inspector.tracing(self.trace_mode());
+        inspector.zk_call_tree(verbosity() > 4);
         inspector.collect_line_coverage(self.line_coverage);
         inspector.enable_isolation(self.isolation);
         inspector.networks(self.networks);
@@ -391,6 +392,7 @@ pub fn executor(
                     .logs(self.config.live_logs)
                     .cheatcodes(cheats_config)
                     .trace_mode(self.trace_mode())
+                    .zk_call_tree(verbosity() > 4)
                     .line_coverage(self.line_coverage)
                     .enable_isolation(self.isolation)
                     .networks(self.networks)
@@ -6,7 +6,7 @@
     gas_report::GasReport,
 };
 use alloy_primitives::{
-    Address, I256, Log, U256,
+    Address, Bytes, I256, Log, U256,
     map::{AddressHashMap, HashMap},
 };
 use eyre::Report;
@@ -17,8 +17,9 @@
     decode::SkipReason,
     executors::{RawCallResult, invariant::InvariantMetrics},
     fuzz::{CounterExample, FuzzCase, FuzzFixtures, FuzzTestResult},
-    traces::{CallTraceArena, CallTraceDecoder, TraceKind, Traces},
+    traces::{CallTraceDecoder, SparsedTraceArena, TraceKind, Traces},
 };
+use foundry_zksync_core::vm::ZkPubdataUsage;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::{BTreeMap, HashMap as Map},
@@ -429,6 +430,11 @@ pub struct TestResult {
     /// What kind of test this was
     pub kind: TestKind,
 
+    /// Data returned by the test function of a unit test, compared between the EVM and EraVM
+    /// runs of `--zk-differential`.
+    #[serde(skip)]
+    pub return_data: Bytes,
+
     /// Traces
     pub traces: Traces,
 
@@ -436,7 +442,7 @@ pub struct TestResult {
     ///
     /// These are cleared after the gas report is analyzed.
     #[serde(skip)]
-    pub gas_report_traces: Vec<Vec<CallTraceArena>>,
+    pub gas_report_traces: Vec<Vec<SparsedTraceArena>>,
 
     /// Raw line coverage info
     #[serde(skip)]
@@ -600,7 +606,12 @@ pub fn single_result(
     ) {
         self.kind = TestKind::Unit {
             gas: raw_call_result.gas_used.saturating_sub(raw_call_result.stipend),
+            zk_pubdata: raw_call_result
+                .cheatcodes
+                .as_ref()
+                .and_then(|cheatcodes| cheatcodes.zk_pubdata),
         };
+        self.return_data = raw_call_result.result.clone();
 
         extend!(self, raw_call_result, TraceKind::Execution);
 
@@ -718,7 +729,7 @@ pub fn invariant_setup_fail(&mut self, e: Report) {
     #[expect(clippy::too_many_arguments)]
     pub fn invariant_result(
         &mut self,
-        gas_report_traces: Vec<Vec<CallTraceArena>>,
+        gas_report_traces: Vec<Vec<SparsedTraceArena>>,
         success: bool,
         reason: Option<String>,
         counterexample: Option<CounterExample>,
@@ -800,6 +811,8 @@ pub fn merge_coverages(&mut self, other_coverage: Option<HitMaps>) {
 pub enum TestKindReport {
     Unit {
         gas: u64,
+        /// Pubdata published by calls executed in EraVM.
+        zk_pubdata: Option<ZkPubdataUsage>,
     },
     Fuzz {
         runs: usize,
@@ -826,8 +839,13 @@ pub enum TestKindReport {
 impl fmt::Display for TestKindReport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Unit { gas } => {
-                write!(f, "(gas: {gas})")
+            Self::Unit { gas, zk_pubdata } => {
+                if let Some(pubdata) = zk_pubdata {
+                    let (bytes, l1_cost) = (pubdata.published, pubdata.l1_cost());
+                    write!(f, "(gas: {gas}, pubdata: {bytes}, l1 cost: {l1_cost})")
+                } else {
+                    write!(f, "(gas: {gas})")
+                }
             }
             Self::Fuzz { runs, mean_gas, median_gas, failed_corpus_replays } => {
                 if *failed_corpus_replays != 0 {
@@ -870,7 +888,7 @@ impl TestKindReport {
     /// Returns the main gas value to compare against
     pub fn gas(&self) -> u64 {
         match *self {
-            Self::Unit { gas } => gas,
+            Self::Unit { gas, .. } => gas,
             // We use the median for comparisons
             Self::Fuzz { median_gas, .. } | Self::Table { median_gas, .. } => median_gas,
             // We return 0 since it's not applicable
@@ -883,7 +901,12 @@ pub fn gas(&self) -> u64 {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum TestKind {
     /// A unit test.
-    Unit { gas: u64 },
+    Unit {
+        gas: u64,
+        /// Pubdata published by calls executed in EraVM.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        zk_pubdata: Option<ZkPubdataUsage>,
+    },
     /// A fuzz test.
     Fuzz {
         /// we keep this for the debugger
@@ -909,7 +932,7 @@ pub enum TestKind {
 
 impl Default for TestKind {
     fn default() -> Self {
-        Self::Unit { gas: 0 }
+        Self::Unit { gas: 0, zk_pubdata: None }
     }
 }
 
@@ -927,7 +950,9 @@ pub fn is_invariant(&self) -> bool {
     /// The gas consumed by this test
     pub fn report(&self) -> TestKindReport {
         match self {
-            Self::Unit { gas } => TestKindReport::Unit { gas: *gas },
+            Self::Unit { gas, zk_pubdata } => {
+                TestKindReport::Unit { gas: *gas, zk_pubdata: *zk_pubdata }
+            }
             Self::Fuzz { first_case: _, runs, mean_gas, median_gas, failed_corpus_replays } => {
                 TestKindReport::Fuzz {
                     runs: *runs,
@@ -1453,6 +1453,7 @@
     "startup": false,
     "evm_interpreter": false,
     "zksolc": null,
+    "zkvyper": null,
     "solc_path": null,
     "hash_type": null,
     "bytecode_hash": null,
@@ -1464,7 +1465,12 @@
     "optimizer_mode": "3",
     "optimizer_details": null,
     "suppressed_warnings": [],
-    "suppressed_errors": []
+    "suppressed_errors": [],
+    "l1_gas_price": null,
+    "fair_l2_gas_price": null,
+    "fair_pubdata_price": null,
+    "protocol_version": null,
+    "system_contracts_path": null
   }
 }
 
@@ -222,6 +222,52 @@
 "#]]);
 });
 
+forgetest!(enables_zksync_lints_with_zksync_compile, |prj, cmd| {
+    prj.add_source(
+        "BlockNumber",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract BlockNumber {
+    function current() public view returns (uint256) {
+        return block.number;
+    }
+}
+"#,
+    );
+
+    prj.update_config(|config| {
+        config.lint = LinterConfig {
+            severity: vec![LintSeverity::High, LintSeverity::Med],
+            exclude_lints: vec![],
+            ignore: vec![],
+            lint_on_build: true,
+            ..Default::default()
+        };
+    });
+    cmd.forge_fuse().arg("lint").assert_success().stderr_eq(str![[""]]);
+
+    // `zksync` lints run on top of the configured severities when compiling with zksolc
+    prj.update_config(|config| {
+        config.zksync.compile = true;
+    });
+    cmd.forge_fuse().arg("lint").assert_success().stderr_eq(str![[r#"
+warning[zksync-block-number]: 'block.number' is the L2 block number on ZKsync and doesn't advance at the L1 block rate
+  [FILE]:7:16
+  │
+7 │         return block.number;
+  │                ━━━━━━━━━━━━
+  │
+  ╰ help: https://book.getfoundry.sh/reference/forge/forge-lint#zksync-block-number
+
+
+"#]]);
+
+    // but not when the severity is overridden
+    cmd.forge_fuse().args(["lint", "--severity", "high"]).assert_success().stderr_eq(str![[""]]);
+});
+
 forgetest!(can_override_config_path, |prj, cmd| {
     prj.add_source("ContractWithLints", CONTRACT);
     prj.add_source("OtherContractWithLints", OTHER_CONTRACT);
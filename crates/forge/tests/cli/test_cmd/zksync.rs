@@ -72,7 +72,7 @@
     }
     drop(dotenv);
 
-    let args = vec!["test", "--zksync", "--mc", "ZkTraceTest", "-vvvvv"];
+    let args = vec!["test", "--zksync", "--mc", "ZkTraceTest", "-vvvv"];
 
     cmd.args(args).assert_success().stdout_eq(str![[r#"
 ...
@@ -138,6 +138,88 @@
 "#]]);
 });
 
+forgetest!(test_zk_traces_full_call_tree, |_prj, cmd| {
+    let testdata =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../testdata_zk").canonicalize().unwrap();
+    cmd.current_dir(&testdata);
+
+    let mut dotenv = std::fs::File::create(testdata.join(".env")).unwrap();
+    writeln!(dotenv, "ZK_DEBUG_HISTORICAL_BLOCK_HASHES=5").unwrap();
+    for (name, endpoint) in rpc_endpoints_zk().iter() {
+        if let Some(url) = endpoint.endpoint.as_url() {
+            let key = format!("RPC_{}", name.to_uppercase());
+            cmd.env(&key, url);
+            writeln!(dotenv, "{key}={url}").unwrap();
+        }
+    }
+    drop(dotenv);
+
+    let args = vec![
+        "test",
+        "--zksync",
+        "--mc",
+        "ZkTraceTest",
+        "--mt",
+        "testZkTraceOutputDuringCall",
+        "-vvvvv",
+    ];
+
+    // System contract frames, near calls and the pubdata spent per frame are recorded and
+    // decoded at `-vvvvv`, around the contract calls shown at lower verbosities.
+    cmd.args(args).assert_success().stdout_eq(str![[r#"
+...
+Ran 1 test for Trace.t.sol:ZkTraceTest
+[PASS] testZkTraceOutputDuringCall() ([GAS])
+Logs:
+  10
+
+Traces:
+  [..] ZkTraceTest::testZkTraceOutputDuringCall()
+    ├─ [..] → new Adder@0xB5c1DF089600415B21FB76bf89900Adb575947c8
+...
+    │   ├─ [..] ContractDeployer::create([..])
+...
+    │   │   ├─ [..] AccountCodeStorage::[..]
+...
+    │   └─ ← [Return] 2848 bytes of code[..]
+    ├─ [..] Adder::add()
+...
+    │   ├─ [..] Adder::near_call()
+...
+    │   │   ├─ [..] → new Number@0xd6A7A38ee698eFae2F48F3a62dC7a71C3C0930A1
+...
+    │   │   ├─ [..] Number::five()
+...
+    │   │   │   ├─ [..] Number::near_call()
+...
+    │   │   │   │   ├─ [..] → new InnerNumber@0x89c74b24FB24DDa42a8465EE0F9edE2c1308DeEb
+...
+    │   │   │   │   ├─ [..] InnerNumber::innerFive()
+...
+    │   │   │   │   │   └─ ← [Return] 5[..]
+...
+    │   │   │   └─ ← [Return] 5 [pubdata: [..] bytes]
+...
+    │   │   ├─ [..] Number::five()
+...
+    │   │   │   │   ├─ [..] → new InnerNumber@0x9359008843d2c083a14E9C17Cde01893938047FA
+...
+    │   │   │   │   ├─ [..] InnerNumber::innerFive()
+...
+    │   │   │   └─ ← [Return] 5 [pubdata: [..] bytes]
+...
+    │   └─ ← [Return] 10 [pubdata: [..] bytes]
+    ├─ [..] console::log(10) [staticcall]
+    │   └─ ← [Stop]
+    └─ ← [Stop]
+
+Suite result: ok. 1 passed; 0 failed; 0 skipped; [ELAPSED]
+
+Ran 1 test suite [ELAPSED]: 1 tests passed, 0 failed, 0 skipped (1 total tests)
+
+"#]]);
+});
+
 forgetest!(test_zk_repros, |_prj, cmd| {
     let testdata =
         PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../testdata_zk").canonicalize().unwrap();
@@ -525,6 +607,52 @@ fn setup_deploy_prj(prj: &mut TestProject) {
             .expect("value was not a string")
             .contains("0x0");
     });
+
+    forgetest_async!(test_zk_bridge_cheatcodes_query_l2, |prj, cmd| {
+        util::initialize(prj.root());
+        let l2 = foundry_test_utils::MockServer::run();
+        l2.expect("zks_getBridgehubContract", None, serde_json::Value::Null);
+        l2.expect(
+            "eth_getTransactionReceipt",
+            Some(serde_json::json!([format!("0x{}01", "0".repeat(62))])),
+            serde_json::Value::Null,
+        );
+
+        prj.add_test(
+            "BridgeCheatcodes.t.sol",
+            &r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import "forge-std/Test.sol";
+
+contract BridgeCheatcodesTest is Test {
+    function testDepositRequiresBridgehub() public {
+        vm._expectCheatcodeRevert("the L2 node did not return a bridgehub address");
+        vm.zkL1DepositTransaction("<url>", "<url>", address(1), address(2), 1 ether);
+    }
+
+    function testFinalizeWithdrawalRequiresTransaction() public {
+        vm._expectCheatcodeRevert(
+            "withdrawal transaction 0x0000000000000000000000000000000000000000000000000000000000000001 not found"
+        );
+        vm.zkL1FinalizeWithdrawalTransaction("<url>", bytes32(uint256(1)), 0);
+    }
+}
+"#
+            .replace("<url>", &l2.url()),
+        );
+
+        cmd.args(["test", "--zksync", "--use", ZK_MAX_SOLC, "--mc", "BridgeCheatcodesTest"])
+            .assert_success()
+            .stdout_eq(str![[r#"
+...
+Ran 2 tests for test/BridgeCheatcodes.t.sol:BridgeCheatcodesTest
+[PASS] testDepositRequiresBridgehub() ([GAS])
+[PASS] testFinalizeWithdrawalRequiresTransaction() ([GAS])
+...
+"#]]);
+    });
 }
 
 mod constructor {
@@ -867,6 +995,49 @@ fn setup_deploy_prj(prj: &mut TestProject) {
     }
 }
 
+mod expect_halt {
+    use super::*;
+
+    // An ordinary revert carrying the same reason as an EraVM halt must not satisfy
+    // `zkExpectHalt`.
+    forgetest!(test_zk_expect_halt_rejects_plain_revert, |prj, cmd| {
+        util::initialize(prj.root());
+        prj.add_test(
+            "ExpectHalt.t.sol",
+            r#"
+import {Test} from "forge-std/Test.sol";
+
+contract Reverter {
+    function fail() public pure {
+        revert("paymaster validation failed");
+    }
+}
+
+contract ExpectHaltTest is Test {
+    /// forge-config: default.allow_internal_expect_revert = true
+    function testPlainRevertIsNotHalt() public {
+        Reverter reverter = new Reverter();
+        (bool success,) =
+            address(vm).call(abi.encodeWithSignature("zkExpectHalt(string)", "paymaster validation failed"));
+        require(success, "zkExpectHalt call failed");
+
+        reverter.fail();
+    }
+}
+"#,
+        );
+
+        let output = cmd
+            .args(["test", "--zksync", "--use", ZK_MAX_SOLC, "--mc", "ExpectHaltTest"])
+            .assert_failure()
+            .get_output()
+            .stdout_lossy();
+        assert!(output.contains(
+            "call reverted with 'paymaster validation failed', but was expected to halt in the ZK-VM"
+        ));
+    });
+}
+
 mod factory_deps {
     use foundry_zksync_core::MAX_L2_GAS_LIMIT;
 
@@ -1102,6 +1273,73 @@ mod fork {
     });
 }
 
+mod fuzz {
+    use super::*;
+
+    // Calls to zk-compiled handlers must report EraVM edges, so inputs producing new coverage
+    // are persisted as corpus entries.
+    forgetest!(test_zk_invariant_corpus_edge_coverage, |prj, cmd| {
+        util::initialize(prj.root());
+        prj.update_config(|config| {
+            config.invariant.runs = 10;
+            config.invariant.depth = 10;
+            config.invariant.corpus.corpus_dir = Some("invariant_corpus".into());
+        });
+        prj.add_test(
+            "Handler.t.sol",
+            r#"
+import {Test} from "forge-std/Test.sol";
+
+contract Handler {
+    uint256 public count;
+
+    function step(uint256 x) public {
+        if (x % 3 == 0) {
+            count += 1;
+        } else if (x % 3 == 1) {
+            count += 2;
+        }
+    }
+}
+
+contract HandlerTest is Test {
+    Handler handler;
+
+    function setUp() public {
+        handler = new Handler();
+        targetContract(address(handler));
+    }
+
+    function invariant_count() public view {
+        assertLe(handler.count(), 20);
+    }
+}
+"#,
+        );
+
+        cmd.args(["test", "--zksync", "--mt", "invariant_count"]).assert_success();
+
+        // The worker corpus dir is always created, only new EraVM edges produce entries in it.
+        let worker_corpus = prj
+            .root()
+            .join("invariant_corpus")
+            .join("HandlerTest")
+            .join("invariant_count")
+            .join("worker0")
+            .join("corpus");
+        let entries = std::fs::read_dir(worker_corpus)
+            .unwrap()
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                name.ends_with(".json") || name.ends_with(".json.gz")
+            })
+            .count();
+        assert!(entries > 0, "no corpus entries persisted for EraVM edge coverage");
+    });
+}
+
 mod gas {
     use super::*;
 
@@ -1340,15 +1578,42 @@ mod linking {
         }
     );
 
+    forgetest_async!(create_zk_deploy_time_linking, |prj, cmd| {
+        setup_libs_prj(&mut prj, &mut cmd, None);
+
+        let node = ZkSyncNode::start().await;
+        let url = node.url();
+
+        let private_key = ZkSyncNode::rich_wallets()
+            .next()
+            .map(|(_, pk, _)| pk)
+            .expect("No rich wallets available");
+
+        let uses_foo_address = deploy_zk_contract(
+            &mut cmd,
+            url.as_str(),
+            private_key,
+            "./src/WithLibraries.sol:UsesFoo",
+            None,
+        )
+        .expect("Failed to deploy UsesFoo contract");
+
+        assert!(!uses_foo_address.is_empty(), "Deployed address should not be empty");
+    });
+
     forgetest_async!(
-        #[should_panic = "Dynamic linking not supported"]
-        create_zk_using_unlinked_fails,
+        #[ignore]
+        #[should_panic = "deploy-time linking not supported"]
+        create_zk_deploy_time_linking_fails_older_version,
         |prj, cmd| {
-            setup_libs_prj(&mut prj, &mut cmd, None);
+            let mut version = ZKSOLC_MIN_LINKING_VERSION;
+            version.patch -= 1;
+
+            setup_libs_prj(&mut prj, &mut cmd, Some(version));
 
             // we don't really connect to the rpc because
             // we expect to fail before that point
-            let foo_address = deploy_zk_contract(
+            deploy_zk_contract(
                 &mut cmd,
                 "127.0.0.1:1234",
                 "0x0000000000000000000000000000000000000000000000000000000000000000",
@@ -1356,8 +1621,6 @@ mod linking {
                 None,
             )
             .expect("Failed to deploy UsesFoo contract");
-
-            assert!(!foo_address.is_empty(), "Deployed address should not be empty");
         }
     );
 
@@ -1443,6 +1706,45 @@ fn setup_deploy_prj(prj: &mut TestProject) {
     }
 }
 
+mod account_abstraction {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_zk_execute_as_custom_account() {
+        let (prj, mut cmd) = util::setup_forge(
+            "test_zk_execute_as_custom_account",
+            foundry_test_utils::foundry_compilers::PathStyle::Dapptools,
+        );
+        util::initialize(prj.root());
+
+        cmd.args(["install", "cyfrin/zksync-contracts", "--shallow"]).assert_success();
+        cmd.forge_fuse();
+
+        let config = cmd.config();
+        prj.write_config(config);
+
+        prj.add_source(
+            "CustomAccount.t.sol",
+            include_str!("../../fixtures/zk/CustomAccount.t.sol"),
+        );
+        prj.add_source(
+            "LargeContracts.sol",
+            include_str!("../../../../../testdata_zk/LargeContracts.sol"),
+        );
+
+        cmd.args([
+            "test",
+            "--zk-startup",
+            "--zk-enable-eravm-extensions",
+            "--use",
+            ZK_MAX_SOLC,
+            "--match-contract",
+            "TestCustomAccount",
+        ]);
+        assert!(cmd.assert_success().get_output().stdout_lossy().contains("Suite result: ok"));
+    }
+}
+
 mod paymaster {
     use super::*;
 
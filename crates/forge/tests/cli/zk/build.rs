@@ -1,4 +1,4 @@
-use foundry_test_utils::util::OutputExt;
+use foundry_test_utils::util::{OutputExt, get_vyper};
 use regex::Regex;
 
 // tests build output is as expected in zksync mode
@@ -15,8 +15,72 @@
     ]);
     let stdout = cmd.assert_success().get_output().stdout_lossy();
     let pattern =
-        Regex::new(r"\|\s*Counter\s*\|\s*864\s*\|\s*864\s*\|\s*450,135\s*\|\s*450,135\s*\|")
+        Regex::new(r"\|\s*Counter\s*\|\s*27\s*\|\s*864\s*\|\s*65,509\s*\|\s*0\s*\|\s*0\s*\|")
             .unwrap();
 
     assert!(pattern.is_match(&stdout), "Unexpected size output:\n{stdout}");
 });
+
+const VYPER_COUNTER: &str = r#"
+# pragma version >=0.4.0
+
+number: public(uint256)
+
+@external
+def increment() -> uint256:
+    self.number += 1
+    return self.number
+"#;
+
+// tests Vyper sources are compiled with zkvyper into the zksync artifacts
+forgetest!(test_zk_build_compiles_vyper_sources, |prj, cmd| {
+    prj.add_source("Greeter", "contract Greeter {}");
+    prj.add_raw_source("Counter.vy", VYPER_COUNTER);
+    prj.update_config(|config| config.vyper.path = Some(get_vyper().path));
+
+    cmd.args(["build", "--zksync", "--use", super::ZK_MAX_SOLC]).assert_success();
+
+    let artifact: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(prj.root().join("zkout/Counter.vy/Counter.json")).unwrap(),
+    )
+    .unwrap();
+    assert!(artifact["hash"].is_string(), "missing EraVM bytecode hash: {artifact}");
+});
+
+// tests Vyper contracts are deployed and called in the zkVM, with their zkvyper bytecode
+forgetest_async!(test_zk_can_deploy_and_call_vyper_contract, |prj, cmd| {
+    foundry_test_utils::util::initialize(prj.root());
+    prj.add_raw_source("Counter.vy", VYPER_COUNTER);
+    prj.add_test(
+        "VyperCounter.t.sol",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import "forge-std/Test.sol";
+
+interface ICounter {
+    function number() external view returns (uint256);
+    function increment() external returns (uint256);
+}
+
+contract VyperCounterTest is Test {
+    function testDeployAndCallVyperContract() public {
+        ICounter counter = ICounter(deployCode("Counter.vy"));
+        assertEq(counter.increment(), 1);
+        assertEq(counter.increment(), 2);
+        assertEq(counter.number(), 2);
+    }
+}
+"#,
+    );
+    prj.update_config(|config| config.vyper.path = Some(get_vyper().path));
+
+    cmd.args(["test", "--zksync", "--use", super::ZK_MAX_SOLC, "--mc", "VyperCounterTest"])
+        .assert_success()
+        .stdout_eq(str![[r#"
+...
+[PASS] testDeployAndCallVyperContract() ([GAS])
+...
+"#]]);
+});
@@ -47,34 +47,99 @@
         gas_reports_ignore: (vec![]),
         ..Default::default()
     });
-    let out = cmd.arg("test").arg("--gas-report").assert_success().get_output().stdout_lossy();
+    let out =
+        cmd.args(["test", "--gas-report", "--json"]).assert_success().get_output().stdout_lossy();
     cmd.forge_fuse();
     let out_zk = cmd
-        .arg("test")
-        .arg("--gas-report")
-        .arg("--zksync")
-        .args(["--use", super::ZK_MAX_SOLC])
+        .args(["test", "--gas-report", "--json", "--zksync", "--use", super::ZK_MAX_SOLC])
         .assert_success()
         .get_output()
         .stdout_lossy();
 
-    let mut cells = out.split('|');
-    let deployment_cost: u64 = cells.nth(17).unwrap().trim().parse().unwrap();
-    let deployment_size: u64 = cells.next().unwrap().trim().parse().unwrap();
-    let function = cells.nth(25).unwrap().trim();
-    let gas: u64 = cells.next().unwrap().trim().parse().unwrap();
+    let report: serde_json::Value = serde_json::from_str(&out).unwrap();
+    let report_zk: serde_json::Value = serde_json::from_str(&out_zk).unwrap();
+    let (contract, contract_zk) = (&report[0], &report_zk[0]);
+    assert_eq!(contract["contract"], "src/Contracts.sol:ContractOne");
+    assert_eq!(contract_zk["contract"], "src/Contracts.sol:ContractOne");
 
-    let mut cells_zk = out_zk.split('|');
-    let deployment_cost_zk: u64 = cells_zk.nth(17).unwrap().trim().parse().unwrap();
-    let deployment_size_zk: u64 = cells_zk.next().unwrap().trim().parse().unwrap();
-    let function_zk = cells_zk.nth(25).unwrap().trim();
-    let gas_zk: u64 = cells_zk.next().unwrap().trim().parse().unwrap();
+    let deployment_cost = contract["deployment"]["gas"].as_u64().unwrap();
+    let deployment_size = contract["deployment"]["size"].as_u64().unwrap();
+    let gas = contract["functions"]["foo()"]["mean"].as_u64().unwrap();
+    let deployment_cost_zk = contract_zk["deployment"]["gas"].as_u64().unwrap();
+    let deployment_size_zk = contract_zk["deployment"]["size"].as_u64().unwrap();
+    let gas_zk = contract_zk["functions"]["foo()"]["mean"].as_u64().unwrap();
 
     assert!(deployment_cost_zk > deployment_cost);
     assert!(deployment_size_zk > deployment_size);
     assert!(gas_zk > gas);
-    assert_eq!(function, "foo");
-    assert_eq!(function_zk, "foo");
+
+    // Pubdata is only reported for contracts executed in EraVM, and is priced at the default
+    // pubdata price.
+    assert!(contract["deployment"].get("pubdata").is_none());
+    assert!(contract["functions"]["foo()"].get("pubdata").is_none());
+    let deployment_pubdata = contract_zk["deployment"]["pubdata"].as_u64().unwrap();
+    assert!(deployment_pubdata > 0);
+    assert_eq!(contract_zk["deployment"]["l1_cost"].as_u64().unwrap(), deployment_pubdata * 1000);
+    let pubdata = contract_zk["functions"]["foo()"]["pubdata"].as_u64().unwrap();
+    assert!(pubdata > 0);
+    assert_eq!(contract_zk["functions"]["foo()"]["l1_cost"].as_u64().unwrap(), pubdata * 1000);
+
+    // The table shows the pubdata columns as well.
+    cmd.forge_fuse();
+    let table_zk = cmd
+        .args(["test", "--gas-report", "--zksync", "--use", super::ZK_MAX_SOLC])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    assert!(table_zk.contains("Deployment Pubdata"), "{table_zk}");
+    assert!(table_zk.contains("L1 Cost"), "{table_zk}");
+});
+
+forgetest_async!(test_zk_gas_snapshot_records_pubdata_in_separate_group, |prj, cmd| {
+    foundry_test_utils::util::initialize(prj.root());
+    prj.add_test(
+        "GasSnapshot.t.sol",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import "forge-std/Test.sol";
+
+contract Store {
+    uint256 public value;
+
+    function set(uint256 _value) public {
+        value = _value;
+    }
+}
+
+contract GasSnapshotTest is Test {
+    function testSnapshotGas() public {
+        Store store = new Store();
+        vm.startSnapshotGas("Store", "set");
+        store.set(42);
+        vm.stopSnapshotGas();
+    }
+}
+"#,
+    );
+
+    cmd.args(["test", "--zksync", "--use", super::ZK_MAX_SOLC, "--mc", "GasSnapshotTest"])
+        .assert_success();
+
+    // The gas snapshots of the group only hold the gas used.
+    let read_group = |group: &str| -> serde_json::Value {
+        let path = prj.root().join("snapshots").join(format!("{group}.json"));
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    };
+    let gas = read_group("Store");
+    assert_eq!(gas.as_object().unwrap().keys().collect::<Vec<_>>(), ["set"]);
+
+    let pubdata = read_group("Store.zkPubdata");
+    let bytes: u64 = pubdata["set.bytes"].as_str().unwrap().parse().unwrap();
+    let l1_cost: u64 = pubdata["set.l1Cost"].as_str().unwrap().parse().unwrap();
+    assert!(bytes > 0);
+    assert_eq!(l1_cost, bytes * 1000);
 });
 
 forgetest_init!(test_zk_can_init_with_zksync, |prj, cmd| {
@@ -212,3 +277,325 @@
     // Assert that forge-zksync-std is installed
     assert!(prj.root().join("lib/forge-zksync-std").exists());
 });
+
+forgetest_async!(test_zk_can_select_protocol_version, |prj, cmd| {
+    foundry_test_utils::util::initialize(prj.root());
+    prj.add_test(
+        "ProtocolVersion.t.sol",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import "forge-std/Test.sol";
+
+contract SystemContractsProbe {
+    function codeHashes() external view returns (bytes32 hash) {
+        for (uint160 addr = 0x8002; addr <= 0x800f; addr++) {
+            hash = keccak256(abi.encode(hash, address(addr).codehash));
+        }
+    }
+}
+
+contract ProtocolVersionTest is Test {
+    function testSystemContractsCodeHashes() external {
+        SystemContractsProbe probe = new SystemContractsProbe();
+        console.logBytes32(probe.codeHashes());
+    }
+}
+"#,
+    );
+
+    let mut code_hashes = |args: &[&str]| {
+        let output = cmd
+            .forge_fuse()
+            .args(["test", "--zksync", "--use", super::ZK_MAX_SOLC, "--mc", "ProtocolVersionTest"])
+            .args(["-vv"])
+            .args(args)
+            .assert_success()
+            .get_output()
+            .stdout_lossy();
+        output
+            .lines()
+            .skip_while(|line| line.trim() != "Logs:")
+            .nth(1)
+            .map(|line| line.trim().to_string())
+            .expect("code hashes not logged")
+    };
+
+    // The system contracts of the selected protocol version are deployed in the zkVM.
+    let v27 = code_hashes(&["--zk-protocol-version", "27"]);
+    assert_eq!(v27, code_hashes(&["--zk-protocol-version", "27"]));
+    assert_ne!(v27, code_hashes(&[]));
+
+    cmd.forge_fuse()
+        .args(["test", "--zksync", "--use", super::ZK_MAX_SOLC, "--mc", "ProtocolVersionTest"])
+        .args(["--zk-protocol-version", "1"]);
+    let output = cmd.assert_failure().get_output().stderr_lossy();
+    assert!(output.contains("invalid `zksync.protocol_version`"));
+    assert!(output.contains("unsupported protocol version 1"));
+});
+
+forgetest_async!(test_zk_invalid_system_contracts_path_fails, |prj, cmd| {
+    foundry_test_utils::util::initialize(prj.root());
+
+    cmd.args(["test", "--zksync", "--use", super::ZK_MAX_SOLC])
+        .args(["--zk-system-contracts-path", "missing-system-contracts"]);
+    let output = cmd.assert_failure().get_output().stderr_lossy();
+    assert!(output.contains("invalid `zksync.system_contracts_path`"));
+    assert!(output.contains("missing-system-contracts does not exist"));
+});
+
+forgetest_async!(test_zk_zero_pubdata_price_fails, |prj, cmd| {
+    foundry_test_utils::util::initialize(prj.root());
+    prj.update_config(|config| config.zksync.fair_pubdata_price = Some(0));
+
+    cmd.args(["test", "--zksync", "--use", super::ZK_MAX_SOLC]);
+    let output = cmd.assert_failure().get_output().stderr_lossy();
+    assert!(output.contains("invalid `zksync.fair_pubdata_price`: pubdata price must not be zero"));
+});
+
+forgetest_async!(test_zk_differential_reports_divergences, |prj, cmd| {
+    foundry_test_utils::util::initialize(prj.root());
+    prj.add_test(
+        "Differential.t.sol",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import "forge-std/Test.sol";
+
+contract Empty {}
+
+contract DifferentialTest is Test {
+    function testSameOnBothVms() public pure {
+        assertEq(uint256(1) + 1, 2);
+    }
+
+    // EraVM derives CREATE addresses differently than the EVM
+    function testCreateAddressDerivation() public {
+        address expected = vm.computeCreateAddress(address(this), vm.getNonce(address(this)));
+        assertEq(address(new Empty()), expected);
+    }
+
+    function testCreatedAddress() public returns (address) {
+        return address(new Empty());
+    }
+}
+"#,
+    );
+
+    cmd.args([
+        "test",
+        "--zk-differential",
+        "--use",
+        super::ZK_MAX_SOLC,
+        "--mc",
+        "DifferentialTest",
+    ]);
+    let output = cmd.assert_failure().get_output().stderr_lossy();
+    assert!(output.contains("testCreateAddressDerivation() diverges between the EVM and EraVM"));
+    assert!(output.contains("status: passed on the EVM, failed on EraVM"));
+    assert!(output.contains("testCreatedAddress() diverges between the EVM and EraVM"));
+    assert!(output.contains("- return data: 0x"));
+    assert!(output.contains("2 tests diverged between the EVM and EraVM"));
+    assert!(!output.contains("testSameOnBothVms()"));
+});
+
+forgetest_async!(test_zk_coverage, |prj, cmd| {
+    foundry_test_utils::util::initialize(prj.root());
+    prj.add_source(
+        "EvenCheck.sol",
+        r#"
+pragma solidity ^0.8.0;
+
+contract EvenCheck {
+    function check(uint256 x) external pure returns (bool) {
+        if (x % 2 == 0) {
+            return true;
+        }
+        return false;
+    }
+}
+"#,
+    );
+    prj.add_source(
+        "InlinedParity.sol",
+        r#"
+pragma solidity ^0.8.0;
+
+library InlinedParity {
+    function isOdd(uint256 x) internal pure returns (bool) {
+        if (x % 2 == 1) {
+            return true;
+        }
+        return false;
+    }
+}
+"#,
+    );
+    // Internal library code is inlined into the test contract, which is executed in the EVM,
+    // while `EvenCheck` is deployed and executed in EraVM.
+    prj.add_test(
+        "Parity.t.sol",
+        r#"
+pragma solidity ^0.8.0;
+
+import {EvenCheck} from "../src/EvenCheck.sol";
+import {InlinedParity} from "../src/InlinedParity.sol";
+
+contract ParityTest {
+    function testParity() public {
+        require(new EvenCheck().check(2));
+        require(InlinedParity.isOdd(3));
+    }
+}
+"#,
+    );
+
+    let output = cmd
+        .args(["coverage", "--zksync", "--use", super::ZK_MAX_SOLC])
+        .args(["--report=lcov", "--report=summary"])
+        .assert_success()
+        .get_output()
+        .clone();
+    assert!(output.stdout_lossy().contains("| src/Counter.sol"));
+    assert!(
+        output
+            .stderr_lossy()
+            .contains("branch coverage is not supported for contracts executed in EraVM")
+    );
+
+    // Counter is only deployed in EraVM, so its hits come from the EraVM bytecode.
+    let lcov = std::fs::read_to_string(prj.root().join("lcov.info")).unwrap();
+    let counter = lcov
+        .split("end_of_record")
+        .find(|record| record.contains("SF:src/Counter.sol"))
+        .expect("no coverage for src/Counter.sol");
+    for function in ["Counter.setNumber", "Counter.increment"] {
+        let hits = counter
+            .lines()
+            .find_map(|line| line.strip_prefix("FNDA:")?.strip_suffix(&format!(",{function}")))
+            .unwrap_or_else(|| panic!("no coverage for {function}"));
+        assert_ne!(hits, "0", "{function} not covered");
+    }
+    assert!(counter.contains("\nLH:4\n"), "{counter}");
+
+    // Branches are only reported for the sources executed in the EVM.
+    let record = |path: &str| {
+        lcov.split("end_of_record")
+            .find(|record| record.contains(&format!("SF:{path}")))
+            .unwrap_or_else(|| panic!("no coverage for {path}"))
+    };
+    let even_check = record("src/EvenCheck.sol");
+    assert!(even_check.contains("\nBRF:0\n"), "{even_check}");
+    let inlined = record("src/InlinedParity.sol");
+    assert!(inlined.contains("\nBRF:2\n"), "{inlined}");
+});
+
+forgetest_async!(test_zk_debug_dump_records_eravm_steps, |prj, cmd| {
+    foundry_test_utils::util::initialize(prj.root());
+    let dump_path = prj.root().join("dump.json");
+
+    cmd.args(["test", "--zksync", "--use", super::ZK_MAX_SOLC, "--mt", "test_Increment"])
+        .args(["--debug", "--dump", dump_path.to_str().unwrap()])
+        .assert_success();
+
+    let dump: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(dump_path).unwrap()).unwrap();
+    let zk_nodes = dump["debug_arena"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|node| !node["zk_steps"].as_array().unwrap().is_empty())
+        .collect::<Vec<_>>();
+    assert!(!zk_nodes.is_empty(), "no EraVM steps recorded");
+
+    for node in zk_nodes {
+        let steps = node["zk_steps"].as_array().unwrap();
+        assert!(steps.iter().any(|step| step["opcode"] == "ret.ok"), "{node}");
+        // Solidity stores the free memory pointer on the heap before anything else.
+        assert!(steps.iter().any(|step| step["heap"] != "0x"), "{node}");
+    }
+});
+
+/// Serves the verification info of a `Counter` contract with the given EraVM bytecode to every
+/// request, as a ZKsync explorer would, and returns the URL of the server.
+fn serve_zk_verification_info(bytecode: std::sync::Arc<std::sync::Mutex<Vec<u8>>>) -> String {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let _ = stream.read(&mut [0; 4096]);
+            let body = serde_json::json!({
+                "request": {
+                    "id": 1,
+                    "contractAddress": "0x0000000000000000000000000000000000000001",
+                    "codeFormat": "solidity-standard-json-input",
+                    "sourceCode": {
+                        "language": "Solidity",
+                        "sources": {
+                            "src/Counter.sol": {
+                                "content": "pragma solidity ^0.8.0;\ncontract Counter { uint256 public number; }\n"
+                            }
+                        },
+                        "settings": { "optimizer": { "enabled": true, "mode": "3" } }
+                    },
+                    "contractName": "src/Counter.sol:Counter",
+                    "compilerZksolcVersion": "v1.5.15",
+                    "compilerSolcVersion": format!("zkVM-{}-1.0.2", super::ZK_MAX_SOLC),
+                    "optimizationUsed": true,
+                    "optimizerMode": "3",
+                    "constructorArguments": "0x",
+                    "isSystem": false,
+                    "forceEvmla": false
+                },
+                "artifacts": { "bytecode": bytecode.lock().unwrap().clone(), "abi": [] }
+            })
+            .to_string();
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+        }
+    });
+    url
+}
+
+forgetest_async!(test_zk_clone_checks_bytecode_hash, |prj, cmd| {
+    prj.wipe();
+    let bytecode = std::sync::Arc::new(std::sync::Mutex::new(vec![0; 32]));
+    let url = serve_zk_verification_info(bytecode.clone());
+    let address = "0x0000000000000000000000000000000000000001";
+
+    // The verified bytecode doesn't match the zksolc build of the sources.
+    let mismatch = prj.root().join("mismatch");
+    cmd.args(["clone", "--zksync-explorer-url", &url, address])
+        .arg(&mismatch)
+        .assert_failure()
+        .stderr_eq(str![[r#"
+...
+Error: bytecode hash mismatch for Counter: local build [..], on-chain [..]
+
+"#]]);
+
+    // Serving the bytecode of that build makes the check pass.
+    let artifact: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(mismatch.join("zkout/Counter.sol/Counter.json")).unwrap(),
+    )
+    .unwrap();
+    *bytecode.lock().unwrap() =
+        alloy_primitives::hex::decode(artifact["bytecode"]["object"].as_str().unwrap()).unwrap();
+
+    cmd.forge_fuse()
+        .args(["clone", "--zksync-explorer-url", &url, address])
+        .arg(prj.root().join("match"))
+        .assert_success()
+        .stdout_eq(str![[r#"
+...
+The zksolc build of Counter matches the on-chain bytecode hash [..]
+
+"#]]);
+});
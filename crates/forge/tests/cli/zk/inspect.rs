@@ -1,4 +1,4 @@
-use crate::foundry_test_utils::util::OutputExt;
+use crate::foundry_test_utils::util::{OutputExt, TestCommand};
 use foundry_config::Config;
 use foundry_test_utils::forgetest;
 
@@ -84,3 +84,93 @@
         .assert_failure()
         .stderr_eq("Error: ZKsync version of inspect does not support this field\n");
 });
+
+forgetest!(test_zk_inspect_eravm_fields, |prj, cmd| {
+    prj.add_source(
+        "Factory.sol",
+        r#"
+//SPDX-license-identifier: MIT
+
+pragma solidity ^0.8.20;
+
+contract Child {
+    uint256 public value;
+}
+
+contract Middle {
+    function create() public returns (address) {
+        return address(new Child());
+    }
+}
+
+contract Factory {
+    function create() public returns (address) {
+        return address(new Middle());
+    }
+}
+    "#,
+    );
+
+    let hash = inspect_zk(&mut cmd, "zk-bytecode-hash");
+    let hash = hash.lines().last().expect("inspect returns output");
+    assert!(hash.starts_with("0x01"), "unexpected bytecode hash: {hash}");
+    assert_eq!(hash.len(), 66);
+
+    let assembly = inspect_zk(&mut cmd, "eravm-assembly");
+    assert!(assembly.contains(".text"), "unexpected assembly: {assembly}");
+
+    let size = inspect_zk(&mut cmd, "zk-size");
+    assert!(size.contains("EraVM limit is 65536 words"), "unexpected size: {size}");
+
+    // Both the direct and the nested factory dependency are listed
+    let deps = inspect_zk(&mut cmd, "factory-deps");
+    assert!(deps.contains("└── Middle"), "unexpected factory deps: {deps}");
+    assert!(deps.contains("    └── Child"), "unexpected factory deps: {deps}");
+    assert!(deps.contains("3 bytecodes"), "unexpected factory deps: {deps}");
+
+    // The fields are only available with zksolc
+    cmd.args(["inspect", "Factory", "zk-size"]).assert_failure().stderr_eq(
+        "Error: `zk-size` is only available when compiling with zksolc, pass `--zksync`\n",
+    );
+});
+
+forgetest!(test_zk_inspect_storage_layout, |prj, cmd| {
+    prj.add_source(
+        "Counter.sol",
+        r#"
+//SPDX-license-identifier: MIT
+
+pragma solidity ^0.8.20;
+
+contract Counter {
+    uint256 public immutable start;
+    uint256 public number;
+    address public owner;
+
+    constructor(uint256 _start) {
+        start = _start;
+    }
+}
+    "#,
+    );
+
+    let output = cmd
+        .args(["inspect", "Counter", "storageLayout", "--zksync", "--use", super::ZK_MAX_SOLC])
+        .assert_success();
+    let layout = output.get_output().stdout_lossy();
+    assert!(layout.contains("| number"), "unexpected storage layout: {layout}");
+    assert!(layout.contains("| owner"), "unexpected storage layout: {layout}");
+    assert!(!layout.contains("| start"), "unexpected storage layout: {layout}");
+    let note = output.get_output().stderr_lossy();
+    assert!(note.contains("ImmutableSimulator"), "unexpected note: {note}");
+});
+
+fn inspect_zk(cmd: &mut TestCommand, field: &str) -> String {
+    let out = cmd
+        .args(["inspect", "Factory", field, "--zksync", "--use", super::ZK_MAX_SOLC])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    cmd.forge_fuse();
+    out
+}
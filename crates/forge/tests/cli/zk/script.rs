@@ -109,3 +109,56 @@ struct ZkTransactionInner {
     let transactions = transactions.transactions;
     assert_eq!(transactions.len(), 3);
 });
+
+forgetest_async!(test_zk_script_simulation_reports_fee_breakdown, |prj, cmd| {
+    let node = foundry_test_utils::ZkSyncNode::start().await;
+
+    cmd.args(["init", "--force"]).arg(prj.root());
+    cmd.assert_success();
+    cmd.forge_fuse();
+
+    prj.add_script(
+        "Deploy.s.sol",
+        r#"
+pragma solidity ^0.8.18;
+
+import {Script} from "forge-std/Script.sol";
+
+contract Counter {
+    uint256 public number;
+
+    function setNumber(uint256 _number) public {
+        number = _number;
+    }
+}
+
+contract DeployScript is Script {
+    function run() external {
+        vm.startBroadcast();
+        Counter counter = new Counter();
+        counter.setNumber(42);
+        vm.stopBroadcast();
+    }
+}
+   "#,
+    );
+
+    let output = cmd
+        .arg("script")
+        .args([
+            "--zksync",
+            "--use",
+            super::ZK_MAX_SOLC,
+            "DeployScript",
+            "--private-key",
+            "0x3d3cbc973389cb26f657686445bcc75662b415b656078503592ac8c1abb8810e",
+            "--rpc-url",
+            node.url().as_str(),
+        ])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+
+    assert!(output.contains("Estimated pubdata published by script:"), "{output}");
+    assert!(output.contains("Estimated ZKsync fee of 2 transactions:"), "{output}");
+});
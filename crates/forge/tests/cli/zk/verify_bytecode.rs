@@ -185,3 +185,57 @@
         assert!(output.contains("Runtime code matched with status partial"));
     }
 );
+
+forgetest_async!(zk_verify_bytecode_from_bundle, |prj, cmd| {
+    let config = Config {
+        evm_version: EvmVersion::London,
+        solc: Some(SolcReq::Version(Version::new(0, 8, 28))),
+        zksync: foundry_config::zksync::ZkSyncConfig {
+            hash_type: Some(BytecodeHash::Keccak256),
+            zksolc: Some(SolcReq::Version(Version::new(1, 5, 11))),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    prj.add_source("Counter.sol", COUNTER_SOURCE);
+
+    prj.write_config(config);
+
+    let output = cmd
+        .forge_fuse()
+        .args(["verify-contract", COUNTER_ADDRESS, "src/Counter.sol:Counter", "--zksync"])
+        .args(["--export", "bundle"])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    assert!(output.contains("Exported verification bundle for [src/Counter.sol:Counter]"));
+    assert!(prj.root().join("bundle/bundle.json").exists());
+    assert!(prj.root().join("bundle/standard_json_input.json").exists());
+
+    let bytecode = cmd
+        .forge_fuse()
+        .args(["inspect", "Counter", "deployedbytecode", "--zksync"])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let bytecode = bytecode.lines().last().expect("inspect returns output");
+    std::fs::write(prj.root().join("code.hex"), bytecode).unwrap();
+
+    // Sources are no longer needed, everything is recompiled from the bundle.
+    std::fs::remove_dir_all(prj.root().join("src")).unwrap();
+
+    let args = vec![
+        "verify-bytecode",
+        COUNTER_ADDRESS,
+        "Counter",
+        "--zksync",
+        "--from-bundle",
+        "bundle",
+        "--bytecode-file",
+        "code.hex",
+    ];
+
+    let output = cmd.forge_fuse().args(args).assert_success().get_output().stdout_lossy();
+    assert!(output.contains("Runtime code matched with status full"));
+});
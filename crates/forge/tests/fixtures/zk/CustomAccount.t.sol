@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.13;
+
+import {Test} from "forge-std/Test.sol";
+import {
+    IAccount,
+    ACCOUNT_VALIDATION_SUCCESS_MAGIC
+} from "zksync-contracts/zksync-contracts/l2/system-contracts/interfaces/IAccount.sol";
+import {
+    TransactionHelper,
+    Transaction
+} from "zksync-contracts/zksync-contracts/l2/system-contracts/libraries/TransactionHelper.sol";
+import {SystemContractsCaller} from
+    "zksync-contracts/zksync-contracts/l2/system-contracts/libraries/SystemContractsCaller.sol";
+import "zksync-contracts/zksync-contracts/l2/system-contracts/Constants.sol";
+import {LargeContract} from "./LargeContracts.sol";
+
+/// An account that accepts transactions signed with a fixed secret.
+contract SecretAccount is IAccount {
+    using TransactionHelper for Transaction;
+
+    bytes32 private immutable secretHash;
+
+    modifier onlyBootloader() {
+        require(msg.sender == BOOTLOADER_FORMAL_ADDRESS, "Only bootloader can call this method");
+        _;
+    }
+
+    constructor(bytes32 _secretHash) {
+        secretHash = _secretHash;
+    }
+
+    function validateTransaction(bytes32, bytes32, Transaction calldata _transaction)
+        external
+        payable
+        onlyBootloader
+        returns (bytes4 magic)
+    {
+        SystemContractsCaller.systemCallWithPropagatedRevert(
+            uint32(gasleft()),
+            address(NONCE_HOLDER_SYSTEM_CONTRACT),
+            0,
+            abi.encodeCall(INonceHolder.incrementMinNonceIfEquals, (_transaction.nonce))
+        );
+
+        if (keccak256(_transaction.signature) == secretHash) {
+            magic = ACCOUNT_VALIDATION_SUCCESS_MAGIC;
+        }
+    }
+
+    function executeTransaction(bytes32, bytes32, Transaction calldata _transaction)
+        external
+        payable
+        onlyBootloader
+    {
+        address to = address(uint160(_transaction.to));
+        if (to == address(DEPLOYER_SYSTEM_CONTRACT)) {
+            SystemContractsCaller.systemCallWithPropagatedRevert(
+                uint32(gasleft()), to, uint128(_transaction.value), _transaction.data
+            );
+            return;
+        }
+
+        (bool success, bytes memory data) = to.call{value: _transaction.value}(_transaction.data);
+        if (!success) {
+            assembly {
+                revert(add(data, 0x20), mload(data))
+            }
+        }
+    }
+
+    function executeTransactionFromOutside(Transaction calldata) external payable {
+        revert("not supported");
+    }
+
+    function payForTransaction(bytes32, bytes32, Transaction calldata _transaction)
+        external
+        payable
+        onlyBootloader
+    {
+        bool success = _transaction.payToTheBootloader();
+        require(success, "Failed to pay the fee to the operator");
+    }
+
+    function prepareForPaymaster(bytes32, bytes32, Transaction calldata _transaction)
+        external
+        payable
+        onlyBootloader
+    {
+        _transaction.processPaymasterInput();
+    }
+
+    receive() external payable {}
+}
+
+contract Greeter {
+    address public lastSender;
+
+    function greet() external returns (string memory) {
+        lastSender = msg.sender;
+        return "hello";
+    }
+}
+
+contract TestCustomAccount is Test {
+    bytes constant SECRET = "open sesame";
+
+    SecretAccount private account;
+    Greeter private greeter;
+
+    function setUp() public {
+        account = new SecretAccount(keccak256(SECRET));
+        greeter = new Greeter();
+        vm.deal(address(account), 1 ether);
+    }
+
+    function executeAsAccount(bytes memory signature) internal {
+        (bool success,) = address(vm).call(
+            abi.encodeWithSignature("zkExecuteAsAccount(address,bytes)", address(account), signature)
+        );
+        require(success, "zkExecuteAsAccount call failed");
+    }
+
+    function testExecutesAsAccount() public {
+        executeAsAccount(SECRET);
+        greeter.greet();
+
+        assertEq(greeter.lastSender(), address(account));
+    }
+
+    function testDeploysWithBatchedFactoryDepsAsAccount() public {
+        (, bytes memory data) = address(vm).call(
+            abi.encodeWithSignature("zkGetTransactionNonce(address)", address(account))
+        );
+        uint64 txNonce = abi.decode(data, (uint64));
+
+        executeAsAccount(SECRET);
+        new LargeContract();
+
+        // LargeContract's factory dependencies are deployed in 3 transactions,
+        // each validated by the account
+        (, data) = address(vm).call(
+            abi.encodeWithSignature("zkGetTransactionNonce(address)", address(account))
+        );
+        assertEq(abi.decode(data, (uint64)), txNonce + 3);
+    }
+
+    /// forge-config: default.allow_internal_expect_revert = true
+    function testHaltsOnInvalidSignature() public {
+        executeAsAccount("wrong secret");
+        (bool success,) =
+            address(vm).call(abi.encodeWithSignature("zkExpectHalt(string)", "validation failed"));
+        require(success, "zkExpectHalt call failed");
+        greeter.greet();
+    }
+}
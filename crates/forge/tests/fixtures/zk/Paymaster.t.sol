@@ -93,6 +93,24 @@ contract TestPaymasterFlow is Test {
         vm.expectRevert();
         do_stuff.do_stuff(bob);
     }
+
+    /// forge-config: default.allow_internal_expect_revert = true
+    function testHaltsWhenPaymasterCannotPay() public {
+        MyPaymaster unfunded_paymaster = new MyPaymaster();
+        vm.deal(address(do_stuff), 1 ether);
+
+        (bool success,) = address(vm).call(
+            abi.encodeWithSignature(
+                "zkUsePaymaster(address,bytes)", address(unfunded_paymaster), paymaster_encoded_input
+            )
+        );
+        require(success, "zkUsePaymaster call failed");
+        (success,) = address(vm).call(abi.encodeWithSignature("zkExpectHalt(string)", "paymaster validation failed"));
+        require(success, "zkExpectHalt call failed");
+
+        vm.prank(alice, alice);
+        do_stuff.do_stuff(bob);
+    }
 }
 
 contract DoStuff {
@@ -15,6 +15,7 @@
         CompactDeployedBytecode, Libraries,
     },
     contracts::ArtifactContracts,
+    error::SolcError,
 };
 use foundry_zksync_compilers::{
     compilers::{
@@ -37,10 +38,25 @@ pub enum ZkLinkerError {
     MissingLibraries(BTreeSet<MissingLibrary>),
     #[error("unable to fully link due to unlinked factory dependencies")]
     MissingFactoryDeps(BTreeSet<MissingLibrary>),
+    #[error(
+        "deploy-time linking not supported. minimum: {}, given: {0}",
+        DEPLOY_TIME_LINKING_ZKSOLC_MIN_VERSION
+    )]
+    UnsupportedVersion(Version),
+    #[error("failed linking with zksolc: {0}")]
+    Zksolc(#[from] SolcError),
 }
 
 pub const DEPLOY_TIME_LINKING_ZKSOLC_MIN_VERSION: Version = Version::new(1, 5, 9);
 
+/// Returns an error if the given zksolc version doesn't support deploy-time linking.
+pub fn ensure_deploy_time_linking_supported(version: &Version) -> Result<(), ZkLinkerError> {
+    if version < &DEPLOY_TIME_LINKING_ZKSOLC_MIN_VERSION {
+        return Err(ZkLinkerError::UnsupportedVersion(version.clone()));
+    }
+    Ok(())
+}
+
 #[derive(Debug)]
 pub struct ZkLinker<'a> {
     pub linker: Linker<'a>,
@@ -340,8 +356,7 @@ pub fn zk_link(
             .collect::<HashSet<_>>();
 
         let mut link_output =
-            zk_link::zksolc_link(zksolc_path, zk_link::LinkJsonInput { bytecodes, libraries })
-                .expect("able to call zksolc --link"); // TODO(zk): proper error check
+            zk_link::zksolc_link(zksolc_path, zk_link::LinkJsonInput { bytecodes, libraries })?;
 
         let link_id = &artifact_to_link_id(target);
 
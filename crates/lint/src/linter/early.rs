@@ -40,6 +40,7 @@ fn check_item(&mut self, _ctx: &LintContext, _item: &'ast ast::Item<'ast>) {}
     fn check_stmt(&mut self, _ctx: &LintContext, _stmt: &'ast ast::Stmt<'ast>) {}
     fn check_path(&mut self, _ctx: &LintContext, _path: &'ast ast::PathSlice) {}
     fn check_ty(&mut self, _ctx: &LintContext, _ty: &'ast ast::Type<'ast>) {}
+    fn check_yul_expr(&mut self, _ctx: &LintContext, _expr: &'ast ast::yul::Expr<'ast>) {}
 
     /// Should be called after the source unit has been visited. Enables lints that require
     /// knowledge of the entire AST to perform their analysis.
@@ -201,4 +202,14 @@ fn visit_ty(&mut self, ty: &'ast ast::Type<'ast>) -> ControlFlow<Self::BreakValu
         }
         self.walk_ty(ty)
     }
+
+    fn visit_yul_expr(
+        &mut self,
+        expr: &'ast ast::yul::Expr<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        for pass in self.passes.iter_mut() {
+            pass.check_yul_expr(self.ctx, expr)
+        }
+        self.walk_yul_expr(expr)
+    }
 }
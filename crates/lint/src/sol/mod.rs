@@ -38,6 +38,7 @@
 pub mod high;
 pub mod info;
 pub mod med;
+pub mod zksync;
 
 static ALL_REGISTERED_LINTS: LazyLock<Vec<&'static str>> = LazyLock::new(|| {
     let mut lints = Vec::new();
@@ -46,6 +47,7 @@
     lints.extend_from_slice(info::REGISTERED_LINTS);
     lints.extend_from_slice(gas::REGISTERED_LINTS);
     lints.extend_from_slice(codesize::REGISTERED_LINTS);
+    lints.extend_from_slice(zksync::REGISTERED_LINTS);
     lints.into_iter().map(|lint| lint.id()).collect()
 });
 
@@ -129,10 +131,11 @@ fn process_source_ast<'gcx>(
         passes_and_lints.extend(med::create_early_lint_passes());
         passes_and_lints.extend(info::create_early_lint_passes());
 
-        // Do not apply 'gas' and 'codesize' severity rules on tests and scripts
+        // Do not apply 'gas', 'codesize' and 'zksync' severity rules on tests and scripts
         if !self.path_config.is_test_or_script(path) {
             passes_and_lints.extend(gas::create_early_lint_passes());
             passes_and_lints.extend(codesize::create_early_lint_passes());
+            passes_and_lints.extend(zksync::create_early_lint_passes());
         }
 
         // Filter passes based on linter config
@@ -180,10 +183,11 @@ fn process_source_hir<'gcx>(
         passes_and_lints.extend(med::create_late_lint_passes());
         passes_and_lints.extend(info::create_late_lint_passes());
 
-        // Do not apply 'gas' and 'codesize' severity rules on tests and scripts
+        // Do not apply 'gas', 'codesize' and 'zksync' severity rules on tests and scripts
         if !self.path_config.is_test_or_script(path) {
             passes_and_lints.extend(gas::create_late_lint_passes());
             passes_and_lints.extend(codesize::create_late_lint_passes());
+            passes_and_lints.extend(zksync::create_late_lint_passes());
         }
 
         // Filter passes based on config
@@ -417,6 +421,12 @@ fn try_from(value: &'a str) -> Result<Self, Self::Error> {
             }
         }
 
+        for &lint in zksync::REGISTERED_LINTS {
+            if lint.id() == value {
+                return Ok(lint);
+            }
+        }
+
         Err(SolLintError::InvalidId(value.to_string()))
     }
 }
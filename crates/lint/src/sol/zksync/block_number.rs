@@ -0,0 +1,36 @@
+use super::ZksyncBlockNumber;
+use crate::{
+    linter::{EarlyLintPass, LintContext},
+    sol::{Severity, SolLint},
+};
+use solar::ast::{Expr, ExprKind, yul};
+
+declare_forge_lint!(
+    ZKSYNC_BLOCK_NUMBER,
+    Severity::Zksync,
+    "zksync-block-number",
+    "'block.number' is the L2 block number on ZKsync and doesn't advance at the L1 block rate"
+);
+
+/// Flags `block.number` and the `number` builtin in assembly. Logic measuring time in blocks
+/// should use `block.timestamp` instead, as L2 blocks are produced at a different rate.
+impl<'ast> EarlyLintPass<'ast> for ZksyncBlockNumber {
+    fn check_expr(&mut self, ctx: &LintContext, expr: &'ast Expr<'ast>) {
+        if let ExprKind::Member(base, member) = &expr.kind
+            && member.as_str() == "number"
+            && let ExprKind::Ident(ident) = &base.kind
+            && ident.as_str() == "block"
+        {
+            ctx.emit(&ZKSYNC_BLOCK_NUMBER, expr.span);
+        }
+    }
+
+    fn check_yul_expr(&mut self, ctx: &LintContext, expr: &'ast yul::Expr<'ast>) {
+        if let yul::ExprKind::Call(yul::ExprCall { name, arguments }) = &expr.kind
+            && name.as_str() == "number"
+            && arguments.is_empty()
+        {
+            ctx.emit(&ZKSYNC_BLOCK_NUMBER, expr.span);
+        }
+    }
+}
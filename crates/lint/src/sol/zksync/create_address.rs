@@ -0,0 +1,57 @@
+use super::ZksyncCreateAddress;
+use crate::{
+    linter::{EarlyLintPass, LintContext},
+    sol::{Severity, SolLint},
+};
+use solar::ast::{Expr, ExprKind, LitKind, StrKind};
+
+declare_forge_lint!(
+    ZKSYNC_CREATE_ADDRESS,
+    Severity::Zksync,
+    "zksync-create-address",
+    "CREATE and CREATE2 addresses are derived differently on ZKsync"
+);
+
+/// Flags `keccak256(abi.encodePacked(prefix, ...))` where `prefix` is the `0xff` byte of the
+/// CREATE2 address derivation or an RLP list prefix (`0xd6` to `0xd9`) of the CREATE one.
+///
+/// ZKsync uses its own prefix for both derivations, and the bytecode hash instead of the init code
+/// hash for CREATE2, so such hardcoded computations don't match the deployed addresses.
+impl<'ast> EarlyLintPass<'ast> for ZksyncCreateAddress {
+    fn check_expr(&mut self, ctx: &LintContext, expr: &'ast Expr<'ast>) {
+        if let ExprKind::Call(callee, args) = &expr.kind
+            && let ExprKind::Ident(ident) = &callee.kind
+            && ident.as_str() == "keccak256"
+            && args.len() == 1
+            && let Some(arg) = args.exprs().next()
+            && let ExprKind::Call(encode, packed_args) = &arg.kind
+            && let ExprKind::Member(base, member) = &encode.kind
+            && let ExprKind::Ident(abi) = &base.kind
+            && abi.as_str() == "abi"
+            && member.as_str() == "encodePacked"
+            && packed_args.exprs().next().and_then(prefix_byte).is_some_and(is_address_prefix)
+        {
+            ctx.emit(&ZKSYNC_CREATE_ADDRESS, expr.span);
+        }
+    }
+}
+
+/// Returns the value of a single byte literal, unwrapping casts such as `bytes1(0xff)`.
+fn prefix_byte(expr: &Expr<'_>) -> Option<u8> {
+    match &expr.kind {
+        ExprKind::Call(_, args) if args.len() == 1 => args.exprs().next().and_then(prefix_byte),
+        ExprKind::Lit(lit, _) => match &lit.kind {
+            LitKind::Number(n) => u8::try_from(*n).ok(),
+            LitKind::Str(StrKind::Hex, sym, _) => match sym.as_byte_str() {
+                [byte] => Some(*byte),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_address_prefix(byte: u8) -> bool {
+    matches!(byte, 0xff | 0xd6..=0xd9)
+}
@@ -0,0 +1,35 @@
+use super::ZksyncExtcodesize;
+use crate::{
+    linter::{EarlyLintPass, LintContext},
+    sol::{Severity, SolLint},
+};
+use solar::ast::{Expr, ExprKind, yul};
+
+declare_forge_lint!(
+    ZKSYNC_EXTCODESIZE,
+    Severity::Zksync,
+    "zksync-extcodesize",
+    "code size checks can't tell EOAs apart on ZKsync, where accounts can have code"
+);
+
+/// Flags `account.code.length` and the `extcodesize` builtin in assembly, commonly used to check
+/// whether an address is an EOA. With native account abstraction, accounts on ZKsync are contracts.
+impl<'ast> EarlyLintPass<'ast> for ZksyncExtcodesize {
+    fn check_expr(&mut self, ctx: &LintContext, expr: &'ast Expr<'ast>) {
+        if let ExprKind::Member(base, member) = &expr.kind
+            && member.as_str() == "length"
+            && let ExprKind::Member(_, code) = &base.kind
+            && code.as_str() == "code"
+        {
+            ctx.emit(&ZKSYNC_EXTCODESIZE, expr.span);
+        }
+    }
+
+    fn check_yul_expr(&mut self, ctx: &LintContext, expr: &'ast yul::Expr<'ast>) {
+        if let yul::ExprKind::Call(yul::ExprCall { name, .. }) = &expr.kind
+            && name.as_str() == "extcodesize"
+        {
+            ctx.emit(&ZKSYNC_EXTCODESIZE, expr.span);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::sol::{EarlyLintPass, LateLintPass, SolLint};
+
+mod block_number;
+use block_number::ZKSYNC_BLOCK_NUMBER;
+
+mod create_address;
+use create_address::ZKSYNC_CREATE_ADDRESS;
+
+mod extcodesize;
+use extcodesize::ZKSYNC_EXTCODESIZE;
+
+mod send_transfer;
+use send_transfer::ZKSYNC_SEND_TRANSFER;
+
+mod unsupported_opcodes;
+use unsupported_opcodes::ZKSYNC_UNSUPPORTED_OPCODE;
+
+register_lints!(
+    (ZksyncSendTransfer, early, (ZKSYNC_SEND_TRANSFER)),
+    (ZksyncExtcodesize, early, (ZKSYNC_EXTCODESIZE)),
+    (ZksyncCreateAddress, early, (ZKSYNC_CREATE_ADDRESS)),
+    (ZksyncBlockNumber, early, (ZKSYNC_BLOCK_NUMBER)),
+    (ZksyncUnsupportedOpcode, early, (ZKSYNC_UNSUPPORTED_OPCODE))
+);
@@ -0,0 +1,30 @@
+use super::ZksyncSendTransfer;
+use crate::{
+    linter::{EarlyLintPass, LintContext},
+    sol::{Severity, SolLint},
+};
+use solar::ast::{Expr, ExprKind};
+
+declare_forge_lint!(
+    ZKSYNC_SEND_TRANSFER,
+    Severity::Zksync,
+    "zksync-send-transfer",
+    "'transfer' and 'send' forward a fixed gas stipend which may not be enough on ZKsync"
+);
+
+/// Flags `address.transfer(amount)` and `address.send(amount)`, which zksolc only reports as a
+/// warning even though the 2300 gas stipend is not enough for most value transfers on ZKsync.
+///
+/// WARN: can issue false positives, as the type of the receiver is not checked, so a user defined
+/// `transfer` or `send` function with a single parameter is flagged as well.
+impl<'ast> EarlyLintPass<'ast> for ZksyncSendTransfer {
+    fn check_expr(&mut self, ctx: &LintContext, expr: &'ast Expr<'ast>) {
+        if let ExprKind::Call(callee, args) = &expr.kind
+            && let ExprKind::Member(_, member) = &callee.kind
+            && matches!(member.as_str(), "transfer" | "send")
+            && args.len() == 1
+        {
+            ctx.emit(&ZKSYNC_SEND_TRANSFER, expr.span);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use super::ZksyncUnsupportedOpcode;
+use crate::{
+    linter::{EarlyLintPass, LintContext},
+    sol::{Severity, SolLint},
+};
+use solar::ast::{Expr, ExprKind, yul};
+
+declare_forge_lint!(
+    ZKSYNC_UNSUPPORTED_OPCODE,
+    Severity::Zksync,
+    "zksync-unsupported-opcode",
+    "opcode is not supported by zksolc"
+);
+
+/// Yul builtins that zksolc refuses to compile.
+const UNSUPPORTED_OPCODES: [&str; 3] = ["selfdestruct", "callcode", "pc"];
+
+impl<'ast> EarlyLintPass<'ast> for ZksyncUnsupportedOpcode {
+    fn check_expr(&mut self, ctx: &LintContext, expr: &'ast Expr<'ast>) {
+        // `selfdestruct(recipient)` is compiled to the `SELFDESTRUCT` opcode.
+        if let ExprKind::Call(callee, _args) = &expr.kind
+            && let ExprKind::Ident(ident) = &callee.kind
+            && ident.as_str() == "selfdestruct"
+        {
+            ctx.emit(&ZKSYNC_UNSUPPORTED_OPCODE, expr.span);
+        }
+    }
+
+    fn check_yul_expr(&mut self, ctx: &LintContext, expr: &'ast yul::Expr<'ast>) {
+        if let yul::ExprKind::Call(yul::ExprCall { name, .. }) = &expr.kind
+            && UNSUPPORTED_OPCODES.contains(&name.as_str())
+        {
+            ctx.emit(&ZKSYNC_UNSUPPORTED_OPCODE, expr.span);
+        }
+    }
+}
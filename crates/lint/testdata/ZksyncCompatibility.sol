@@ -0,0 +1,90 @@
+//@compile-flags: --severity zksync
+
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.18;
+
+interface IToken {
+    function transfer(address to, uint256 amount) external returns (bool);
+}
+
+contract ZksyncCompatibility {
+    uint256 public deadline;
+
+    // SHOULD PASS: Calls with value and ERC20 transfers
+    function sendWithCall(address payable to, uint256 amount) public {
+        (bool success,) = to.call{value: amount}("");
+        require(success);
+    }
+
+    function transferToken(IToken token, address to, uint256 amount) public {
+        require(token.transfer(to, amount));
+    }
+
+    // SHOULD FAIL: Fixed gas stipend value transfers
+    function sendWithTransfer(address payable to, uint256 amount) public {
+        to.transfer(amount); //~WARN: 'transfer' and 'send' forward a fixed gas stipend which may not be enough on ZKsync
+    }
+
+    function sendWithSend(address payable to, uint256 amount) public returns (bool) {
+        return to.send(amount); //~WARN: 'transfer' and 'send' forward a fixed gas stipend which may not be enough on ZKsync
+    }
+
+    // SHOULD FAIL: EOA detection through the code size
+    function isContract(address account) public view returns (bool) {
+        return account.code.length > 0; //~WARN: code size checks can't tell EOAs apart on ZKsync, where accounts can have code
+    }
+
+    function isContractAsm(address account) public view returns (bool result) {
+        assembly {
+            result := gt(extcodesize(account), 0) //~WARN: code size checks can't tell EOAs apart on ZKsync, where accounts can have code
+        }
+    }
+
+    // SHOULD FAIL: Hardcoded address derivations
+    function computeCreate2(address deployer, bytes32 salt, bytes32 initCodeHash) public pure returns (address) {
+        return address(
+            uint160(uint256(keccak256(abi.encodePacked(bytes1(0xff), deployer, salt, initCodeHash)))) //~WARN: CREATE and CREATE2 addresses are derived differently on ZKsync
+        );
+    }
+
+    function computeCreate(address deployer) public pure returns (address) {
+        return address(uint160(uint256(keccak256(abi.encodePacked(hex"d6", hex"94", deployer, hex"01"))))); //~WARN: CREATE and CREATE2 addresses are derived differently on ZKsync
+    }
+
+    function computeCreateNonce(address deployer) public pure returns (address) {
+        return address(
+            uint160(uint256(keccak256(abi.encodePacked(bytes1(0xd6), bytes1(0x94), deployer, bytes1(0x01))))) //~WARN: CREATE and CREATE2 addresses are derived differently on ZKsync
+        );
+    }
+
+    function hashPacked(address account) public pure returns (bytes32) {
+        return keccak256(abi.encodePacked(account, uint8(1)));
+    }
+
+    // SHOULD PASS: Time based logic
+    function isExpired() public view returns (bool) {
+        return block.timestamp > deadline;
+    }
+
+    // SHOULD FAIL: Block number reliance
+    function isExpiredByBlock() public view returns (bool) {
+        return block.number > deadline; //~WARN: 'block.number' is the L2 block number on ZKsync and doesn't advance at the L1 block rate
+    }
+
+    function blockNumberAsm() public view returns (uint256 result) {
+        assembly {
+            result := number() //~WARN: 'block.number' is the L2 block number on ZKsync and doesn't advance at the L1 block rate
+        }
+    }
+
+    // SHOULD FAIL: Opcodes zksolc doesn't support
+    function destroy(address payable to) public {
+        selfdestruct(to); //~WARN: opcode is not supported by zksolc
+    }
+
+    function destroyAsm(address to) public {
+        assembly {
+            selfdestruct(to) //~WARN: opcode is not supported by zksolc
+        }
+    }
+}
@@ -32,6 +32,30 @@ pub struct TransactionWithMetadata {
     pub transaction: TransactionMaybeSigned,
     pub additional_contracts: Vec<AdditionalContract>,
     pub is_fixed_gas_limit: bool,
+    /// The fee of the transaction estimated by the simulation, if it was executed in EraVM.
+    #[serde(skip)]
+    pub zk_fee: Option<ZkFeeEstimate>,
+}
+
+/// Fee of a transaction executed in EraVM, as estimated by the local simulation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkFeeEstimate {
+    /// The gas limit of the transaction, including the gas spent on publishing pubdata.
+    pub gas_limit: u128,
+    /// The gas charged per published pubdata byte.
+    pub gas_per_pubdata: u64,
+    /// The number of pubdata bytes published.
+    pub pubdata_bytes: u64,
+    /// The price of a unit of gas charged by the batch, in wei.
+    pub gas_price: u64,
+}
+
+impl ZkFeeEstimate {
+    /// Returns the cost of the transaction if its whole gas limit is spent, in wei.
+    pub fn cost(&self) -> u128 {
+        self.gas_limit.saturating_mul(self.gas_price as u128)
+    }
 }
 
 fn default_string() -> Option<String> {
@@ -59,6 +83,7 @@ pub fn from_tx_request(transaction: TransactionMaybeSigned) -> Self {
             is_fixed_gas_limit: Default::default(),
             additional_contracts: Default::default(),
             rpc: Default::default(),
+            zk_fee: Default::default(),
         }
     }
 
@@ -1,19 +1,25 @@
 use std::{cmp::Ordering, sync::Arc, time::Duration};
 
 use alloy_chains::{Chain, NamedChain};
-use alloy_consensus::TxEnvelope;
+use alloy_consensus::{SignableTransaction, TxEnvelope};
+use alloy_dyn_abi::TypedData;
 use alloy_eips::{BlockId, eip2718::Encodable2718};
-use alloy_network::{AnyNetwork, EthereumWallet, TransactionBuilder};
+use alloy_network::{AnyNetwork, EthereumWallet, NetworkWallet, TransactionBuilder};
 use alloy_primitives::{
-    Address, Bytes, TxHash,
+    Address, Bytes, TxHash, U256,
     map::{AddressHashMap, AddressHashSet},
     utils::format_units,
 };
 use alloy_provider::{Provider, utils::Eip1559Estimation};
 use alloy_rpc_types::TransactionRequest;
 use alloy_serde::WithOtherFields;
+use alloy_signer::Signer;
 use alloy_zksync::network::{
-    Zksync, transaction_request::TransactionRequest as ZkTransactionRequest, tx_type::TxType,
+    Zksync,
+    transaction_request::TransactionRequest as ZkTransactionRequest,
+    tx_envelope::TxEnvelope as ZkTxEnvelope,
+    tx_type::TxType,
+    unsigned_tx::{TypedTransaction as ZkTypedTransaction, eip712::TxEip712},
 };
 use eyre::{Context, Result, bail};
 use forge_verify::provider::VerificationProviderType;
@@ -31,7 +37,7 @@
 };
 use foundry_config::Config;
 use foundry_wallets::{WalletSigner, wallet_browser::signer::BrowserSigner};
-use foundry_zksync_core::convert::ConvertH160;
+use foundry_zksync_core::{ZkTransactionMetadata, convert::ConvertH160};
 use futures::{FutureExt, StreamExt, future::join_all, stream::FuturesUnordered};
 use itertools::Itertools;
 
@@ -93,7 +99,8 @@ pub async fn prepare(
         estimate_via_rpc: bool,
         estimate_multiplier: u64,
     ) -> Result<()> {
-        let zk_tx_meta = if let Self::Raw(tx, _) | Self::Unlocked(tx) = self {
+        let zk_tx_meta = if let Self::Raw(tx, _) | Self::Unlocked(tx) | Self::Browser(tx, _) = self
+        {
             foundry_strategy_zksync::try_get_zksync_transaction_metadata(&tx.other)
         } else {
             None
@@ -147,6 +154,9 @@ pub async fn prepare(
     /// - Submit via `eth_sendTransaction` for unlocked accounts
     /// - Sign and submit via `eth_sendRawTransaction` for raw transactions
     /// - Submit pre-signed transaction via `eth_sendRawTransaction`
+    ///
+    /// ZKsync transactions are submitted as EIP-712 transactions, signed through the browser
+    /// wallet with `eth_signTypedData_v4` for browser signers.
     pub async fn send(
         self,
         provider: Arc<RetryProvider>,
@@ -158,9 +168,27 @@ pub async fn send(
             Self::Unlocked(tx) => {
                 debug!("sending transaction from unlocked account {:?}", tx);
 
-                // Submit the transaction
-                let pending = provider.send_transaction(tx).await?;
-                Ok(*pending.tx_hash())
+                let zk_tx_meta =
+                    foundry_strategy_zksync::try_get_zksync_transaction_metadata(&tx.other);
+
+                if let Some(zk_tx_meta) = zk_tx_meta {
+                    let zk_tx = build_zk_transaction(
+                        &tx,
+                        &zk_tx_meta,
+                        &zk_provider,
+                        estimate_multiplier,
+                        &zk_tx_opts,
+                    )
+                    .await?;
+
+                    // Submit the EIP-712 transaction, the node signs it for the unlocked account
+                    let pending = zk_provider.send_transaction(zk_tx).await?;
+                    Ok(*pending.tx_hash())
+                } else {
+                    // Submit the transaction
+                    let pending = provider.send_transaction(tx).await?;
+                    Ok(*pending.tx_hash())
+                }
             }
             Self::Raw(tx, signer) => {
                 debug!("sending transaction: {:?}", tx);
@@ -169,29 +197,12 @@ pub async fn send(
                     foundry_strategy_zksync::try_get_zksync_transaction_metadata(&tx.other);
 
                 if let Some(zk_tx_meta) = zk_tx_meta {
-                    let mut inner = tx.inner.clone();
-                    inner.transaction_type = Some(TxType::Eip712 as u8);
-                    let mut zk_tx: ZkTransactionRequest = inner.into();
-                    if !zk_tx_meta.factory_deps.is_empty() {
-                        zk_tx.set_factory_deps(
-                            zk_tx_meta.factory_deps.iter().map(Bytes::from_iter).collect(),
-                        );
-                    }
-
-                    if let Some(paymaster_data) = &zk_tx_meta.paymaster_data {
-                        zk_tx.set_paymaster_params(
-                            alloy_zksync::network::unsigned_tx::eip712::PaymasterParams {
-                                paymaster: paymaster_data.paymaster.to_address(),
-                                paymaster_input: paymaster_data.paymaster_input.clone().into(),
-                            },
-                        );
-                    }
-
-                    foundry_zksync_core::estimate_fee(
-                        &mut zk_tx,
+                    let zk_tx = build_zk_transaction(
+                        &tx,
+                        &zk_tx_meta,
                         &zk_provider,
                         estimate_multiplier,
-                        zk_tx_opts.gas_per_pubdata,
+                        &zk_tx_opts,
                     )
                     .await?;
 
@@ -218,8 +229,27 @@ pub async fn send(
             Self::Browser(tx, signer) => {
                 debug!("sending transaction: {:?}", tx);
 
-                // Sign and send the transaction via the browser wallet
-                Ok(signer.send_transaction_via_browser(tx.into_inner()).await?)
+                let zk_tx_meta =
+                    foundry_strategy_zksync::try_get_zksync_transaction_metadata(&tx.other);
+
+                if let Some(zk_tx_meta) = zk_tx_meta {
+                    let zk_tx = build_zk_transaction(
+                        &tx,
+                        &zk_tx_meta,
+                        &zk_provider,
+                        estimate_multiplier,
+                        &zk_tx_opts,
+                    )
+                    .await?;
+
+                    // Sign the EIP-712 payload via the browser wallet and submit it ourselves
+                    let signed = zk_tx.build(&ZkBrowserWallet(signer)).await?.encoded_2718();
+                    let pending = zk_provider.send_raw_transaction(signed.as_ref()).await?;
+                    Ok(*pending.tx_hash())
+                } else {
+                    // Sign and send the transaction via the browser wallet
+                    Ok(signer.send_transaction_via_browser(tx.into_inner()).await?)
+                }
             }
         }
     }
@@ -252,6 +282,139 @@ pub async fn prepare_and_send(
     }
 }
 
+/// Builds the EIP-712 ZKsync transaction for `tx`, with its fee estimated.
+async fn build_zk_transaction(
+    tx: &WithOtherFields<TransactionRequest>,
+    zk_tx_meta: &ZkTransactionMetadata,
+    zk_provider: &Arc<RetryProvider<Zksync>>,
+    estimate_multiplier: u64,
+    zk_tx_opts: &ZkTransactionOpts,
+) -> Result<ZkTransactionRequest> {
+    let mut inner = tx.inner.clone();
+    inner.transaction_type = Some(TxType::Eip712 as u8);
+    let mut zk_tx: ZkTransactionRequest = inner.into();
+    if !zk_tx_meta.factory_deps.is_empty() {
+        zk_tx.set_factory_deps(zk_tx_meta.factory_deps.iter().map(Bytes::from_iter).collect());
+    }
+
+    if let Some(paymaster_data) = &zk_tx_meta.paymaster_data {
+        zk_tx.set_paymaster_params(alloy_zksync::network::unsigned_tx::eip712::PaymasterParams {
+            paymaster: paymaster_data.paymaster.to_address(),
+            paymaster_input: paymaster_data.paymaster_input.clone().into(),
+        });
+    }
+
+    foundry_zksync_core::estimate_fee(
+        &mut zk_tx,
+        zk_provider,
+        estimate_multiplier,
+        zk_tx_opts.gas_per_pubdata,
+    )
+    .await?;
+
+    Ok(zk_tx)
+}
+
+/// Signs ZKsync EIP-712 transactions through a browser wallet.
+///
+/// Browser wallets cannot sign raw hashes, so the transaction is presented as typed data and
+/// signed with `eth_signTypedData_v4`.
+struct ZkBrowserWallet<'a>(&'a BrowserSigner);
+
+impl NetworkWallet<Zksync> for ZkBrowserWallet<'_> {
+    fn default_signer_address(&self) -> Address {
+        Signer::address(self.0)
+    }
+
+    fn has_signer_for(&self, address: &Address) -> bool {
+        Signer::address(self.0) == *address
+    }
+
+    fn signer_addresses(&self) -> impl Iterator<Item = Address> {
+        [Signer::address(self.0)].into_iter()
+    }
+
+    async fn sign_transaction_from(
+        &self,
+        _sender: Address,
+        tx: ZkTypedTransaction,
+    ) -> alloy_signer::Result<ZkTxEnvelope> {
+        match tx {
+            ZkTypedTransaction::Native(_) => Err(alloy_signer::Error::other(
+                "browser wallets can only sign ZKsync EIP-712 transactions",
+            )),
+            ZkTypedTransaction::Eip712(tx) => {
+                let typed_data = zk_eip712_typed_data(&tx).map_err(alloy_signer::Error::other)?;
+                let signature = self.0.sign_dynamic_typed_data(&typed_data).await?;
+                Ok(ZkTxEnvelope::Eip712(tx.into_signed(signature)))
+            }
+        }
+    }
+}
+
+/// Returns the typed data signed for a ZKsync EIP-712 transaction.
+///
+/// See <https://docs.zksync.io/zksync-protocol/rollup/transaction-lifecycle#eip-712-0x71>
+fn zk_eip712_typed_data(tx: &TxEip712) -> Result<TypedData> {
+    let to_uint = |address: Address| U256::from_be_slice(address.as_slice()).to_string();
+    let meta = tx.eip712_meta.as_ref();
+    let gas_per_pubdata = meta.map(|meta| meta.gas_per_pubdata).unwrap_or_default();
+    let (paymaster, paymaster_input) = meta
+        .and_then(|meta| meta.paymaster_params.clone())
+        .map(|params| (params.paymaster, params.paymaster_input))
+        .unwrap_or_default();
+    let factory_deps = meta
+        .map(|meta| meta.factory_deps.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .map(|dep| format!("{:#x}", foundry_zksync_core::hash_bytecode(dep)))
+        .collect::<Vec<_>>();
+
+    let typed_data = serde_json::json!({
+        "types": {
+            "EIP712Domain": [
+                { "name": "name", "type": "string" },
+                { "name": "version", "type": "string" },
+                { "name": "chainId", "type": "uint256" },
+            ],
+            "Transaction": [
+                { "name": "txType", "type": "uint256" },
+                { "name": "from", "type": "uint256" },
+                { "name": "to", "type": "uint256" },
+                { "name": "gasLimit", "type": "uint256" },
+                { "name": "gasPerPubdataByteLimit", "type": "uint256" },
+                { "name": "maxFeePerGas", "type": "uint256" },
+                { "name": "maxPriorityFeePerGas", "type": "uint256" },
+                { "name": "paymaster", "type": "uint256" },
+                { "name": "nonce", "type": "uint256" },
+                { "name": "value", "type": "uint256" },
+                { "name": "data", "type": "bytes" },
+                { "name": "factoryDeps", "type": "bytes32[]" },
+                { "name": "paymasterInput", "type": "bytes" },
+            ],
+        },
+        "primaryType": "Transaction",
+        "domain": { "name": "zkSync", "version": "2", "chainId": tx.chain_id },
+        "message": {
+            "txType": (TxType::Eip712 as u8).to_string(),
+            "from": to_uint(tx.from),
+            "to": to_uint(tx.to),
+            "gasLimit": tx.gas_limit.to_string(),
+            "gasPerPubdataByteLimit": gas_per_pubdata.to_string(),
+            "maxFeePerGas": tx.max_fee_per_gas.to_string(),
+            "maxPriorityFeePerGas": tx.max_priority_fee_per_gas.to_string(),
+            "paymaster": to_uint(paymaster),
+            "nonce": tx.nonce.to_string(),
+            "value": tx.value.to_string(),
+            "data": tx.input,
+            "factoryDeps": factory_deps,
+            "paymasterInput": paymaster_input,
+        },
+    });
+
+    Ok(serde_json::from_value(typed_data)?)
+}
+
 /// Convenience enum to represent either an Ethereum wallet or a browser signer
 pub enum EitherSigner {
     Ethereum(EthereumWallet),
@@ -704,3 +867,39 @@ pub fn verify_preflight_check(&self) -> Result<()> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+    use alloy_zksync::network::unsigned_tx::eip712::PaymasterParams;
+
+    #[test]
+    fn zk_eip712_typed_data_matches_signature_hash() {
+        let inner = TransactionRequest::default()
+            .with_from(address!("0x36615Cf349d7F6344891B1e7CA7C72883F5dc049"))
+            .with_to(address!("0xa61464658AfeAf65CccaaFD3a512b69A83B77618"))
+            .with_nonce(7)
+            .with_value(U256::from(1_000))
+            .with_input(Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef]))
+            .with_gas_limit(2_000_000)
+            .with_max_fee_per_gas(50_000_000)
+            .with_max_priority_fee_per_gas(0)
+            .with_chain_id(260)
+            .transaction_type(TxType::Eip712 as u8);
+        let mut zk_tx: ZkTransactionRequest = inner.into();
+        zk_tx.set_gas_per_pubdata(U256::from(50_000));
+        zk_tx.set_factory_deps(vec![Bytes::from(vec![1u8; 32])]);
+        zk_tx.set_paymaster_params(PaymasterParams {
+            paymaster: address!("0x0000000000000000000000000000000000010000"),
+            paymaster_input: Bytes::from_static(&[1, 2, 3]),
+        });
+
+        let ZkTypedTransaction::Eip712(tx) = zk_tx.build_unsigned().unwrap() else {
+            panic!("expected an EIP-712 transaction");
+        };
+        let typed_data = zk_eip712_typed_data(&tx).unwrap();
+
+        assert_eq!(typed_data.eip712_signing_hash().unwrap(), tx.signature_hash());
+    }
+}
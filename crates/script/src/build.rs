@@ -243,12 +243,24 @@ pub fn compile(self) -> Result<CompiledState> {
             let zk_compiler = ProjectCompiler::new().files(sources_to_compile);
 
             zk_output = Some(zk_compiler.zksync_compile(&zk_project)?);
-            Some(DualCompiledContracts::new(
+            let mut dual_compiled_contracts = DualCompiledContracts::new(
                 &output,
                 zk_output.as_ref().unwrap(),
                 &project.paths,
                 &zk_project.paths,
-            ))
+            );
+
+            if let Some(zkvyper_project) = foundry_config::zksync::config_create_zkvyper_project(
+                &script_config.config,
+                script_config.config.cache,
+                false,
+            )? {
+                let zkvyper_output =
+                    ProjectCompiler::new().zksync_compile_vyper(&zkvyper_project)?;
+                dual_compiled_contracts.extend_with_zkvyper(&output, &zkvyper_output);
+            }
+
+            Some(dual_compiled_contracts)
         } else {
             None
         };
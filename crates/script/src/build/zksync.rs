@@ -8,7 +8,7 @@
     contracts::ArtifactContracts,
     info::ContractInfo,
 };
-use foundry_linking::{DEPLOY_TIME_LINKING_ZKSOLC_MIN_VERSION, ZkLinker};
+use foundry_linking::{ZkLinker, ensure_deploy_time_linking_supported};
 use foundry_zksync_compilers::dual_compiled_contracts::DualCompiledContract;
 use foundry_zksync_core::{DEFAULT_CREATE2_DEPLOYER_ZKSYNC, hash_bytecode};
 
@@ -38,12 +38,8 @@ fn get_zk_linker(&self, script_config: &ScriptConfig) -> Result<ZkLinker<'_>> {
 
         // if there are no no libs, no linking will happen
         // so we can skip version check
-        if !libs.is_empty() && version < &DEPLOY_TIME_LINKING_ZKSOLC_MIN_VERSION {
-            eyre::bail!(
-                "deploy-time linking not supported. minimum: {}, given: {}",
-                DEPLOY_TIME_LINKING_ZKSOLC_MIN_VERSION,
-                &version
-            );
+        if !libs.is_empty() {
+            ensure_deploy_time_linking_supported(version)?;
         }
 
         Ok(linker)
@@ -120,7 +116,7 @@ pub(super) async fn zk_link(
         let mut libs = Default::default();
         linker
             .zk_collect_dependencies(target, &mut libs, Some(&mut factory_deps))
-            .expect("able to enumerate all deps");
+            .context("enumerating all dependencies")?;
 
         let linked_contracts = linker
             .zk_get_linked_artifacts(
@@ -640,7 +640,7 @@ async fn _get_runner(
     ) -> Result<ScriptRunner> {
         trace!("preparing script runner");
         let env = self.evm_opts.evm_env().await?;
-        let mut strategy = utils::get_executor_strategy(&self.config);
+        let mut strategy = utils::get_executor_strategy(&self.config)?;
 
         let db = if let Some(fork_url) = self.evm_opts.fork_url.as_ref() {
             match self.backends.get(fork_url) {
@@ -14,7 +14,7 @@
 use alloy_primitives::{Address, TxKind, U256, map::HashMap, utils::format_units};
 use dialoguer::Confirm;
 use eyre::{Context, Result};
-use forge_script_sequence::{ScriptSequence, TransactionWithMetadata};
+use forge_script_sequence::{ScriptSequence, TransactionWithMetadata, ZkFeeEstimate};
 use foundry_cheatcodes::Wallets;
 use foundry_cli::utils::{has_different_gas_calc, now};
 use foundry_common::{ContractData, TransactionMaybeSigned, shell};
@@ -277,6 +277,7 @@ pub async fn bundle(mut self) -> Result<BundledState> {
         }
 
         let mut total_gas_per_rpc: HashMap<String, u128> = HashMap::default();
+        let mut zk_fees_per_rpc: HashMap<String, Vec<ZkFeeEstimate>> = HashMap::default();
 
         // Batches sequence of transactions from different rpcs.
         let mut new_sequence = VecDeque::new();
@@ -296,6 +297,10 @@ pub async fn bundle(mut self) -> Result<BundledState> {
                 tx.set_chain_id(provider_info.chain);
             }
 
+            if let Some(zk_fee) = tx.zk_fee {
+                zk_fees_per_rpc.entry(tx_rpc.clone()).or_default().push(zk_fee);
+            }
+
             if !self.args.skip_simulation {
                 let tx = tx.tx_mut();
 
@@ -380,6 +385,13 @@ pub async fn bundle(mut self) -> Result<BundledState> {
                     .unwrap_or_else(|_| "[Could not calculate]".to_string());
                 let estimated_amount = estimated_amount_raw.trim_end_matches('0');
 
+                let zk_fees = zk_fees_per_rpc.remove(&rpc).unwrap_or_default();
+                let zk_pubdata_bytes = zk_fees.iter().map(|fee| fee.pubdata_bytes).sum::<u64>();
+                let zk_amount_raw =
+                    format_units(zk_fees.iter().map(ZkFeeEstimate::cost).sum::<u128>(), 18)
+                        .unwrap_or_else(|_| "[Could not calculate]".to_string());
+                let zk_amount = zk_amount_raw.trim_end_matches('0');
+
                 if !shell::is_json() {
                     sh_println!("\n==========================")?;
                     sh_println!("\nChain {}", provider_info.chain)?;
@@ -387,18 +399,30 @@ pub async fn bundle(mut self) -> Result<BundledState> {
                     sh_println!("\nEstimated gas price: {} gwei", estimated_gas_price)?;
                     sh_println!("\nEstimated total gas used for script: {total_gas}")?;
                     sh_println!("\nEstimated amount required: {estimated_amount} {token_symbol}")?;
+                    if !zk_fees.is_empty() {
+                        sh_println!(
+                            "\nEstimated pubdata published by script: {zk_pubdata_bytes} bytes"
+                        )?;
+                        sh_println!(
+                            "\nEstimated ZKsync fee of {} transactions: {zk_amount} {token_symbol}",
+                            zk_fees.len()
+                        )?;
+                    }
                     sh_println!("\n==========================")?;
                 } else {
-                    sh_println!(
-                        "{}",
-                        serde_json::json!({
-                            "chain": provider_info.chain,
-                            "estimated_gas_price": estimated_gas_price,
-                            "estimated_total_gas_used": total_gas,
-                            "estimated_amount_required": estimated_amount,
-                            "token_symbol": token_symbol,
-                        })
-                    )?;
+                    let mut summary = serde_json::json!({
+                        "chain": provider_info.chain,
+                        "estimated_gas_price": estimated_gas_price,
+                        "estimated_total_gas_used": total_gas,
+                        "estimated_amount_required": estimated_amount,
+                        "token_symbol": token_symbol,
+                    });
+                    if !zk_fees.is_empty() {
+                        summary["zk_estimated_pubdata_bytes"] = zk_pubdata_bytes.into();
+                        summary["zk_estimated_amount_required"] = zk_amount.into();
+                        summary["zk_transaction_fees"] = serde_json::to_value(&zk_fees)?;
+                    }
+                    sh_println!("{summary}")?;
                 }
             }
         }
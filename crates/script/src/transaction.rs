@@ -3,7 +3,7 @@
 use alloy_dyn_abi::JsonAbiExt;
 use alloy_primitives::{Address, B256, TxKind, hex};
 use eyre::Result;
-use forge_script_sequence::TransactionWithMetadata;
+use forge_script_sequence::{TransactionWithMetadata, ZkFeeEstimate};
 use foundry_common::{ContractData, SELECTOR_LEN, TransactionMaybeSigned, fmt::format_token_raw};
 use foundry_evm::traces::CallTraceDecoder;
 use itertools::Itertools;
@@ -167,6 +167,18 @@ pub fn with_execution_result(
             unsigned.gas = Some(result.gas_used * gas_estimate_multiplier / 100);
         }
 
+        // Transactions executed in EraVM record the pubdata they published on their top call.
+        if let Some(pubdata) =
+            result.traces.iter().find_map(|(_, arena)| arena.zk_pubdata.get(&0).copied())
+        {
+            self.transaction.zk_fee = Some(ZkFeeEstimate {
+                gas_limit: self.transaction.tx().gas().unwrap_or(result.gas_used as u128),
+                gas_per_pubdata: pubdata.gas_per_pubdata,
+                pubdata_bytes: pubdata.bytes,
+                gas_price: pubdata.gas_price,
+            });
+        }
+
         self
     }
 
@@ -6,7 +6,9 @@
 use alloy_primitives::{Address, hex};
 use eyre::{Result, eyre};
 use forge_script_sequence::{AdditionalContract, ScriptSequence};
-use forge_verify::{RetryArgs, VerifierArgs, VerifyArgs, provider::VerificationProviderType};
+use forge_verify::{
+    ContractLanguage, RetryArgs, VerifierArgs, VerifyArgs, provider::VerificationProviderType,
+};
 use foundry_cli::opts::{EtherscanOpts, ProjectPathOpts};
 use foundry_common::ContractsByArtifact;
 use foundry_compilers::{Project, artifacts::EvmVersion, info::ContractInfo};
@@ -54,6 +56,8 @@ pub struct VerifyBundle {
     pub verifier: VerifierArgs,
     pub via_ir: bool,
     pub zksync: bool,
+    /// The zkvyper version Vyper contracts are compiled with in zksync mode.
+    pub zkvyper_version: Option<Version>,
 }
 
 impl VerifyBundle {
@@ -82,6 +86,7 @@ pub fn new(
 
         let via_ir = config.via_ir;
         let zksync = config.zksync.should_compile();
+        let zkvyper_version = if zksync { config.zksync.zkvyper_version().ok() } else { None };
 
         Self {
             num_of_optimizations,
@@ -92,6 +97,7 @@ pub fn new(
             verifier,
             via_ir,
             zksync,
+            zkvyper_version,
         }
     }
 
@@ -169,6 +175,7 @@ pub fn get_verify_args(
                     guess_constructor_args: false,
                     compilation_profile: Some(artifact.profile.to_string()),
                     language: None,
+                    zk_compiler_version: None,
                     zksync: self.zksync,
                     creation_transaction_hash: None,
                 };
@@ -224,9 +231,17 @@ pub fn get_verify_args_zk(
 
             let contract_bytecode_hash = foundry_zksync_core::hash_bytecode(bytecode);
             if bytecode_hash == contract_bytecode_hash {
-                if artifact.source.extension().is_some_and(|e| e.to_str() == Some("vy")) {
-                    warn!("Skipping verification of Vyper contract: {}", artifact.name);
-                }
+                // Vyper contracts are compiled with zkvyper, against the vyper version of the
+                // artifact.
+                let (language, zk_compiler_version) =
+                    if artifact.source.extension().is_some_and(|e| e.to_str() == Some("vy")) {
+                        (
+                            Some(ContractLanguage::Vyper),
+                            self.zkvyper_version.as_ref().map(|version| version.to_string()),
+                        )
+                    } else {
+                        (None, None)
+                    };
 
                 let contract = ContractInfo {
                     path: Some(artifact.source.to_string_lossy().to_string()),
@@ -264,7 +279,8 @@ pub fn get_verify_args_zk(
                     show_standard_json_input: false,
                     guess_constructor_args: false,
                     compilation_profile: None, //TODO(zk): get compilation profile
-                    language: None,            // TODO(zk): vyper is not supported right now
+                    language,
+                    zk_compiler_version,
                     zksync: self.zksync,
                     creation_transaction_hash: None,
                     no_auto_detect: false,
@@ -72,7 +72,9 @@ fn inspect(
         // patch evm context with real caller
         evm_context.tx.caller = env.tx.caller;
 
-        result.map(|(result, call_traces)| {
+        result.map(|(result, call_traces, pubdata)| {
+            // Recorded before the traces, so the pubdata is attributed to the top call.
+            inspector.pubdata_zksync(Box::new(pubdata));
             inspector.trace_zksync(&mut evm_context, Box::new(call_traces), true);
             result
         })
@@ -188,7 +190,7 @@ fn transact_from_tx(
             evm_context.tx.caller = env.tx.caller;
             evm_context.journaled_state.depth = journaled_state.depth + 1;
 
-            result.map(|(result, call_traces)| {
+            result.map(|(result, call_traces, _)| {
                 inspector.trace_zksync(&mut evm_context, Box::new(call_traces), true);
                 result
             })?
@@ -8,7 +8,7 @@
 use foundry_zksync_compilers::dual_compiled_contracts::{
     DualCompiledContract, DualCompiledContracts,
 };
-use foundry_zksync_core::{H256, ZkPaymasterData, vm::ZkEnv};
+use foundry_zksync_core::{H256, ZkAccountData, ZkPaymasterData, vm::ZkEnv};
 use revm::state::Bytecode;
 
 use super::types::ZkStartupMigration;
@@ -32,6 +32,9 @@ pub struct ZksyncCheatcodeInspectorStrategyContext {
     /// Paymaster params
     pub paymaster_params: Option<ZkPaymasterData>,
 
+    /// Custom account that executes the next CALL or CREATE, set through `zkExecuteAsAccount`.
+    pub account_data: Option<ZkAccountData>,
+
     /// Dual compiled contracts
     pub dual_compiled_contracts: DualCompiledContracts,
 
@@ -120,6 +123,7 @@ pub fn new(
             skip_zk_vm_addresses: Default::default(),
             record_next_create_address: Default::default(),
             paymaster_params: Default::default(),
+            account_data: Default::default(),
             dual_compiled_contracts,
             zk_startup_migration: ZkStartupMigration::Defer,
             zk_use_factory_deps: Default::default(),
@@ -1,10 +1,11 @@
 use std::any::TypeId;
 
 use alloy_eips::eip2718::Decodable2718;
+use alloy_network::AnyNetwork;
 use alloy_primitives::U256;
 use alloy_rpc_types::TransactionRequest;
 use alloy_sol_types::SolValue;
-use alloy_zksync::network::tx_envelope::TxEnvelope as ZkTxEnvelope;
+use alloy_zksync::network::{Zksync, tx_envelope::TxEnvelope as ZkTxEnvelope};
 use eyre::Context;
 use foundry_cheatcodes::{
     BroadcastableTransaction, CheatcodesExecutor, CheatsCtxt, DealRecord, DynCheatcode, Error,
@@ -14,19 +15,25 @@
         createSelectFork_0Call, createSelectFork_1Call, createSelectFork_2Call, dealCall, etchCall,
         getCodeCall, getNonce_0Call, mockCall_0Call, mockCallRevert_0Call, resetNonceCall,
         rollCall, selectForkCall, setNonceCall, setNonceUnsafeCall, warpCall,
-        zkGetDeploymentNonceCall, zkGetTransactionNonceCall, zkRegisterContractCall,
-        zkUseFactoryDepCall, zkUsePaymasterCall, zkVmCall, zkVmSkipCall,
+        zkExecuteAsAccountCall, zkExecuteL1TxCall, zkGetDeploymentNonceCall, zkGetImmutableCall,
+        zkGetTransactionNonceCall, zkL1DepositTransactionCall,
+        zkL1FinalizeWithdrawalTransactionCall, zkRegisterContractCall, zkSetFairL2GasPriceCall,
+        zkSetImmutableCall, zkSetL1GasPriceCall, zkSetPubdataPriceCall, zkUseFactoryDepCall,
+        zkUsePaymasterCall, zkVmCall, zkVmSkipCall,
     },
     make_acc_non_empty,
 };
-use foundry_common::TransactionMaybeSigned;
+use foundry_common::{TransactionMaybeSigned, provider::ProviderBuilder};
 use foundry_compilers::info::ContractInfo;
 use foundry_evm::backend::LocalForkId;
 use foundry_evm_core::ContextExt;
 use foundry_zksync_compilers::dual_compiled_contracts::DualCompiledContract;
 use foundry_zksync_core::{
-    H256, PaymasterParams, ZKSYNC_TRANSACTION_OTHER_FIELDS_KEY, ZkPaymasterData,
+    H256, PaymasterParams, ZKSYNC_TRANSACTION_OTHER_FIELDS_KEY, ZkAccountData, ZkPaymasterData,
     ZkTransactionMetadata,
+    bridge::{self, BridgeTransaction, DepositRequest},
+    convert::ConvertRU256,
+    vm::ZkL1TxInputs,
 };
 use revm::interpreter::InstructionResult;
 use tracing::{info, warn};
@@ -134,6 +141,20 @@ fn is<T: std::any::Any>(t: TypeId) -> bool {
                     foundry_zksync_core::cheatcodes::get_full_nonce(account, ccx.ecx);
                 Ok(deploy_nonce.abi_encode())
             }
+            t if is::<zkGetImmutableCall>(t) => {
+                let &zkGetImmutableCall { target, index } =
+                    cheatcode.as_any().downcast_ref().unwrap();
+
+                let value = foundry_zksync_core::cheatcodes::get_immutable(target, index, ccx.ecx);
+                Ok(value.abi_encode())
+            }
+            t if is::<zkSetImmutableCall>(t) => {
+                let &zkSetImmutableCall { target, index, value } =
+                    cheatcode.as_any().downcast_ref().unwrap();
+
+                foundry_zksync_core::cheatcodes::set_immutable(target, index, value, ccx.ecx);
+                Ok(Default::default())
+            }
             t if using_zk_vm && is::<mockCall_0Call>(t) => {
                 let mockCall_0Call { callee, data, returnData } =
                     cheatcode.as_any().downcast_ref().unwrap();
@@ -208,6 +229,64 @@ fn is<T: std::any::Any>(t: TypeId) -> bool {
                 });
                 Ok(Default::default())
             }
+            t if is::<zkExecuteAsAccountCall>(t) => {
+                let zkExecuteAsAccountCall { account, signature } =
+                    cheatcode.as_any().downcast_ref().unwrap();
+                info!(?account, "cheatcode zkExecuteAsAccount");
+
+                let ctx = get_context(ccx.state.strategy.context.as_mut());
+                ctx.account_data =
+                    Some(ZkAccountData { address: *account, signature: signature.clone() });
+                Ok(Default::default())
+            }
+            t if is::<zkExecuteL1TxCall>(t) => {
+                let zkExecuteL1TxCall { l1Sender, to, data, value, l2GasLimit } =
+                    cheatcode.as_any().downcast_ref().unwrap();
+                info!(?l1Sender, ?to, ?value, "cheatcode zkExecuteL1Tx");
+
+                if !using_zk_vm {
+                    return Err(Error::display("`zkExecuteL1Tx` requires the zkVM to be selected"));
+                }
+
+                let inputs = ZkL1TxInputs {
+                    l1_sender: *l1Sender,
+                    to: *to,
+                    data: data.to_vec(),
+                    value: value.to_u256(),
+                    l2_gas_limit: l2GasLimit.to_u256(),
+                };
+                let (success, output) = self.zksync_execute_l1_tx(ccx, executor, inputs)?;
+                Ok((success, output).abi_encode_params())
+            }
+            t if is::<zkSetL1GasPriceCall>(t) => {
+                let &zkSetL1GasPriceCall { gasPrice } = cheatcode.as_any().downcast_ref().unwrap();
+                info!(?gasPrice, "cheatcode zkSetL1GasPrice");
+
+                let ctx = get_context(ccx.state.strategy.context.as_mut());
+                ctx.zk_env.l1_gas_price = gasPrice;
+                Ok(Default::default())
+            }
+            t if is::<zkSetFairL2GasPriceCall>(t) => {
+                let &zkSetFairL2GasPriceCall { gasPrice } =
+                    cheatcode.as_any().downcast_ref().unwrap();
+                info!(?gasPrice, "cheatcode zkSetFairL2GasPrice");
+
+                let ctx = get_context(ccx.state.strategy.context.as_mut());
+                ctx.zk_env.fair_l2_gas_price = gasPrice;
+                Ok(Default::default())
+            }
+            t if is::<zkSetPubdataPriceCall>(t) => {
+                let &zkSetPubdataPriceCall { pubdataPrice } =
+                    cheatcode.as_any().downcast_ref().unwrap();
+                info!(?pubdataPrice, "cheatcode zkSetPubdataPrice");
+
+                if pubdataPrice == 0 {
+                    return Err(Error::display("pubdata price must not be zero"));
+                }
+                let ctx = get_context(ccx.state.strategy.context.as_mut());
+                ctx.zk_env.fair_pubdata_price = pubdataPrice;
+                Ok(Default::default())
+            }
             t if is::<zkUseFactoryDepCall>(t) => {
                 let zkUseFactoryDepCall { name } = cheatcode.as_any().downcast_ref().unwrap();
                 info!("Adding factory dependency: {:?}", name);
@@ -255,6 +334,44 @@ fn is<T: std::any::Any>(t: TypeId) -> bool {
 
                 Ok(Default::default())
             }
+            t if is::<zkL1DepositTransactionCall>(t) => {
+                let zkL1DepositTransactionCall { l1UrlOrAlias, l2UrlOrAlias, from, to, amount } =
+                    cheatcode.as_any().downcast_ref().unwrap();
+                info!(?from, ?to, ?amount, "cheatcode zkL1DepositTransaction");
+
+                let l1_url = ccx.state.config.rpc_endpoint(l1UrlOrAlias)?.url()?;
+                let l2_url = ccx.state.config.rpc_endpoint(l2UrlOrAlias)?.url()?;
+                let l1_provider = ProviderBuilder::<AnyNetwork>::new(&l1_url).build()?;
+                let l2_provider = ProviderBuilder::<Zksync>::new(&l2_url).build()?;
+
+                let request = DepositRequest {
+                    from: *from,
+                    to: *to,
+                    amount: *amount,
+                    l2_gas_limit: None,
+                    refund_recipient: None,
+                };
+                let BridgeTransaction { to, value, data, gas_price } = foundry_common::block_on(
+                    bridge::deposit_transaction(&l1_provider, &l2_provider, &request),
+                )?;
+                Ok((to, value, data, U256::from(gas_price.unwrap_or_default())).abi_encode_params())
+            }
+            t if is::<zkL1FinalizeWithdrawalTransactionCall>(t) => {
+                let &zkL1FinalizeWithdrawalTransactionCall { ref l2UrlOrAlias, txHash, index } =
+                    cheatcode.as_any().downcast_ref().unwrap();
+                info!(?txHash, ?index, "cheatcode zkL1FinalizeWithdrawalTransaction");
+
+                let l2_url = ccx.state.config.rpc_endpoint(l2UrlOrAlias)?.url()?;
+                let l2_provider = ProviderBuilder::<Zksync>::new(&l2_url).build()?;
+
+                let index = usize::try_from(index).map_err(|_| {
+                    Error::display(format!("withdrawal index {index} is too large"))
+                })?;
+                let BridgeTransaction { to, value, data, .. } = foundry_common::block_on(
+                    bridge::finalize_withdrawal_transaction(&l2_provider, txHash, index),
+                )?;
+                Ok((to, value, data).abi_encode_params())
+            }
             t if is::<selectForkCall>(t) => {
                 let selectForkCall { forkId } = cheatcode.as_any().downcast_ref().unwrap();
                 let ctx = get_context(ccx.state.strategy.context.as_mut());
@@ -6,9 +6,10 @@
     request::{TransactionInput, TransactionRequest},
     serde_helpers::WithOtherFields,
 };
+use alloy_sol_types::SolError;
 use foundry_cheatcodes::{
     Broadcast, BroadcastableTransaction, BroadcastableTransactions, Cheatcodes, CheatcodesExecutor,
-    CheatsConfig, CheatsCtxt, CommonCreateInput, DynCheatcode, Result,
+    CheatsConfig, CheatsCtxt, CommonCreateInput, DynCheatcode, Error, Result,
     Vm::{self, AccountAccess, AccountAccessKind, ChainInfo, StorageAccess},
     journaled_account,
     strategy::{
@@ -19,8 +20,9 @@
 use foundry_common::TransactionMaybeSigned;
 use foundry_evm::{
     Env,
-    backend::{DatabaseError, LocalForkId},
+    backend::LocalForkId,
     constants::{DEFAULT_CREATE2_DEPLOYER, DEFAULT_CREATE2_DEPLOYER_CODE},
+    coverage::{HitMap, HitMaps},
 };
 use foundry_evm_core::{ContextExt, Ecx, backend::DatabaseExt};
 use foundry_zksync_core::{
@@ -30,6 +32,7 @@
     convert::{ConvertAddress, ConvertH160, ConvertH256, ConvertRU256, ConvertU256},
     get_account_code_key, get_balance_key, get_nonce_key,
     state::parse_full_nonce,
+    vm::{ZkCallTraces, ZkHalt, ZkL1TxInputs},
 };
 use itertools::Itertools;
 use revm::{
@@ -151,6 +154,153 @@ fn append_recorded_accesses(
             }
         }
     }
+
+    /// Appends the EraVM coverage to the executor's line coverage collector.
+    fn append_coverage(
+        &self,
+        state: &mut Cheatcodes,
+        executor: &mut dyn CheatcodesExecutor,
+        coverage: impl IntoIterator<Item = (H256, foundry_zksync_core::vm::ZkBytecodeCoverage)>,
+    ) {
+        let hit_maps = HitMaps(
+            coverage
+                .into_iter()
+                .map(|(hash, coverage)| {
+                    let mut hit_map = HitMap::new(coverage.bytecode.into());
+                    for (pc, hits) in coverage.hits {
+                        hit_map.hits(pc, hits);
+                    }
+                    (hash.to_b256(), hit_map)
+                })
+                .collect(),
+        );
+        if !hit_maps.is_empty() {
+            executor.get_inspector(state).coverage_zksync(Box::new(hit_maps));
+        }
+    }
+
+    /// Appends the branches taken in the zkEVM to the executor's edge coverage.
+    fn append_edge_coverage(
+        &self,
+        state: &mut Cheatcodes,
+        executor: &mut dyn CheatcodesExecutor,
+        edge_coverage: std::collections::HashMap<u64, u32>,
+    ) {
+        if !edge_coverage.is_empty() {
+            executor.get_inspector(state).edge_coverage_zksync(Box::new(edge_coverage));
+        }
+    }
+
+    /// Records the pubdata published by a zkEVM call in the cheatcodes state, the active gas
+    /// snapshots and the executor's tracer.
+    ///
+    /// Must be called before the call traces are appended, so the pubdata is attributed to the
+    /// intercepted call.
+    fn record_pubdata(
+        &self,
+        state: &mut Cheatcodes,
+        executor: &mut dyn CheatcodesExecutor,
+        pubdata: foundry_zksync_core::vm::ZkPubdataUsage,
+    ) {
+        state.zk_pubdata.get_or_insert_default().merge(pubdata);
+        for record in &mut state.gas_metering.gas_records {
+            record.zk_pubdata.merge(pubdata);
+        }
+        executor.get_inspector(state).pubdata_zksync(Box::new(pubdata));
+    }
+
+    /// Appends the executed EraVM instructions to the executor's tracer, to be stepped through in
+    /// the debugger.
+    fn append_steps(
+        &self,
+        state: &mut Cheatcodes,
+        executor: &mut dyn CheatcodesExecutor,
+        steps: Vec<foundry_zksync_core::vm::ZkVmStep>,
+    ) {
+        if !steps.is_empty() {
+            executor.get_inspector(state).steps_zksync(Box::new(steps));
+        }
+    }
+
+    /// Executes a priority transaction in the zkEVM, returning whether it succeeded along with its
+    /// return or revert data.
+    ///
+    /// Console logs, traces and emitted events are handled as for a regular call.
+    fn zksync_execute_l1_tx(
+        &self,
+        ccx: &mut CheatsCtxt<'_, '_, '_, '_>,
+        executor: &mut dyn CheatcodesExecutor,
+        inputs: ZkL1TxInputs,
+    ) -> Result<(bool, Bytes)> {
+        let record_coverage = executor.get_inspector(ccx.state).zksync_record_coverage();
+        let record_edge_coverage = executor.get_inspector(ccx.state).zksync_record_edge_coverage();
+        let record_steps = executor.get_inspector(ccx.state).zksync_record_steps();
+        let record_call_tree = executor.get_inspector(ccx.state).zksync_record_call_tree();
+        let ctx = get_context(ccx.state.strategy.context.as_mut());
+        let tracer_ccx = foundry_zksync_core::vm::CheatcodeTracerContext {
+            mocked_calls: ccx.state.mocked_calls.clone(),
+            expected_calls: Some(&mut ccx.state.expected_calls),
+            accesses: Some(&mut ccx.state.accesses),
+            persisted_factory_deps: Some(&mut ctx.persisted_factory_deps),
+            zk_env: ctx.zk_env.clone(),
+            record_storage_accesses: ccx.state.recorded_account_diffs_stack.is_some(),
+            evm_interpreter: ctx.evm_interpreter,
+            record_coverage,
+            record_edge_coverage,
+            record_steps,
+            record_call_tree,
+            ..Default::default()
+        };
+
+        let result =
+            foundry_zksync_core::vm::execute_l1_tx::<_, eyre::Report>(inputs, ccx.ecx, tracer_ccx)
+                .map_err(|err| {
+                    Error::display(format!("failed executing L1 transaction: {err:?}"))
+                })?;
+
+        result
+            .logs
+            .iter()
+            .filter_map(foundry_evm::decode::decode_console_log)
+            .for_each(|decoded_log| executor.console_log(ccx, &decoded_log));
+
+        self.append_coverage(ccx.state, executor, result.coverage);
+        self.append_edge_coverage(ccx.state, executor, result.edge_coverage);
+        self.append_steps(ccx.state, executor, result.steps);
+
+        if let Some(recorded_logs) = &mut ccx.state.recorded_logs {
+            recorded_logs.extend(result.logs.iter().map(|log| Vm::Log {
+                topics: log.data.topics().to_vec(),
+                data: log.data.data.clone(),
+                emitter: log.address,
+            }));
+        }
+        self.record_pubdata(ccx.state, executor, result.pubdata);
+        // the priority transaction is not traced as an EVM call, so the top call is recorded too
+        executor.get_inspector(ccx.state).trace_zksync(
+            ccx.ecx,
+            Box::new(ZkCallTraces { calls: result.call_traces, frames: result.frames }),
+            true,
+        );
+        if !ccx.state.expected_emits.is_empty() {
+            for log in &result.logs {
+                foundry_cheatcodes::handle_expect_emit(
+                    ccx.state,
+                    log,
+                    Some(&mut Default::default()),
+                );
+            }
+        }
+
+        Ok(match result.execution_result {
+            ExecutionResult::Success { output, .. } => {
+                self.append_recorded_accesses(ccx.state, ccx.ecx, result.account_accesses);
+                (true, output.into_data())
+            }
+            ExecutionResult::Revert { output, .. } => (false, output),
+            ExecutionResult::Halt { .. } => (false, halt_output(result.halt)),
+        })
+    }
 }
 
 impl CheatcodeInspectorStrategyRunner for ZksyncCheatcodeInspectorStrategyRunner {
@@ -632,19 +782,29 @@ fn zksync_try_create(
             }
         };
 
+        let record_coverage = executor.get_inspector(state).zksync_record_coverage();
+        let record_edge_coverage = executor.get_inspector(state).zksync_record_edge_coverage();
+        let record_steps = executor.get_inspector(state).zksync_record_steps();
+        let record_call_tree = executor.get_inspector(state).zksync_record_call_tree();
+        let ctx = get_context(state.strategy.context.as_mut());
         let ccx = foundry_zksync_core::vm::CheatcodeTracerContext {
             mocked_calls: state.mocked_calls.clone(),
             expected_calls: Some(&mut state.expected_calls),
             accesses: Some(&mut state.accesses),
             persisted_factory_deps: Some(&mut ctx.persisted_factory_deps),
             paymaster_data: ctx.paymaster_params.take(),
+            account_data: ctx.account_data.take(),
             zk_env: ctx.zk_env.clone(),
             record_storage_accesses: state.recorded_account_diffs_stack.is_some(),
             evm_interpreter: ctx.evm_interpreter,
+            record_coverage,
+            record_edge_coverage,
+            record_steps,
+            record_call_tree,
         };
 
         let mut gas = Gas::new(input.gas_limit());
-        match foundry_zksync_core::vm::create::<_, DatabaseError>(zk_create, ecx, ccx) {
+        match foundry_zksync_core::vm::create::<_, eyre::Report>(zk_create, ecx, ccx) {
             Ok(result) => {
                 if let Some(recorded_logs) = &mut state.recorded_logs {
                     recorded_logs.extend(result.logs.clone().into_iter().map(|log| Vm::Log {
@@ -669,13 +829,21 @@ fn zksync_try_create(
                     },
                 );
 
-                // append traces
+                // append debugger steps
+                self.append_steps(state, executor, result.steps);
+
+                // append pubdata and traces
+                self.record_pubdata(state, executor, result.pubdata);
                 executor.get_inspector(state).trace_zksync(
                     ecx,
-                    Box::new(result.call_traces),
+                    Box::new(ZkCallTraces { calls: result.call_traces, frames: result.frames }),
                     false,
                 );
 
+                // append coverage
+                self.append_coverage(state, executor, result.coverage);
+                self.append_edge_coverage(state, executor, result.edge_coverage);
+
                 // for each log in cloned logs call handle_expect_emit
                 if !state.expected_emits.is_empty() {
                     for log in result.logs {
@@ -756,7 +924,7 @@ fn zksync_try_create(
                     ExecutionResult::Halt { .. } => Some(CreateOutcome {
                         result: InterpreterResult {
                             result: InstructionResult::Revert,
-                            output: Bytes::from_iter(String::from("zk vm halted").as_bytes()),
+                            output: halt_output(result.halt),
                             gas,
                         },
                         address: None,
@@ -826,19 +994,29 @@ fn zksync_try_call(
         // To be consistent with where we clear factory deps in try_create_in_zk.
         ctx.zk_use_factory_deps.clear();
 
+        let record_coverage = executor.get_inspector(state).zksync_record_coverage();
+        let record_edge_coverage = executor.get_inspector(state).zksync_record_edge_coverage();
+        let record_steps = executor.get_inspector(state).zksync_record_steps();
+        let record_call_tree = executor.get_inspector(state).zksync_record_call_tree();
+        let ctx = get_context(state.strategy.context.as_mut());
         let ccx = foundry_zksync_core::vm::CheatcodeTracerContext {
             mocked_calls: state.mocked_calls.clone(),
             expected_calls: Some(&mut state.expected_calls),
             accesses: Some(&mut state.accesses),
             persisted_factory_deps: Some(&mut ctx.persisted_factory_deps),
             paymaster_data: ctx.paymaster_params.take(),
+            account_data: ctx.account_data.take(),
             zk_env: ctx.zk_env.clone(),
             record_storage_accesses: state.recorded_account_diffs_stack.is_some(),
             evm_interpreter: ctx.evm_interpreter,
+            record_coverage,
+            record_edge_coverage,
+            record_steps,
+            record_call_tree,
         };
 
         let mut gas = Gas::new(call.gas_limit);
-        match foundry_zksync_core::vm::call::<_, DatabaseError>(call, factory_deps, ecx, ccx) {
+        match foundry_zksync_core::vm::call::<_, eyre::Report>(call, factory_deps, ecx, ccx) {
             Ok(result) => {
                 // append console logs from zkEVM to the current executor's LogTracer
                 result.logs.iter().filter_map(foundry_evm::decode::decode_console_log).for_each(
@@ -855,6 +1033,11 @@ fn zksync_try_call(
                     },
                 );
 
+                // append coverage and debugger steps
+                self.append_coverage(state, executor, result.coverage);
+                self.append_edge_coverage(state, executor, result.edge_coverage);
+                self.append_steps(state, executor, result.steps);
+
                 // skip log processing for static calls
                 if !call.is_static {
                     if let Some(recorded_logs) = &mut state.recorded_logs {
@@ -865,10 +1048,11 @@ fn zksync_try_call(
                         }));
                     }
 
-                    // append traces
+                    // append pubdata and traces
+                    self.record_pubdata(state, executor, result.pubdata);
                     executor.get_inspector(state).trace_zksync(
                         ecx,
-                        Box::new(result.call_traces),
+                        Box::new(ZkCallTraces { calls: result.call_traces, frames: result.frames }),
                         false,
                     );
 
@@ -941,7 +1125,7 @@ fn zksync_try_call(
                     ExecutionResult::Halt { .. } => Some(CallOutcome {
                         result: InterpreterResult {
                             result: InstructionResult::Revert,
-                            output: Bytes::from_iter(String::from("zk vm halted").as_bytes()),
+                            output: halt_output(result.halt),
                             gas,
                         },
                         memory_offset: call.return_memory_offset.clone(),
@@ -1321,3 +1505,14 @@ fn get_context(
 ) -> &mut ZksyncCheatcodeInspectorStrategyContext {
     ctx.as_any_mut().downcast_mut().expect("expected ZksyncCheatcodeInspectorStrategyContext")
 }
+
+/// Returns the revert data for a call halted in the zkEVM, encoded as [`Vm::ZkHalt`] so that it
+/// can be matched by `zkExpectHalt`.
+fn halt_output(halt: Option<ZkHalt>) -> Bytes {
+    match halt {
+        Some(halt) => Vm::ZkHalt { reason: halt.reason.to_string(), message: halt.message }
+            .abi_encode()
+            .into(),
+        None => Bytes::from_iter(String::from("zk vm halted").as_bytes()),
+    }
+}
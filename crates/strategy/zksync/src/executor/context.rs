@@ -5,6 +5,7 @@
     dual_compiled_contracts::DualCompiledContracts,
 };
 use foundry_zksync_core::{ZkTransactionMetadata, vm::ZkEnv};
+use zksync_types::ProtocolVersionId;
 
 /// Defines the context for [ZksyncExecutorStrategyRunner].
 #[derive(Debug, Default, Clone)]
@@ -14,6 +15,8 @@ pub struct ZksyncExecutorStrategyContext {
     pub(super) dual_compiled_contracts: DualCompiledContracts,
     pub(super) zk_env: ZkEnv,
     pub(super) evm_interpreter: bool,
+    /// Protocol version set in the config, takes precedence over the one detected from a fork.
+    pub(super) protocol_version: Option<ProtocolVersionId>,
 }
 
 impl ExecutorStrategyContext for ZksyncExecutorStrategyContext {
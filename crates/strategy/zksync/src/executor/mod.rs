@@ -1,4 +1,7 @@
+use eyre::WrapErr;
+use foundry_config::zksync::ZkSyncConfig;
 use foundry_evm::executors::strategy::ExecutorStrategy;
+use foundry_zksync_core::vm::ZkEnv;
 
 mod context;
 mod runner;
@@ -12,17 +15,47 @@
 /// Create ZKsync strategy for [ExecutorStrategy].
 pub trait ZksyncExecutorStrategyBuilder {
     /// Create new zksync strategy.
-    fn new_zksync(evm_interpreter: bool) -> Self;
+    ///
+    /// Fails if the configured protocol version is not supported, if the configured pubdata price
+    /// is zero, or if the configured system contracts cannot be loaded.
+    fn new_zksync(config: &ZkSyncConfig) -> eyre::Result<Self>
+    where
+        Self: Sized;
 }
 
 impl ZksyncExecutorStrategyBuilder for ExecutorStrategy {
-    fn new_zksync(evm_interpreter: bool) -> Self {
-        Self {
+    fn new_zksync(config: &ZkSyncConfig) -> eyre::Result<Self> {
+        let default_env = ZkEnv::default();
+        let protocol_version = config
+            .protocol_version
+            .map(foundry_zksync_core::protocol_version)
+            .transpose()
+            .wrap_err("invalid `zksync.protocol_version`")?;
+        // a fair pubdata price of 0 is not supported, see `ZkEnv::default`
+        if config.fair_pubdata_price == Some(0) {
+            eyre::bail!("invalid `zksync.fair_pubdata_price`: pubdata price must not be zero");
+        }
+        let zk_env = ZkEnv {
+            l1_gas_price: config.l1_gas_price.unwrap_or(default_env.l1_gas_price),
+            fair_l2_gas_price: config.fair_l2_gas_price.unwrap_or(default_env.fair_l2_gas_price),
+            fair_pubdata_price: config.fair_pubdata_price.unwrap_or(default_env.fair_pubdata_price),
+            protocol_version: protocol_version.unwrap_or(default_env.protocol_version),
+            system_contracts_path: config.system_contracts_path.clone(),
+        };
+
+        if let Some(path) = &zk_env.system_contracts_path {
+            foundry_zksync_core::vm::validate_system_contracts(path, zk_env.protocol_version)
+                .wrap_err("invalid `zksync.system_contracts_path`")?;
+        }
+
+        Ok(Self {
             runner: &ZksyncExecutorStrategyRunner,
             context: Box::new(ZksyncExecutorStrategyContext {
-                evm_interpreter,
+                evm_interpreter: config.evm_interpreter,
+                zk_env,
+                protocol_version,
                 ..Default::default()
             }),
-        }
+        })
     }
 }
@@ -1,7 +1,7 @@
 use alloy_primitives::{Address, U256};
 use alloy_rpc_types::serde_helpers::OtherFields;
 use alloy_zksync::provider::{ZksyncProvider, zksync_provider};
-use eyre::Result;
+use eyre::{Result, WrapErr};
 use foundry_linking::LinkerError;
 use revm::{Database, context::result::ResultAndState};
 
@@ -24,7 +24,8 @@
     compilers::{artifact_output::zk::ZkArtifactOutput, zksolc::ZkSolcCompiler},
     dual_compiled_contracts::DualCompiledContracts,
 };
-use foundry_zksync_core::vm::ZkEnv;
+use foundry_zksync_core::{DEFAULT_PROTOCOL_VERSION, vm::ZkEnv};
+use zksync_types::ProtocolVersionId;
 
 use crate::{
     backend::{ZksyncBackendStrategyBuilder, ZksyncInspectContext},
@@ -212,6 +213,28 @@ fn transact(
     }
 }
 
+/// Returns the protocol version the zkVM should run with to match the fork, as reported in its
+/// block details, e.g. `Version28`. Falls back to the default version if it is unknown or not
+/// supported.
+fn fork_protocol_version(protocol_version: Option<&str>) -> ProtocolVersionId {
+    let Some(protocol_version) = protocol_version else {
+        return DEFAULT_PROTOCOL_VERSION;
+    };
+
+    protocol_version
+        .strip_prefix("Version")
+        .and_then(|version| version.parse().ok())
+        .ok_or_else(|| eyre::eyre!("unknown protocol version {protocol_version}"))
+        .and_then(foundry_zksync_core::protocol_version)
+        .unwrap_or_else(|err| {
+            let _ = foundry_common::sh_warn!(
+                "{err}, the fork is executed with protocol version {}",
+                DEFAULT_PROTOCOL_VERSION as u16
+            );
+            DEFAULT_PROTOCOL_VERSION
+        })
+}
+
 impl ExecutorStrategyExt for ZksyncExecutorStrategyRunner {
     fn zksync_set_dual_compiled_contracts(
         &self,
@@ -231,6 +254,13 @@ fn zksync_set_compilation_output(
         ctx.compilation_output.replace(output);
     }
 
+    fn zksync_compilation_output<'a>(
+        &self,
+        ctx: &'a dyn ExecutorStrategyContext,
+    ) -> Option<&'a ProjectCompileOutput<ZkSolcCompiler, ZkArtifactOutput>> {
+        get_context_ref(ctx).compilation_output.as_ref()
+    }
+
     fn zksync_set_fork_env(
         &self,
         ctx: &mut dyn ExecutorStrategyContext,
@@ -266,7 +296,21 @@ fn zksync_set_fork_env(
                     .unwrap_or_default()
                     .try_into()
                     .expect("failed to convert fair_pubdata_price to u64"),
+                protocol_version: ctx.protocol_version.unwrap_or_else(|| {
+                    fork_protocol_version(block_details.protocol_version.as_deref())
+                }),
+                system_contracts_path: ctx.zk_env.system_contracts_path.clone(),
             };
+
+            // The fork may select a protocol version the local system contracts were not
+            // validated for.
+            if let Some(path) = &ctx.zk_env.system_contracts_path {
+                foundry_zksync_core::vm::validate_system_contracts(
+                    path,
+                    ctx.zk_env.protocol_version,
+                )
+                .wrap_err("invalid `zksync.system_contracts_path` for the fork")?;
+            }
         }
 
         Ok(())
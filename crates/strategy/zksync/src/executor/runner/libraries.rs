@@ -22,9 +22,7 @@
         },
     },
 };
-use foundry_linking::{
-    DEPLOY_TIME_LINKING_ZKSOLC_MIN_VERSION, LinkerError, ZkLinker, ZkLinkerError,
-};
+use foundry_linking::{LinkerError, ZkLinker, ZkLinkerError, ensure_deploy_time_linking_supported};
 use foundry_zksync_compilers::dual_compiled_contracts::DualCompiledContract;
 use foundry_zksync_core::{
     DEFAULT_CREATE2_DEPLOYER_ZKSYNC, ZKSYNC_TRANSACTION_OTHER_FIELDS_KEY, ZkTransactionMetadata,
@@ -56,26 +54,25 @@ pub(super) fn link_impl(
         let contracts: ArtifactContracts<CompactContractBytecodeCow<'_>> =
             input.artifact_ids().collect();
 
-        let Ok(zksolc_settings) = foundry_config::zksync::config_zksolc_settings(config) else {
-            tracing::error!("unable to determine zksolc compiler to be used for linking");
-            // TODO(zk): better error
-            return Err(LinkerError::CyclicDependency);
-        };
+        let zksolc_settings = foundry_config::zksync::config_zksolc_settings(config)
+            .map_err(|err| LinkerError::LinkingFailed { artifact: err.to_string() })?;
         let version = zksolc_settings.zksolc_version_ref();
 
         let linker = ZkLinker::new(root, contracts.clone(), zksolc_settings.zksolc_path(), input);
 
         let zk_linker_error_to_linker = |zk_error| match zk_error {
             ZkLinkerError::Inner(err) => err,
-            // TODO(zk): better error value
-            ZkLinkerError::MissingLibraries(libs) => LinkerError::MissingLibraryArtifact {
-                file: "libraries".to_string(),
-                name: libs.len().to_string(),
-            },
-            ZkLinkerError::MissingFactoryDeps(libs) => LinkerError::MissingLibraryArtifact {
-                file: "factoryDeps".to_string(),
-                name: libs.len().to_string(),
-            },
+            ZkLinkerError::MissingLibraries(libs) | ZkLinkerError::MissingFactoryDeps(libs) => libs
+                .into_iter()
+                .next()
+                .map(|lib| LinkerError::MissingLibraryArtifact {
+                    file: lib.filename,
+                    name: lib.library,
+                })
+                .unwrap_or(LinkerError::MissingTargetArtifact),
+            err @ (ZkLinkerError::UnsupportedVersion(_) | ZkLinkerError::Zksolc(_)) => {
+                LinkerError::LinkingFailed { artifact: err.to_string() }
+            }
         };
 
         let foundry_linking::LinkOutput { libraries, libs_to_deploy: _ } = linker
@@ -87,20 +84,14 @@ pub(super) fn link_impl(
                 0,
                 linker.linker.contracts.keys(), // link everything
             )
+            // older zksolc versions fail when invoked with `--link`
+            .map_err(|err| ensure_deploy_time_linking_supported(version).err().unwrap_or(err))
             .map_err(zk_linker_error_to_linker)?;
 
         // if we have no libraries then no linking will happen
         // so we can skip the version check
         if !libraries.is_empty() {
-            // TODO(zk): better error
-            if version < &DEPLOY_TIME_LINKING_ZKSOLC_MIN_VERSION {
-                tracing::error!(
-                    %version,
-                    minimum_version = %DEPLOY_TIME_LINKING_ZKSOLC_MIN_VERSION,
-                    "deploy-time linking not supported"
-                );
-                return Err(LinkerError::CyclicDependency);
-            }
+            ensure_deploy_time_linking_supported(version).map_err(zk_linker_error_to_linker)?;
         }
 
         let linked_contracts = linker
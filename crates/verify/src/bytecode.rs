@@ -106,6 +106,23 @@ pub struct VerifyBytecodeArgs {
     /// Verify for zksync
     #[clap(long)]
     pub zksync: bool,
+
+    /// Verify offline against a bundle exported with `forge verify-contract --zksync --export`.
+    ///
+    /// The contract is recompiled from the bundle and compared against the bytecode in
+    /// `--bytecode-file`, without accessing the network. The compilers recorded in the bundle
+    /// must already be installed.
+    #[arg(
+        long,
+        requires_all = ["zksync", "bytecode_file"],
+        value_hint = ValueHint::DirPath,
+        value_name = "DIR"
+    )]
+    pub from_bundle: Option<PathBuf>,
+
+    /// The path to a file containing the hex-encoded deployed bytecode to verify against.
+    #[arg(long, requires = "from_bundle", value_hint = ValueHint::FilePath, value_name = "PATH")]
+    pub bytecode_file: Option<PathBuf>,
 }
 
 impl figment::Provider for VerifyBytecodeArgs {
@@ -143,7 +160,7 @@ pub async fn run(mut self) -> Result<()> {
         // Setup
         let config = self.load_config()?;
         let provider = utils::get_provider(&config)?;
-        let strategy = utils::get_executor_strategy(&config);
+        let strategy = utils::get_executor_strategy(&config)?;
 
         // If chain is not set, we try to get it from the RPC.
         // If RPC is not set, the default chain is used.
@@ -3,11 +3,12 @@
     etherscan::EtherscanVerificationProvider,
     types::VerificationType,
     utils::{BytecodeType, JsonResult},
+    zksync::bundle::{ZkVerificationBundle, bytecode_hash},
 };
-use alloy_primitives::Address;
+use alloy_primitives::{Address, hex};
 use alloy_provider::Provider;
 use alloy_rpc_types::{BlockId, BlockNumberOrTag};
-use eyre::{OptionExt, Result};
+use eyre::{Context, OptionExt, Result};
 use foundry_block_explorers::{Response, ResponseData, errors::EtherscanError};
 use foundry_cli::utils::{self, LoadConfig};
 use foundry_common::{compile::ProjectCompiler, shell};
@@ -17,6 +18,7 @@
 };
 use reqwest::Url;
 use serde::{Deserialize, Deserializer, Serialize};
+use std::path::Path;
 use yansi::Paint;
 
 /// Run verify-bytecode for zksync. Since there's only runtime bytecode and that bytecode
@@ -24,6 +26,10 @@
 /// in EVM as we do not need to simulate the deployment. We just compare the compiled bytecode
 /// against the on-chain one.
 pub async fn run(args: VerifyBytecodeArgs) -> Result<()> {
+    if let Some(dir) = &args.from_bundle {
+        return run_from_bundle(&args, dir);
+    }
+
     let config = args.load_config()?;
     let provider = utils::get_provider(&config)?;
     // If chain is not set, we try to get it from the RPC.
@@ -116,6 +122,74 @@ pub async fn run(args: VerifyBytecodeArgs) -> Result<()> {
     Ok(())
 }
 
+/// Run verify-bytecode offline, recompiling the contract from a verification bundle and comparing
+/// it against the bytecode saved in `--bytecode-file`. Missing compilers are not installed.
+fn run_from_bundle(args: &VerifyBytecodeArgs, dir: &Path) -> Result<()> {
+    let config = args.load_config()?;
+    let (bundle, input) = ZkVerificationBundle::read(dir)?;
+
+    if bundle.address != args.address {
+        eyre::bail!("Bundle was exported for address {}, not {}", bundle.address, args.address);
+    }
+    let bundle_contract_name =
+        bundle.contract_name.rsplit_once(':').map_or(bundle.contract_name.as_str(), |(_, n)| n);
+    if bundle_contract_name != args.contract.name {
+        eyre::bail!("Contract name mismatch: bundle contains {}", bundle.contract_name);
+    }
+
+    let bytecode_file = args.bytecode_file.as_ref().ok_or_eyre("--bytecode-file is required")?;
+    let deployed_bytecode = hex::decode(
+        std::fs::read_to_string(bytecode_file)
+            .wrap_err_with(|| format!("failed to read {}", bytecode_file.display()))?
+            .trim(),
+    )
+    .wrap_err("bytecode file must contain hex-encoded bytecode")?;
+    if deployed_bytecode.is_empty() {
+        eyre::bail!("No bytecode found in {}", bytecode_file.display());
+    }
+
+    if !shell::is_json() {
+        sh_println!(
+            "Verifying bytecode for contract {} at address {} from bundle {}",
+            args.contract.name,
+            args.address,
+            dir.display()
+        )?;
+    }
+
+    let local_bytecode = bundle.compile(&input, config.zksync.solc_path.clone())?;
+    let local_hash = bytecode_hash(&local_bytecode);
+    if local_hash != bundle.bytecode_hash {
+        eyre::bail!(
+            "Recompiled bytecode hash {local_hash} does not match the bundle's expected hash {}",
+            bundle.bytecode_hash
+        );
+    }
+
+    let hash_type = input.settings.metadata.as_ref().and_then(|m| m.hash_type).unwrap_or_default();
+    let match_type = match_bytecodes(&local_bytecode, &deployed_bytecode, hash_type, hash_type);
+
+    let bytecode_type = BytecodeType::Runtime;
+    if shell::is_json() {
+        let json_res = JsonResult {
+            bytecode_type,
+            match_type,
+            message: match_type.is_none().then(|| format!("{bytecode_type:?} code did not match")),
+        };
+        sh_println!("{}", serde_json::to_string(&[json_res])?)?;
+    } else if let Some(res) = match_type {
+        sh_println!(
+            "{} with status {}",
+            format!("{bytecode_type:?} code matched").green().bold(),
+            res.green().bold()
+        )?;
+    } else {
+        sh_err!("{bytecode_type:?} code did not match the bytecode recompiled from the bundle")?;
+    }
+
+    Ok(())
+}
+
 fn match_bytecodes(
     local_bytecode: &[u8],
     onchain_bytecode: &[u8],
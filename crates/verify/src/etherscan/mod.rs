@@ -323,14 +323,17 @@ pub async fn create_verify_request(
                 // compiler input, so we need a different one for zksolc
                 self.source_provider(args).zksync_source(args, context)?
             }
+            CompilerVerificationContext::ZkVyper(_) => {
+                eyre::bail!(
+                    "Etherscan verification of Vyper contracts is not supported in zksync mode, use `--verifier zksync` instead"
+                )
+            }
         };
 
         let lang = match context {
             CompilerVerificationContext::Solc(context) => args.detect_language(context),
-            CompilerVerificationContext::ZkSolc(_context) => {
-                // TODO(zk): Vyper is not supported right now
-                ContractLanguage::Solidity
-            }
+            CompilerVerificationContext::ZkSolc(_context) => ContractLanguage::Solidity,
+            CompilerVerificationContext::ZkVyper(_context) => ContractLanguage::Vyper,
         };
 
         let mut compiler_version = context.compiler_version().clone();
@@ -23,7 +23,7 @@
 pub use retry::RetryArgs;
 
 pub mod verify;
-pub use verify::{VerifierArgs, VerifyArgs, VerifyCheckArgs};
+pub use verify::{ContractLanguage, VerifierArgs, VerifyArgs, VerifyCheckArgs};
 
 mod types;
 
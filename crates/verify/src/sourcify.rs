@@ -202,6 +202,7 @@ fn get_project_root<'a>(&self, context: &'a CompilerVerificationContext) -> &'a
         match context {
             CompilerVerificationContext::Solc(c) => c.project.root(),
             CompilerVerificationContext::ZkSolc(c) => c.project.root(),
+            CompilerVerificationContext::ZkVyper(c) => c.project.root(),
         }
     }
 
@@ -291,7 +292,7 @@ async fn prepare_verify_request(
                     creation_transaction_hash,
                 })
             }
-            CompilerVerificationContext::ZkSolc(_zk_context) => {
+            CompilerVerificationContext::ZkSolc(_) | CompilerVerificationContext::ZkVyper(_) => {
                 // For ZkSolc, we'll need to implement this differently since the standard
                 // Sourcify API may not support ZkSolc yet. For now, return an error.
                 Err(eyre::eyre!(
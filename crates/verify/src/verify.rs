@@ -98,6 +98,12 @@ pub struct VerifyArgs {
     #[arg(long, value_name = "VERSION")]
     pub compiler_version: Option<String>,
 
+    /// The zksolc or zkvyper version used to build the smart contract, in zksync mode.
+    ///
+    /// Defaults to the version set in `zksync.zksolc` or `zksync.zkvyper`.
+    #[arg(long, requires = "zksync", value_name = "VERSION")]
+    pub zk_compiler_version: Option<String>,
+
     /// The compilation profile to use to build the smart contract.
     #[arg(long, value_name = "PROFILE_NAME")]
     pub compilation_profile: Option<String>,
@@ -140,6 +146,21 @@ pub struct VerifyArgs {
     #[arg(long, conflicts_with = "flatten")]
     pub show_standard_json_input: bool,
 
+    /// Export an offline verification bundle to the given directory instead of submitting the
+    /// contract for verification.
+    ///
+    /// The bundle contains the zksolc standard json input, the compiler versions, the constructor
+    /// arguments and the expected bytecode hash, and can be checked with
+    /// `forge verify-bytecode --zksync --from-bundle`.
+    #[arg(
+        long,
+        requires = "zksync",
+        conflicts_with = "show_standard_json_input",
+        value_hint = ValueHint::DirPath,
+        value_name = "DIR"
+    )]
+    pub export: Option<PathBuf>,
+
     /// Use the Yul intermediate representation compilation pipeline.
     #[arg(long)]
     pub via_ir: bool,
@@ -273,6 +294,12 @@ pub async fn run(mut self) -> Result<()> {
             return Ok(());
         }
 
+        if let Some(dir) = &self.export {
+            return crate::zksync::ZkVerificationProvider::default()
+                .export_bundle(&self, &context, dir)
+                .await;
+        }
+
         let verifier_url = self.verifier.verifier_url.clone();
         sh_println!("Start verifying contract `{}` deployed on {chain}", self.address)?;
         if let Some(version) = &self.evm_version {
@@ -322,7 +349,9 @@ pub fn verification_provider(&self) -> Result<Box<dyn VerificationProvider>> {
     /// Resolves [VerificationContext] object either from entered contract name or by trying to
     /// match bytecode located at given address.
     pub async fn resolve_either_context(&self) -> Result<CompilerVerificationContext> {
-        if self.zksync {
+        if self.zksync && self.is_vyper_target() {
+            self.zk_resolve_vyper_context().map(CompilerVerificationContext::ZkVyper)
+        } else if self.zksync {
             self.zk_resolve_context().await.map(CompilerVerificationContext::ZkSolc)
         } else {
             self.resolve_context().await.map(CompilerVerificationContext::Solc)
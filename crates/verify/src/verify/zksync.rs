@@ -1,16 +1,17 @@
 use std::{collections::HashSet, path::PathBuf};
 
 use alloy_provider::Provider;
-use eyre::Result;
+use eyre::{OptionExt, Result};
 use foundry_cli::utils::{self, LoadConfig};
 use foundry_common::{ContractsByArtifact, compile::ProjectCompiler};
 use foundry_compilers::solc::Solc;
 use foundry_config::SolcReq;
 use itertools::Itertools;
+use semver::Version;
 
-use crate::zk_provider::ZkVerificationContext;
+use crate::zk_provider::{ZkVerificationContext, ZkVyperVerificationContext};
 
-use super::VerifyArgs;
+use super::{ContractLanguage, VerifyArgs};
 
 impl VerifyArgs {
     /// Resolves [`ZkVerificationContext`] object either from entered contract name or
@@ -20,6 +21,9 @@ impl VerifyArgs {
     pub(super) async fn zk_resolve_context(&self) -> Result<ZkVerificationContext> {
         let mut config = self.load_config()?;
         config.libraries.extend(self.libraries.clone());
+        if let Some(version) = &self.zk_compiler_version {
+            config.zksync.zksolc = Some(SolcReq::Version(version.trim_start_matches('v').parse()?));
+        }
 
         let project = foundry_config::zksync::config_create_project(&config, config.cache, false)?;
 
@@ -95,4 +99,54 @@ pub(super) async fn zk_resolve_context(&self) -> Result<ZkVerificationContext> {
             )
         }
     }
+
+    /// Returns whether the contract to verify is a Vyper contract, either from `--language` or
+    /// from the extension of the contract path.
+    pub(super) fn is_vyper_target(&self) -> bool {
+        self.language.map(|language| language == ContractLanguage::Vyper).unwrap_or_else(|| {
+            self.contract
+                .as_ref()
+                .and_then(|contract| contract.path.as_ref())
+                .is_some_and(|path| path.ends_with(".vy"))
+        })
+    }
+
+    /// Resolves [`ZkVyperVerificationContext`] object from the entered contract.
+    ///
+    /// Will assume configured compiler is zkvyper
+    pub(super) fn zk_resolve_vyper_context(&self) -> Result<ZkVyperVerificationContext> {
+        let mut config = self.load_config()?;
+        if let Some(version) = &self.zk_compiler_version {
+            config.zksync.zkvyper =
+                Some(SolcReq::Version(version.trim_start_matches('v').parse()?));
+        }
+
+        let contract = self
+            .contract
+            .as_ref()
+            .ok_or_eyre("a contract name is required to verify Vyper contracts in zksync mode")?;
+        let contract_path = if let Some(ref path) = contract.path {
+            config.root.join(path)
+        } else {
+            config.project()?.find_contract_path(&contract.name)?
+        };
+
+        let context =
+            ZkVyperVerificationContext::new(contract_path, contract.name.clone(), config)?;
+
+        // The vyper version is not installed on demand, it has to match the configured one.
+        if let Some(version) = &self.compiler_version {
+            let version: Version = version.trim_start_matches('v').parse()?;
+            let vyper_version = &context.compiler_version.vyper;
+            if (vyper_version.major, vyper_version.minor, vyper_version.patch)
+                != (version.major, version.minor, version.patch)
+            {
+                eyre::bail!(
+                    "contract was compiled with vyper {version}, but the configured vyper is {vyper_version}"
+                );
+            }
+        }
+
+        Ok(context)
+    }
 }
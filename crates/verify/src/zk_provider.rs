@@ -5,8 +5,8 @@
 use foundry_common::compile::ProjectCompiler;
 use foundry_compilers::{
     Artifact, Graph, Project,
-    artifacts::{BytecodeObject, Source, output_selection::OutputSelection},
-    compilers::CompilerSettings,
+    artifacts::{BytecodeObject, Source, Sources, output_selection::OutputSelection},
+    compilers::{CompilerSettings, vyper::parser::VyperParser},
     resolver::parse::SolParser,
     solc::{Solc, SolcCompiler},
 };
@@ -16,6 +16,7 @@
     zksolc::{
         self, ZKSOLC_FIRST_VERSION_SUPPORTS_CBOR, ZKSYNC_SOLC_REVISIONS, ZkSolc, ZkSolcCompiler,
     },
+    zkvyper::{ZkVyperCompiler, input::ZkVyperInput},
 };
 use revm::primitives::Bytes;
 use semver::Version;
@@ -134,11 +135,105 @@ pub fn get_target_imports(&self) -> Result<Vec<PathBuf>> {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct ZkVyperVersion {
+    pub zkvyper: Version,
+    pub vyper: Version,
+}
+
+/// Container with data required for the verification of a Vyper contract compiled with zkvyper.
+#[derive(Debug, Clone)]
+pub struct ZkVyperVerificationContext {
+    pub config: Config,
+    pub project: Project<ZkVyperCompiler, ZkArtifactOutput>,
+    pub target_path: PathBuf,
+    pub target_name: String,
+    pub compiler_version: ZkVyperVersion,
+}
+
+impl ZkVyperVerificationContext {
+    pub fn new(target_path: PathBuf, target_name: String, config: Config) -> Result<Self> {
+        let mut project =
+            foundry_config::zksync::config_create_zkvyper_project(&config, config.cache, false)?
+                .ok_or_eyre("a `vyper` compiler is required to verify Vyper contracts")?;
+        project.no_artifacts = true;
+
+        let compiler_version = ZkVyperVersion {
+            zkvyper: project.settings.zkvyper_version_ref().clone(),
+            vyper: project.compiler.vyper.version.clone(),
+        };
+
+        Ok(Self { config, project, target_name, target_path, compiler_version })
+    }
+
+    /// Compiles the project and returns the bytecode of the target contract.
+    pub fn get_target_bytecode(&self) -> Result<Bytes> {
+        let output = ProjectCompiler::new().quiet(true).zksync_compile_vyper(&self.project)?;
+        let artifact = output
+            .find(&self.target_path, &self.target_name)
+            .ok_or_eyre("Contract artifact wasn't found locally")?;
+
+        let bytecode = artifact
+            .get_bytecode_object()
+            .ok_or_eyre("Contract artifact does not contain bytecode")?;
+
+        match bytecode.as_ref() {
+            BytecodeObject::Bytecode(bytes) => Ok(bytes.clone()),
+            BytecodeObject::Unlinked(_) => {
+                Err(eyre!("You have to provide correct libraries to use --guess-constructor-args"))
+            }
+        }
+    }
+
+    /// Compiles the project and returns the ABI of the target contract.
+    pub fn get_target_abi(&self) -> Result<JsonAbi> {
+        let output = ProjectCompiler::new().quiet(true).zksync_compile_vyper(&self.project)?;
+        let artifact = output
+            .find(&self.target_path, &self.target_name)
+            .ok_or_eyre("failed to find target artifact when compiling for abi")?;
+
+        artifact.abi.clone().ok_or_eyre("target artifact does not have an ABI")
+    }
+
+    /// Returns [Vec] containing imports of the target file.
+    pub fn get_target_imports(&self) -> Result<Vec<PathBuf>> {
+        let mut sources = self.project.paths.read_input_files()?;
+        sources.insert(self.target_path.clone(), Source::read(&self.target_path)?);
+        let graph = Graph::<VyperParser>::resolve_sources(&self.project.paths, sources)?;
+
+        Ok(graph.imports(&self.target_path).into_iter().map(|p| p.to_path_buf()).collect())
+    }
+
+    /// Returns the zkvyper standard json input of the target file and its imports, along with
+    /// the fully qualified name of the target contract.
+    pub fn standard_json_input(&self) -> Result<(ZkVyperInput, String)> {
+        let mut sources = Sources::new();
+        for path in std::iter::once(self.target_path.clone()).chain(self.get_target_imports()?) {
+            let source = Source::read(&path)?;
+            sources.insert(path, source);
+        }
+
+        let mut input = ZkVyperInput::new(sources, self.project.settings.settings.clone());
+        input.strip_prefix(self.project.root());
+
+        let relative_path = self
+            .target_path
+            .strip_prefix(self.project.root())
+            .unwrap_or(self.target_path.as_path())
+            .display()
+            .to_string()
+            .replace('\\', "/");
+
+        Ok((input, format!("{relative_path}:{}", self.target_name)))
+    }
+}
+
 #[derive(Debug)]
 #[allow(clippy::large_enum_variant)]
 pub enum CompilerVerificationContext {
     Solc(VerificationContext),
     ZkSolc(ZkVerificationContext),
+    ZkVyper(ZkVyperVerificationContext),
 }
 
 impl CompilerVerificationContext {
@@ -146,6 +241,7 @@ pub fn config(&self) -> &Config {
         match self {
             Self::Solc(c) => &c.config,
             Self::ZkSolc(c) => &c.config,
+            Self::ZkVyper(c) => &c.config,
         }
     }
 
@@ -153,6 +249,7 @@ pub fn target_path(&self) -> &PathBuf {
         match self {
             Self::Solc(c) => &c.target_path,
             Self::ZkSolc(c) => &c.target_path,
+            Self::ZkVyper(c) => &c.target_path,
         }
     }
 
@@ -160,6 +257,7 @@ pub fn target_name(&self) -> &str {
         match self {
             Self::Solc(c) => &c.target_name,
             Self::ZkSolc(c) => &c.target_name,
+            Self::ZkVyper(c) => &c.target_name,
         }
     }
 
@@ -170,6 +268,7 @@ pub fn compiler_version(&self) -> &Version {
             // this ambiguity somehow (e.g: by having sepparate paths for solc/zksolc
             // and remove this method altogether)
             Self::ZkSolc(c) => &c.compiler_version.solc,
+            Self::ZkVyper(c) => &c.compiler_version.vyper,
         }
     }
 
@@ -177,6 +276,7 @@ pub fn get_target_abi(&self) -> Result<JsonAbi> {
         match self {
             Self::Solc(c) => c.get_target_abi(),
             Self::ZkSolc(c) => c.get_target_abi(),
+            Self::ZkVyper(c) => c.get_target_abi(),
         }
     }
 
@@ -194,6 +294,7 @@ pub fn get_target_imports(&self) -> Result<Vec<PathBuf>> {
                 Ok(graph.imports(&c.target_path).into_iter().map(|p| p.to_path_buf()).collect())
             }
             Self::ZkSolc(c) => c.get_target_imports(),
+            Self::ZkVyper(c) => c.get_target_imports(),
         }
     }
 
@@ -221,6 +322,7 @@ pub fn get_target_metadata(&self) -> Result<serde_json::Value> {
                 Ok(serde_json::to_value(m)?)
             }
             Self::ZkSolc(c) => c.get_target_metadata(),
+            Self::ZkVyper(_) => Err(eyre!("zkvyper artifacts do not contain metadata")),
         }
     }
 
@@ -260,6 +362,7 @@ pub fn get_target_bytecode(&self) -> Result<Bytes> {
                     )),
                 }
             }
+            Self::ZkVyper(context) => context.get_target_bytecode(),
         }
     }
 }
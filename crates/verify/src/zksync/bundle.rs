@@ -0,0 +1,129 @@
+//! Offline verification bundles.
+//!
+//! A bundle is a directory containing the exact zksolc standard JSON input used to verify a
+//! contract, together with a manifest recording the compiler versions, the constructor arguments
+//! and the expected EraVM bytecode hash. It can be recompiled without any access to the network
+//! or to the original project, so that a deployment can be verified fully offline.
+
+use alloy_primitives::{Address, B256, Bytes};
+use eyre::{Context, OptionExt, Result};
+use foundry_zksync_compilers::compilers::zksolc::{
+    ZKSOLC_FIRST_VERSION_SUPPORTS_CBOR, ZKSYNC_SOLC_REVISIONS, ZkSolc,
+    input::{StandardJsonCompilerInput, ZkSolcInput},
+};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use zksync_types::bytecode::BytecodeHash;
+
+/// File name of the bundle manifest.
+pub const BUNDLE_MANIFEST_FILE: &str = "bundle.json";
+
+/// File name of the zksolc standard JSON input of the bundle.
+pub const BUNDLE_STANDARD_JSON_FILE: &str = "standard_json_input.json";
+
+/// Manifest of an offline verification bundle.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkVerificationBundle {
+    /// The address the contract is deployed at.
+    pub address: Address,
+    /// The contract name in the form `<path>:<contractname>`, as found in the standard JSON input.
+    pub contract_name: String,
+    /// The `zksolc` version used to compile the contract.
+    pub zksolc_version: Version,
+    /// The `solc` version used along `zksolc`.
+    pub solc_version: Version,
+    /// Whether the ZKsync fork of `solc` was used.
+    pub is_zksync_solc: bool,
+    /// The ABI-encoded constructor arguments.
+    pub constructor_args: Bytes,
+    /// The EraVM bytecode hash of the compiled contract.
+    pub bytecode_hash: B256,
+}
+
+impl ZkVerificationBundle {
+    /// Writes the manifest and the standard JSON input to `dir`, creating it if needed.
+    pub fn write(&self, dir: &Path, input: &StandardJsonCompilerInput) -> Result<()> {
+        std::fs::create_dir_all(dir)
+            .wrap_err_with(|| format!("failed to create bundle directory {}", dir.display()))?;
+        foundry_common::fs::write_pretty_json_file(&dir.join(BUNDLE_MANIFEST_FILE), self)?;
+        foundry_common::fs::write_pretty_json_file(&dir.join(BUNDLE_STANDARD_JSON_FILE), input)?;
+        Ok(())
+    }
+
+    /// Reads the manifest and the standard JSON input of the bundle in `dir`.
+    pub fn read(dir: &Path) -> Result<(Self, StandardJsonCompilerInput)> {
+        let bundle = foundry_common::fs::read_json_file(&dir.join(BUNDLE_MANIFEST_FILE))
+            .wrap_err_with(|| format!("failed to read bundle manifest in {}", dir.display()))?;
+        let input = foundry_common::fs::read_json_file(&dir.join(BUNDLE_STANDARD_JSON_FILE))
+            .wrap_err_with(|| format!("failed to read standard json input in {}", dir.display()))?;
+        Ok((bundle, input))
+    }
+
+    /// Compiles `input` with the compilers recorded in the bundle and returns the bytecode of the
+    /// bundled contract.
+    ///
+    /// The compilers are never downloaded: `zksolc` and the ZKsync fork of `solc` must already be
+    /// installed, a different `solc` must be provided with `solc_path`.
+    pub fn compile(
+        &self,
+        input: &StandardJsonCompilerInput,
+        solc_path: Option<PathBuf>,
+    ) -> Result<Bytes> {
+        let solc_path = if self.is_zksync_solc {
+            let solc_revision = if self.zksolc_version >= ZKSOLC_FIRST_VERSION_SUPPORTS_CBOR {
+                &ZKSYNC_SOLC_REVISIONS[1]
+            } else {
+                &ZKSYNC_SOLC_REVISIONS[0]
+            };
+            let version = self.solc_version.to_string();
+            let revision = solc_revision.to_string();
+            ZkSolc::find_solc_installed_version(&version, &revision)?.ok_or_else(|| {
+                eyre::eyre!("compiler ZKsync solc {version}-{revision} is not installed")
+            })?
+        } else {
+            solc_path.ok_or_eyre(
+                "the bundle was not compiled with ZKsync solc, a `solc` binary must be provided",
+            )?
+        };
+
+        let zksolc_path =
+            ZkSolc::find_installed_version(&self.zksolc_version)?.ok_or_else(|| {
+                eyre::eyre!("compiler zksolc {} is not installed", self.zksolc_version)
+            })?;
+        let zksolc = ZkSolc::new(zksolc_path, Some(solc_path))?;
+        let zksolc_input = ZkSolcInput {
+            language: input.language,
+            sources: input.sources.iter().cloned().collect(),
+            settings: input.settings.clone(),
+            ..Default::default()
+        };
+        let output = zksolc.compile(&zksolc_input)?;
+        if let Some(error) = output.errors.iter().find(|error| error.is_error()) {
+            eyre::bail!("failed to compile bundle: {error}");
+        }
+
+        let (path, name) = self
+            .contract_name
+            .rsplit_once(':')
+            .ok_or_eyre("bundle contract name must be in the form `<path>:<contractname>`")?;
+        let contract = output
+            .contracts
+            .get(Path::new(path))
+            .and_then(|contracts| contracts.get(name))
+            .ok_or_else(|| {
+                eyre::eyre!("contract {} not found in bundle output", self.contract_name)
+            })?;
+
+        contract
+            .bytecode()
+            .and_then(|bytecode| bytecode.object.into_bytes())
+            .ok_or_eyre("Unlinked bytecode is not supported for verification")
+    }
+}
+
+/// Returns the EraVM bytecode hash of `bytecode`.
+pub fn bytecode_hash(bytecode: &[u8]) -> B256 {
+    B256::from(BytecodeHash::for_bytecode(bytecode).value().0)
+}
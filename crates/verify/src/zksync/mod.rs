@@ -10,7 +10,7 @@
 };
 use futures::FutureExt;
 use serde::{Deserialize, Serialize};
-use std::{fmt::Debug, thread::sleep, time::Duration};
+use std::{fmt::Debug, path::Path, thread::sleep, time::Duration};
 use zksync_types::{
     H160,
     contract_verification::etherscan::{
@@ -18,6 +18,7 @@
     },
 };
 
+pub mod bundle;
 pub mod standard_json;
 
 // Etherscan-compatible API structures
@@ -205,47 +206,103 @@ impl ZkVerificationProvider {
     fn source_provider(&self) -> Box<dyn ZksyncSourceProvider> {
         Box::new(standard_json::ZksyncStandardJsonSource)
     }
-    async fn prepare_request(
+
+    /// Writes an offline verification bundle for the target contract to `dir`, instead of
+    /// submitting it to the explorer.
+    pub async fn export_bundle(
         &mut self,
         args: &VerifyArgs,
         context: &CompilerVerificationContext,
-    ) -> Result<EtherscanVerificationRequest> {
-        let (source, contract_name) = if let CompilerVerificationContext::ZkSolc(context) = context
-        {
-            self.source_provider().zk_source(context)?
-        } else {
+        dir: &Path,
+    ) -> Result<()> {
+        let CompilerVerificationContext::ZkSolc(zk_context) = context else {
             eyre::bail!("Unsupported compiler context: only ZkSolc is supported");
         };
+        let (source, contract_name) = self.source_provider().zk_source(zk_context)?;
+        let constructor_args = self.constructor_args(args, context).await?.unwrap_or_default();
+
+        let mut bundle = bundle::ZkVerificationBundle {
+            address: args.address,
+            contract_name,
+            zksolc_version: zk_context.compiler_version.zksolc.clone(),
+            solc_version: zk_context.compiler_version.solc.clone(),
+            is_zksync_solc: zk_context.compiler_version.is_zksync_solc,
+            constructor_args: hex::decode(constructor_args)?.into(),
+            bytecode_hash: Default::default(),
+        };
+        // Compile the exact input being exported, so the bundle is known to be reproducible.
+        let bytecode = bundle.compile(&source, zk_context.config.zksync.solc_path.clone())?;
+        bundle.bytecode_hash = bundle::bytecode_hash(&bytecode);
+        bundle.write(dir, &source)?;
 
-        let (solc_version, zk_compiler_version) = match context {
-            CompilerVerificationContext::ZkSolc(zk_context) => {
-                // Format solc_version as "zkVM-{compiler_version}-1.0.2"
-                let solc_revision =
-                    if zk_context.compiler_version.zksolc >= ZKSOLC_FIRST_VERSION_SUPPORTS_CBOR {
+        sh_println!(
+            "Exported verification bundle for [{}] at address {} to {}.",
+            bundle.contract_name,
+            bundle.address,
+            dir.display()
+        )?;
+        sh_println!("Expected bytecode hash: {}", bundle.bytecode_hash)?;
+        Ok(())
+    }
+
+    async fn prepare_request(
+        &mut self,
+        args: &VerifyArgs,
+        context: &CompilerVerificationContext,
+    ) -> Result<EtherscanVerificationRequest> {
+        let (source_code, contract_name, compiler_version, zk_compiler_version, optimization_used) =
+            match context {
+                CompilerVerificationContext::ZkSolc(zk_context) => {
+                    let (source, contract_name) = self.source_provider().zk_source(zk_context)?;
+                    // Format solc_version as "zkVM-{compiler_version}-1.0.2"
+                    let solc_revision = if zk_context.compiler_version.zksolc
+                        >= ZKSOLC_FIRST_VERSION_SUPPORTS_CBOR
+                    {
                         &ZKSYNC_SOLC_REVISIONS[1]
                     } else {
                         &ZKSYNC_SOLC_REVISIONS[0]
                     };
-                let solc_version =
-                    format!("zkVM-{}-{solc_revision}", zk_context.compiler_version.solc);
-                let zk_compiler_version = format!("v{}", zk_context.compiler_version.zksolc);
-                (solc_version, zk_compiler_version)
-            }
-            _ => {
-                return Err(eyre::eyre!(
-                    "Expected context to be of type ZkSolc, but received a different type."
-                ));
-            }
-        };
-        let optimization_used = source.settings.optimizer.enabled.unwrap_or(false);
+                    let solc_version =
+                        format!("zkVM-{}-{solc_revision}", zk_context.compiler_version.solc);
+                    let zk_compiler_version = format!("v{}", zk_context.compiler_version.zksolc);
+                    let optimization_used = source.settings.optimizer.enabled.unwrap_or(false);
+                    (
+                        serde_json::to_string(&source)?,
+                        contract_name,
+                        solc_version,
+                        zk_compiler_version,
+                        optimization_used,
+                    )
+                }
+                CompilerVerificationContext::ZkVyper(zk_context) => {
+                    let (input, contract_name) = zk_context.standard_json_input()?;
+                    // Vyper compiler versions are prefixed with the language, as for etherscan,
+                    // and the zkvyper version is sent in the `zksolcVersion` field.
+                    let vyper_version = format!("vyper:{}", zk_context.compiler_version.vyper);
+                    let zk_compiler_version = format!("v{}", zk_context.compiler_version.zkvyper);
+                    let optimization_used = input.settings.optimizer.mode.is_some();
+                    (
+                        serde_json::to_string(&input)?,
+                        contract_name,
+                        vyper_version,
+                        zk_compiler_version,
+                        optimization_used,
+                    )
+                }
+                CompilerVerificationContext::Solc(_) => {
+                    eyre::bail!(
+                        "Unsupported compiler context: only ZkSolc and ZkVyper are supported"
+                    );
+                }
+            };
         let _runs = args.num_of_optimizations.map(|n| n.to_string());
         let constructor_args = self.constructor_args(args, context).await?;
 
         let request = EtherscanVerificationRequest {
             contract_address: H160::from_slice(args.address.as_slice()),
-            source_code: serde_json::to_string(&source)?,
+            source_code,
             contract_name,
-            compiler_version: solc_version,
+            compiler_version,
             zksolc_version: Some(zk_compiler_version),
             constructor_arguments: constructor_args.unwrap_or_else(String::new),
             optimization_used: if optimization_used {
@@ -54,6 +54,9 @@ pub enum OutputSelectionFlag {
     /// The EVM legacy assembly JSON.
     #[serde(rename = "evm.legacyAssembly")]
     EVMLA,
+    /// The EraVM assembly.
+    #[serde(rename = "eravm.assembly")]
+    EraVMAssembly,
 }
 
 impl std::fmt::Display for OutputSelectionFlag {
@@ -68,6 +71,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Self::AST => write!(f, "ast"),
             Self::Yul => write!(f, "irOptimized"),
             Self::EVMLA => write!(f, "evm.legacyAssembly"),
+            Self::EraVMAssembly => write!(f, "eravm.assembly"),
         }
     }
 }
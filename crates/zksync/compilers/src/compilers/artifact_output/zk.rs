@@ -17,7 +17,7 @@
 };
 
 mod bytecode;
-pub use bytecode::ZkArtifactBytecode;
+pub use bytecode::{ERAVM_BYTECODE_WORDS_LIMIT, ZkArtifactBytecode};
 
 /// Artifact representing a compiled contract
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
@@ -18,6 +18,12 @@ pub fn serialize_bytes_without_prefix<S>(code: &Bytes, s: S) -> Result<S::Ok, S:
     s.serialize_str(&alloy_primitives::hex::encode(code))
 }
 
+/// Size limit of an EraVM bytecode, in 32-byte words.
+///
+/// Bytecodes must be strictly smaller than this, as their length in words is encoded in 2 bytes
+/// of the bytecode hash.
+pub const ERAVM_BYTECODE_WORDS_LIMIT: usize = 1 << 16;
+
 /// Bytecode compiled by zksolc
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct ZkArtifactBytecode {
@@ -51,6 +57,21 @@ pub fn is_unlinked(&self) -> bool {
         self.object_format.is_unlinked()
     }
 
+    /// Returns the size of the bytecode, in bytes
+    pub fn size(&self) -> usize {
+        self.object.len()
+    }
+
+    /// Returns the size of the bytecode, in 32-byte words
+    pub fn size_in_words(&self) -> usize {
+        self.object.len().div_ceil(32)
+    }
+
+    /// Returns `true` if the bytecode does not fit the EraVM size limit
+    pub fn exceeds_size_limit(&self) -> bool {
+        self.size_in_words() >= ERAVM_BYTECODE_WORDS_LIMIT
+    }
+
     /// Get link references
     pub fn link_references(&self) -> BTreeMap<String, BTreeMap<String, Vec<Offsets>>> {
         Contract::missing_libs_to_link_references(self.missing_libraries.as_slice())
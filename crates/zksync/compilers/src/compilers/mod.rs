@@ -1,6 +1,7 @@
 //! `foundry-compilers` core trait implementations and overrides for ZK Sync
 pub mod artifact_output;
 pub mod zksolc;
+pub mod zkvyper;
 
 use std::path::{Path, PathBuf};
 
@@ -72,14 +72,14 @@ fn bin_runtime_ref(&self) -> Option<&BytecodeObject> {
 }
 
 #[derive(Debug, Clone, Serialize)]
-enum ZkSolcOS {
+pub(crate) enum ZkSolcOS {
     LinuxAMD64,
     LinuxARM64,
     MacAMD,
     MacARM,
 }
 
-fn get_operating_system() -> Result<ZkSolcOS> {
+pub(crate) fn get_operating_system() -> Result<ZkSolcOS> {
     match std::env::consts::OS {
         "linux" => match std::env::consts::ARCH {
             "aarch64" => Ok(ZkSolcOS::LinuxARM64),
@@ -111,6 +111,15 @@ fn get_solc_prefix(&self) -> &str {
             Self::MacARM => "solc-macosx-arm64-",
         }
     }
+
+    pub(crate) fn get_zkvyper_prefix(&self) -> &str {
+        match self {
+            Self::LinuxAMD64 => "zkvyper-linux-amd64-musl-",
+            Self::LinuxARM64 => "zkvyper-linux-arm64-musl-",
+            Self::MacAMD => "zkvyper-macosx-amd64-",
+            Self::MacARM => "zkvyper-macosx-arm64-",
+        }
+    }
 }
 
 /// ZkSolc compiler
@@ -502,7 +511,7 @@ pub fn compile_output(&self, input: &ZkSolcInput) -> Result<Vec<u8>> {
         compile_output(output)
     }
 
-    fn compilers_dir() -> Result<PathBuf> {
+    pub(crate) fn compilers_dir() -> Result<PathBuf> {
         let mut compilers_dir = dirs::home_dir()
             .ok_or(SolcError::msg("Could not build SolcManager - homedir not found"))?;
         compilers_dir.push(".zksync");
@@ -605,15 +614,15 @@ pub fn find_solc_installed_version(
     }
 }
 
-fn map_io_err(zksolc_path: &Path) -> impl FnOnce(std::io::Error) -> SolcError + '_ {
+pub(crate) fn map_io_err(zksolc_path: &Path) -> impl FnOnce(std::io::Error) -> SolcError + '_ {
     move |err| SolcError::io(err, zksolc_path)
 }
 
-fn compile_output(output: Output) -> Result<Vec<u8>> {
+pub(crate) fn compile_output(output: Output) -> Result<Vec<u8>> {
     if output.status.success() { Ok(output.stdout) } else { Err(SolcError::solc_output(&output)) }
 }
 
-fn version_from_output(output: Output) -> Result<Version> {
+pub(crate) fn version_from_output(output: Output) -> Result<Version> {
     if output.status.success() {
         let stdout = String::from_utf8_lossy(&output.stdout);
         let version = stdout
@@ -660,7 +669,7 @@ fn error_code(&self) -> Option<u64> {
     }
 }
 
-fn compiler_blocking_install(
+pub(crate) fn compiler_blocking_install(
     compiler_path: PathBuf,
     lock_path: PathBuf,
     download_url: &str,
@@ -751,7 +760,7 @@ fn drop(&mut self) {
 }
 
 /// Returns the lockfile to use for a specific file
-fn lock_file_path(compiler: &str, version: &str) -> PathBuf {
+pub(crate) fn lock_file_path(compiler: &str, version: &str) -> PathBuf {
     ZkSolc::compilers_dir()
         .expect("could not detect zksolc compilers directory")
         .join(format!(".lock-{compiler}-{version}"))
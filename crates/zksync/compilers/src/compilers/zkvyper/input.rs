@@ -0,0 +1,135 @@
+//! zkvyper input
+use super::settings::{ZkVyperInputSettings, ZkVyperSettings};
+use foundry_compilers::{
+    artifacts::{Source, Sources},
+    compilers::{CompilerInput, vyper::VyperLanguage},
+};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
+
+/// Extension of Vyper interface files, passed to zkvyper separately from the sources.
+const VYPER_INTERFACE_EXTENSION: &str = "vyi";
+
+/// Versioned input for zkvyper
+#[derive(Debug, Clone, Serialize)]
+pub struct ZkVyperVersionedInput {
+    /// zkvyper json input
+    #[serde(flatten)]
+    pub input: ZkVyperInput,
+    /// vyper version to be used along zkvyper
+    pub vyper_version: Version,
+    /// zkvyper version
+    pub zkvyper_version: Version,
+    /// zkvyper binary path
+    pub zkvyper_path: PathBuf,
+    /// Base path imports are resolved against
+    pub base_path: Option<PathBuf>,
+}
+
+impl CompilerInput for ZkVyperVersionedInput {
+    type Settings = ZkVyperSettings;
+    type Language = VyperLanguage;
+
+    // NOTE: as for zksolc, version is the vyper version and NOT the zkvyper version
+    fn build(
+        sources: Sources,
+        settings: Self::Settings,
+        _language: Self::Language,
+        version: Version,
+    ) -> Self {
+        let zkvyper_path = settings.zkvyper_path();
+        let zkvyper_version = settings.zkvyper_version_ref().clone();
+        let ZkVyperSettings { settings, base_path, .. } = settings;
+        let input = ZkVyperInput::new(sources, settings);
+
+        Self { input, vyper_version: version, zkvyper_version, zkvyper_path, base_path }
+    }
+
+    fn language(&self) -> Self::Language {
+        VyperLanguage
+    }
+
+    fn version(&self) -> &Version {
+        &self.vyper_version
+    }
+
+    fn sources(&self) -> impl Iterator<Item = (&Path, &Source)> {
+        self.input
+            .sources
+            .iter()
+            .chain(self.input.interfaces.iter())
+            .map(|(path, source)| (path.as_path(), source))
+    }
+
+    fn compiler_name(&self) -> Cow<'static, str> {
+        "zkvyper and vyper".into()
+    }
+
+    fn strip_prefix(&mut self, base: &Path) {
+        self.input.strip_prefix(base);
+    }
+}
+
+/// Input type `zkvyper` expects.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkVyperInput {
+    /// source code language, always `Vyper`
+    pub language: String,
+    /// sources to compile
+    pub sources: Sources,
+    /// interface files imported by the sources
+    #[serde(default, skip_serializing_if = "Sources::is_empty")]
+    pub interfaces: Sources,
+    /// compiler settings set by the user
+    pub settings: ZkVyperInputSettings,
+}
+
+impl ZkVyperInput {
+    /// Creates a new input, splitting interface files from the sources to compile.
+    pub fn new(sources: Sources, settings: ZkVyperInputSettings) -> Self {
+        let (interfaces, sources) = sources.into_iter().partition(|(path, _)| {
+            path.extension().is_some_and(|ext| ext == VYPER_INTERFACE_EXTENSION)
+        });
+
+        Self { language: "Vyper".to_string(), sources, interfaces, settings }
+    }
+
+    /// Removes the `base` path from all source files
+    pub fn strip_prefix(&mut self, base: impl AsRef<Path>) {
+        let base = base.as_ref();
+        let strip = |sources: Sources| -> Sources {
+            sources
+                .into_iter()
+                .map(|(path, s)| (path.strip_prefix(base).map(Into::into).unwrap_or(path), s))
+                .collect()
+        };
+        self.sources = strip(std::mem::take(&mut self.sources));
+        self.interfaces = strip(std::mem::take(&mut self.interfaces));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zkvyper_input_splits_interfaces() {
+        let sources = Sources::from([
+            (PathBuf::from("src/Counter.vy"), Source::new("")),
+            (PathBuf::from("src/ICounter.vyi"), Source::new("")),
+        ]);
+
+        let input = ZkVyperInput::new(sources, Default::default());
+
+        assert_eq!(input.language, "Vyper");
+        assert!(input.sources.contains_key(Path::new("src/Counter.vy")));
+        assert!(input.interfaces.contains_key(Path::new("src/ICounter.vyi")));
+        assert_eq!(input.sources.len(), 1);
+        assert_eq!(input.interfaces.len(), 1);
+    }
+}
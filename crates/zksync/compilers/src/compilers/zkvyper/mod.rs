@@ -0,0 +1,231 @@
+//! foundry-compilers trait implementations for zkvyper
+use self::input::{ZkVyperInput, ZkVyperVersionedInput};
+use super::zksolc::{
+    ZkSolc, compile_output, compiler_blocking_install, get_operating_system, lock_file_path,
+    map_io_err, version_from_output,
+};
+use crate::artifacts::{CompilerOutput as ZkCompilerOutput, contract::Contract, error::Error};
+use foundry_compilers::{
+    Compiler, CompilerOutput, CompilerVersion,
+    compilers::vyper::{Vyper, VyperLanguage, parser::VyperParser},
+    error::{Result, SolcError},
+};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs::create_dir_all,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+use tracing::{debug, instrument, trace};
+
+pub mod input;
+pub mod settings;
+pub use settings::{ZkVyperInputSettings, ZkVyperSettings};
+
+/// ZkVyper compiler
+#[derive(Debug, Clone)]
+pub struct ZkVyperCompiler {
+    /// vyper compiler to use along zkvyper
+    pub vyper: Vyper,
+}
+
+impl Compiler for ZkVyperCompiler {
+    type Input = ZkVyperVersionedInput;
+    type CompilationError = Error;
+    type CompilerContract = Contract;
+    type Parser = VyperParser;
+    type Settings = ZkVyperSettings;
+    type Language = VyperLanguage;
+
+    fn compile(
+        &self,
+        input: &Self::Input,
+    ) -> Result<CompilerOutput<Self::CompilationError, Self::CompilerContract>> {
+        let mut zkvyper = ZkVyper::new(input.zkvyper_path.clone(), self.vyper.path.clone());
+        zkvyper.base_path.clone_from(&input.base_path);
+
+        let mut zk_output = zkvyper.compile(&input.input)?;
+
+        let mut metadata = BTreeMap::new();
+        metadata.insert("vyperVersion".to_string(), self.vyper.version.to_string().into());
+        if let Some(zk_version) = zk_output.zk_version.take() {
+            metadata.insert("zkvyperVersion".to_string(), zk_version.into());
+        }
+
+        Ok(CompilerOutput {
+            sources: zk_output.sources,
+            errors: zk_output.errors,
+            contracts: zk_output.contracts,
+            metadata,
+        })
+    }
+
+    // NOTE: As for zksolc, this is used to match source files to a compiler version so the vyper
+    // version is returned
+    fn available_versions(&self, _language: &Self::Language) -> Vec<CompilerVersion> {
+        vec![CompilerVersion::Installed(self.vyper.version.clone())]
+    }
+}
+
+/// Abstraction over `zkvyper` command line utility
+///
+/// By default the zkvyper path is configured as follows, with descending priority:
+///   1. `zksync.zkvyper` config value
+///   2. latest supported version, installed to the ZKsync compilers directory
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ZkVyper {
+    /// Path to the `zkvyper` executable
+    pub zkvyper: PathBuf,
+    /// Value for --vyper arg
+    pub vyper: PathBuf,
+    /// Directory the compiler is run from, imports are resolved against it
+    pub base_path: Option<PathBuf>,
+}
+
+impl ZkVyper {
+    /// A new instance which points to `zkvyper`
+    pub fn new(path: PathBuf, vyper: PathBuf) -> Self {
+        Self { zkvyper: path, vyper, base_path: None }
+    }
+
+    /// Get zkvyper path for a given version
+    pub fn get_path_for_version(version: &Version) -> Result<PathBuf> {
+        let maybe_zkvyper = Self::find_installed_version(version)?;
+
+        let path = if let Some(zkvyper) = maybe_zkvyper {
+            zkvyper
+        } else {
+            Self::blocking_install(version)?
+        };
+
+        Ok(path)
+    }
+
+    /// Invokes `zkvyper --version` and parses the output as a SemVer [`Version`].
+    pub fn get_version_for_path(path: &Path) -> Result<Version> {
+        let mut cmd = Command::new(path);
+        cmd.arg("--version").stdin(Stdio::piped()).stderr(Stdio::piped()).stdout(Stdio::piped());
+        debug!(?cmd, "getting ZkVyper version");
+        let output = cmd.output().map_err(map_io_err(path))?;
+        trace!(?output);
+        let version = version_from_output(output)?;
+        debug!(%version);
+        Ok(version)
+    }
+
+    /// Compiles with `--standard-json` and deserializes the output as [`CompilerOutput`].
+    pub fn compile(&self, input: &ZkVyperInput) -> Result<ZkCompilerOutput> {
+        let output = self.compile_output(input)?;
+        // Only run UTF-8 validation once.
+        let output = std::str::from_utf8(&output).map_err(|_| SolcError::InvalidUtf8)?;
+
+        Ok(serde_json::from_str(output)?)
+    }
+
+    /// Compiles with `--standard-json` and returns the raw `stdout` output.
+    #[instrument(name = "compile", level = "debug", skip_all)]
+    pub fn compile_output(&self, input: &ZkVyperInput) -> Result<Vec<u8>> {
+        let mut cmd = Command::new(&self.zkvyper);
+
+        if let Some(base_path) = &self.base_path {
+            cmd.current_dir(base_path);
+        }
+
+        cmd.arg("--vyper").arg(&self.vyper);
+        cmd.arg("--standard-json");
+        cmd.stdin(Stdio::piped()).stderr(Stdio::piped()).stdout(Stdio::piped());
+
+        trace!(input=%serde_json::to_string(input).unwrap_or_else(|e| e.to_string()));
+        debug!(?cmd, "compiling");
+
+        let mut child = cmd.spawn().map_err(map_io_err(&self.zkvyper))?;
+        debug!("spawned");
+
+        let stdin = child.stdin.as_mut().unwrap();
+        serde_json::to_writer(stdin, input)?;
+        debug!("wrote JSON input to stdin");
+
+        let output = child.wait_with_output().map_err(map_io_err(&self.zkvyper))?;
+        debug!(%output.status, output.stderr = ?String::from_utf8_lossy(&output.stderr), "finished");
+
+        compile_output(output)
+    }
+
+    /// Get supported zkvyper versions
+    pub fn zkvyper_supported_versions() -> Vec<Version> {
+        let mut ret = vec![];
+        let version_ranges = vec![(1, 5, 7..=11)];
+
+        for (major, minor, patch_range) in version_ranges {
+            for patch in patch_range {
+                ret.push(Version::new(major, minor, patch));
+            }
+        }
+
+        ret
+    }
+
+    /// Get zkvyper minimum supported version
+    pub fn zkvyper_minimum_supported_version() -> Version {
+        Self::zkvyper_supported_versions().remove(0)
+    }
+
+    /// Get zkvyper latest supported version
+    pub fn zkvyper_latest_supported_version() -> Version {
+        Self::zkvyper_supported_versions().pop().expect("No supported zkvyper versions")
+    }
+
+    fn compiler_path(version: &Version) -> Result<PathBuf> {
+        let os = get_operating_system()?;
+        Ok(ZkSolc::compilers_dir()?.join(format!("{}v{}", os.get_zkvyper_prefix(), version)))
+    }
+
+    /// Install zkvyper version and block the thread
+    pub fn blocking_install(version: &Version) -> Result<PathBuf> {
+        let os = get_operating_system()?;
+        let compiler_prefix = os.get_zkvyper_prefix();
+        let download_url = format!(
+            "https://github.com/matter-labs/zkvyper-bin/releases/download/v{version}/{compiler_prefix}v{version}",
+        );
+        let compilers_dir = ZkSolc::compilers_dir()?;
+        if !compilers_dir.exists() {
+            create_dir_all(compilers_dir)
+                .map_err(|e| SolcError::msg(format!("Could not create compilers path: {e}")))?;
+        }
+        let compiler_path = Self::compiler_path(version)?;
+        let lock_path = lock_file_path("zkvyper", &version.to_string());
+
+        let label = format!("zkvyper-{version}");
+        compiler_blocking_install(compiler_path, lock_path, &download_url, &label)
+    }
+
+    /// Get path for installed zkvyper version. Returns `Ok(None)` if not installed
+    pub fn find_installed_version(version: &Version) -> Result<Option<PathBuf>> {
+        let zkvyper = Self::compiler_path(version)?;
+
+        if !zkvyper.is_file() {
+            return Ok(None);
+        }
+        Ok(Some(zkvyper))
+    }
+}
+
+impl AsRef<Path> for ZkVyper {
+    fn as_ref(&self) -> &Path {
+        &self.zkvyper
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zkvyper_version_works() {
+        let version = ZkVyper::zkvyper_latest_supported_version();
+        let zkvyper_path = ZkVyper::get_path_for_version(&version).unwrap();
+        assert_eq!(ZkVyper::get_version_for_path(&zkvyper_path).unwrap(), version);
+    }
+}
@@ -0,0 +1,125 @@
+//! zkvyper settings
+use crate::artifacts::output_selection::OutputSelection as ZkOutputSelection;
+use foundry_compilers::{
+    CompilerSettingsRestrictions,
+    artifacts::{EvmVersion, output_selection::OutputSelection, vyper::VyperOptimizationMode},
+    compilers::CompilerSettings,
+    error::Result,
+};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use super::ZkVyper;
+
+/// zkvyper standard json input settings.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkVyperInputSettings {
+    /// The EVM version `vyper` targets before the code is translated to EraVM.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm_version: Option<EvmVersion>,
+    /// The `vyper` optimization mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optimize: Option<VyperOptimizationMode>,
+    /// The output selection.
+    #[serde(default)]
+    pub output_selection: ZkOutputSelection,
+    /// The EraVM optimizer settings.
+    #[serde(default)]
+    pub optimizer: ZkVyperOptimizer,
+    /// Extra LLVM options.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub llvm_options: Vec<String>,
+}
+
+/// zkvyper EraVM optimizer settings.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkVyperOptimizer {
+    /// The optimization mode string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<char>,
+    /// Whether to try to recompile with -Oz if the bytecode is too large.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_fallback: Option<bool>,
+}
+
+/// Analogous to `VyperSettings` for the zkvyper compiler
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkVyperSettings {
+    /// JSON settings expected by zkvyper
+    #[serde(flatten)]
+    pub settings: ZkVyperInputSettings,
+    /// Base path imports are resolved against, set from the project root
+    #[serde(skip)]
+    pub base_path: Option<PathBuf>,
+    /// The version of the zkvyper compiler to use. Retrieved from `zkvyper_path`
+    zkvyper_version: Version,
+    /// zkvyper path
+    zkvyper_path: PathBuf,
+}
+
+impl Default for ZkVyperSettings {
+    fn default() -> Self {
+        let version = ZkVyper::zkvyper_latest_supported_version();
+        let zkvyper_path = ZkVyper::get_path_for_version(&version)
+            .expect("failed getting default zkvyper version path");
+        Self {
+            settings: Default::default(),
+            base_path: None,
+            zkvyper_version: version,
+            zkvyper_path,
+        }
+    }
+}
+
+impl ZkVyperSettings {
+    /// Initialize settings for a given zkvyper path
+    pub fn new_from_path(settings: ZkVyperInputSettings, zkvyper_path: PathBuf) -> Result<Self> {
+        let zkvyper_version = ZkVyper::get_version_for_path(&zkvyper_path)?;
+        Ok(Self { settings, base_path: None, zkvyper_path, zkvyper_version })
+    }
+
+    /// Get zkvyper path
+    pub fn zkvyper_path(&self) -> PathBuf {
+        self.zkvyper_path.clone()
+    }
+
+    /// Get zkvyper version
+    pub fn zkvyper_version_ref(&self) -> &Version {
+        &self.zkvyper_version
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+/// Restrictions for zkvyper
+pub struct ZkVyperRestrictions();
+
+impl CompilerSettingsRestrictions for ZkVyperRestrictions {
+    fn merge(self, _other: Self) -> Option<Self> {
+        None
+    }
+}
+
+impl CompilerSettings for ZkVyperSettings {
+    type Restrictions = ZkVyperRestrictions;
+
+    fn update_output_selection(&mut self, _f: impl FnOnce(&mut OutputSelection)) {
+        // The zk output selection is set from the config, noop as for zksolc
+    }
+
+    fn can_use_cached(&self, other: &Self) -> bool {
+        self.settings == other.settings && self.zkvyper_version == other.zkvyper_version
+    }
+
+    fn with_base_path(mut self, base_path: &Path) -> Self {
+        self.base_path = Some(base_path.to_path_buf());
+        self
+    }
+
+    fn satisfies_restrictions(&self, _restrictions: &Self::Restrictions) -> bool {
+        true
+    }
+}
@@ -6,7 +6,7 @@
 };
 
 use foundry_compilers::{
-    Artifact, ArtifactId, ProjectCompileOutput, ProjectPathsConfig, artifacts::Offsets,
+    Artifact, ArtifactId, Compiler, ProjectCompileOutput, ProjectPathsConfig, artifacts::Offsets,
     info::ContractInfo, solc::SolcLanguage,
 };
 
@@ -14,7 +14,12 @@
 use tracing::debug;
 use zksync_types::H256;
 
-use crate::compilers::{artifact_output::zk::ZkArtifactOutput, zksolc::ZkSolcCompiler};
+use crate::{
+    artifacts::contract::Contract,
+    compilers::{
+        artifact_output::zk::ZkArtifactOutput, zksolc::ZkSolcCompiler, zkvyper::ZkVyperCompiler,
+    },
+};
 
 /// Represents the type of contract (ZK or EVM)
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -101,7 +106,34 @@ pub fn new(
         layout: &ProjectPathsConfig,
         zk_layout: &ProjectPathsConfig<SolcLanguage>,
     ) -> Self {
-        let mut dual_compiled_contracts = HashMap::new();
+        let mut dual_compiled_contracts = Self {
+            contracts: HashMap::new(),
+            zk_artifact_path: zk_layout.artifacts.clone(),
+            evm_artifact_path: layout.artifacts.clone(),
+        };
+        dual_compiled_contracts.insert_compiled(output, zk_output);
+        dual_compiled_contracts
+    }
+
+    /// Adds the Vyper contracts compiled with zkvyper, matched against the vyper artifacts of
+    /// the provided `output`.
+    pub fn extend_with_zkvyper(
+        &mut self,
+        output: &ProjectCompileOutput,
+        zk_output: &ProjectCompileOutput<ZkVyperCompiler, ZkArtifactOutput>,
+    ) {
+        self.insert_compiled(output, zk_output);
+    }
+
+    /// Pairs the zk artifacts of `zk_output` with their EVM counterpart in `output`.
+    fn insert_compiled<C>(
+        &mut self,
+        output: &ProjectCompileOutput,
+        zk_output: &ProjectCompileOutput<C, ZkArtifactOutput>,
+    ) where
+        C: Compiler<CompilerContract = Contract>,
+    {
+        let dual_compiled_contracts = &mut self.contracts;
         let mut solc_bytecodes = HashMap::new();
 
         let output_artifacts = output.artifact_ids().map(|(id, artifact)| {
@@ -197,12 +229,6 @@ pub fn new(
                 }
             }
         }
-
-        Self {
-            contracts: dual_compiled_contracts,
-            zk_artifact_path: zk_layout.artifacts.clone(),
-            evm_artifact_path: layout.artifacts.clone(),
-        }
     }
 
     /// Finds a contract matching the ZK deployed bytecode
@@ -0,0 +1,505 @@
+//! Bridging between L1 and a ZKsync chain.
+//!
+//! Deposits are sent to L1 as priority transactions through the bridgehub, withdrawals are
+//! initiated on L2 through the base token system contract and finalized on L1 through the shared
+//! bridge, with the proof of the L2 to L1 message obtained from `zks_getL2ToL1LogProof`.
+
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::{Address, B256, Bytes, U64, U256, address};
+use alloy_provider::Provider;
+use alloy_sol_types::{SolCall, SolEvent, SolValue, sol};
+use alloy_zksync::network::Zksync;
+use eyre::{OptionExt, Result, bail, ensure, eyre};
+use serde::{Deserialize, Serialize};
+
+use crate::{L2_BASE_TOKEN_ADDRESS, convert::ConvertH160};
+
+/// The gas per pubdata byte limit that L1 to L2 transactions must be sent with.
+pub const REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_LIMIT: u64 = 800;
+
+/// Offset added to the address of an L1 contract to get its alias on L2.
+const L1_TO_L2_ALIAS_OFFSET: Address = address!("1111000000000000000000000000000000001111");
+
+/// Address of the L1 messenger system contract.
+const L1_MESSENGER_ADDRESS: Address = address!("0000000000000000000000000000000000008008");
+
+/// Address used by the bridgehub for ETH as a base token.
+const ETH_TOKEN_ADDRESS: Address = address!("0000000000000000000000000000000000000001");
+
+sol! {
+    struct L2TransactionRequestDirect {
+        uint256 chainId;
+        uint256 mintValue;
+        address l2Contract;
+        uint256 l2Value;
+        bytes l2Calldata;
+        uint256 l2GasLimit;
+        uint256 l2GasPerPubdataByteLimit;
+        bytes[] factoryDeps;
+        address refundRecipient;
+    }
+
+    interface IBridgehub {
+        function requestL2TransactionDirect(L2TransactionRequestDirect calldata request)
+            external
+            payable
+            returns (bytes32 canonicalTxHash);
+
+        function l2TransactionBaseCost(
+            uint256 chainId,
+            uint256 gasPrice,
+            uint256 l2GasLimit,
+            uint256 l2GasPerPubdataByteLimit
+        ) external view returns (uint256);
+
+        function baseToken(uint256 chainId) external view returns (address);
+    }
+
+    interface IL1SharedBridge {
+        function finalizeWithdrawal(
+            uint256 chainId,
+            uint256 l2BatchNumber,
+            uint256 l2MessageIndex,
+            uint16 l2TxNumberInBatch,
+            bytes calldata message,
+            bytes32[] calldata merkleProof
+        ) external;
+    }
+
+    interface IL2BaseToken {
+        function withdraw(address l1Receiver) external payable;
+    }
+
+    interface IL1Messenger {
+        event L1MessageSent(address indexed sender, bytes32 indexed hash, bytes message);
+    }
+}
+
+/// A call that moves funds across the bridge, to be sent on L1 for deposits and withdrawal
+/// finalizations, or on L2 for withdrawals.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BridgeTransaction {
+    /// The called contract.
+    pub to: Address,
+    /// The value sent with the call.
+    pub value: U256,
+    /// The calldata.
+    pub data: Bytes,
+    /// The gas price the transaction must be sent with, if its value depends on it.
+    pub gas_price: Option<u128>,
+}
+
+/// A deposit of the base token from L1 to L2.
+#[derive(Clone, Debug)]
+pub struct DepositRequest {
+    /// The L1 sender of the deposit.
+    pub from: Address,
+    /// The L2 recipient of the deposit.
+    pub to: Address,
+    /// The deposited amount.
+    pub amount: U256,
+    /// The L2 gas limit of the priority transaction, estimated if not set.
+    pub l2_gas_limit: Option<U256>,
+    /// The L2 account refunded with the unused fee, the sender if not set.
+    pub refund_recipient: Option<Address>,
+}
+
+/// Returns the L2 alias of the L1 contract `l1_address`, which is the sender of the priority
+/// transactions it requests.
+///
+/// See <https://github.com/matter-labs/era-contracts/blob/main/l1-contracts/contracts/vendor/AddressAliasHelper.sol>
+pub fn apply_l1_to_l2_alias(l1_address: Address) -> Address {
+    let offset = U256::from_be_slice(L1_TO_L2_ALIAS_OFFSET.as_slice());
+    let aliased = U256::from_be_slice(l1_address.as_slice()).wrapping_add(offset);
+    Address::from_word(aliased.into())
+}
+
+/// Builds the L1 transaction that deposits the base token to L2 through the bridgehub.
+///
+/// The base cost of the priority transaction is computed with a 50% margin over the current L1
+/// gas price, so that the deposit doesn't fail if the gas price rises before it is included. The
+/// transaction must be sent with the same gas price, as the bridgehub checks the base cost against
+/// it. The unused part of the fee is refunded on L2.
+pub async fn deposit_transaction<N: Network>(
+    l1_provider: &impl Provider<N>,
+    l2_provider: &impl Provider<Zksync>,
+    request: &DepositRequest,
+) -> Result<BridgeTransaction> {
+    let chain_id = U256::from(l2_provider.get_chain_id().await?);
+    let bridgehub: Option<Address> =
+        l2_provider.raw_request("zks_getBridgehubContract".into(), ()).await?;
+    let bridgehub = bridgehub.ok_or_eyre("the L2 node did not return a bridgehub address")?;
+
+    let base_token = IBridgehub::baseTokenCall::abi_decode_returns(
+        &call(l1_provider, bridgehub, IBridgehub::baseTokenCall { chainId: chain_id }).await?,
+    )?;
+    ensure!(
+        base_token == ETH_TOKEN_ADDRESS,
+        "only chains with ETH as base token are supported, chain {chain_id} uses {base_token}"
+    );
+
+    let l2_gas_limit = match request.l2_gas_limit {
+        Some(l2_gas_limit) => l2_gas_limit,
+        None => {
+            let estimate_request = L1ToL2CallRequest {
+                from: request.from,
+                to: request.to,
+                value: request.amount,
+                data: Bytes::new(),
+                eip712_meta: Eip712Meta {
+                    gas_per_pubdata: U256::from(REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_LIMIT),
+                },
+            };
+            l2_provider.raw_request("zks_estimateGasL1ToL2".into(), (estimate_request,)).await?
+        }
+    };
+
+    let gas_price = l1_provider.get_gas_price().await? * 3 / 2;
+    let base_cost = IBridgehub::l2TransactionBaseCostCall::abi_decode_returns(
+        &call(
+            l1_provider,
+            bridgehub,
+            IBridgehub::l2TransactionBaseCostCall {
+                chainId: chain_id,
+                gasPrice: U256::from(gas_price),
+                l2GasLimit: l2_gas_limit,
+                l2GasPerPubdataByteLimit: U256::from(REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_LIMIT),
+            },
+        )
+        .await?,
+    )?;
+
+    let mint_value = base_cost + request.amount;
+    let data = IBridgehub::requestL2TransactionDirectCall {
+        request: L2TransactionRequestDirect {
+            chainId: chain_id,
+            mintValue: mint_value,
+            l2Contract: request.to,
+            l2Value: request.amount,
+            l2Calldata: Bytes::new(),
+            l2GasLimit: l2_gas_limit,
+            l2GasPerPubdataByteLimit: U256::from(REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_LIMIT),
+            factoryDeps: vec![],
+            refundRecipient: request.refund_recipient.unwrap_or(request.from),
+        },
+    }
+    .abi_encode();
+
+    Ok(BridgeTransaction {
+        to: bridgehub,
+        value: mint_value,
+        data: data.into(),
+        gas_price: Some(gas_price),
+    })
+}
+
+/// Builds the L2 transaction that withdraws `amount` of the base token to `to` on L1.
+pub fn withdraw_transaction(to: Address, amount: U256) -> BridgeTransaction {
+    BridgeTransaction {
+        to: L2_BASE_TOKEN_ADDRESS.to_address(),
+        value: amount,
+        data: IL2BaseToken::withdrawCall { l1Receiver: to }.abi_encode().into(),
+        gas_price: None,
+    }
+}
+
+/// Builds the L1 transaction that finalizes the `index`-th withdrawal initiated by the L2
+/// transaction `tx_hash`.
+///
+/// Fails if the L1 batch that includes the transaction has not been executed on L1 yet.
+pub async fn finalize_withdrawal_transaction(
+    l2_provider: &impl Provider<Zksync>,
+    tx_hash: B256,
+    index: usize,
+) -> Result<BridgeTransaction> {
+    let receipt: Option<WithdrawalReceipt> =
+        l2_provider.raw_request("eth_getTransactionReceipt".into(), (tx_hash,)).await?;
+    let receipt = receipt.ok_or_else(|| eyre!("withdrawal transaction {tx_hash} not found"))?;
+    let (Some(l1_batch_number), Some(l1_batch_tx_index)) =
+        (receipt.l1_batch_number, receipt.l1_batch_tx_index)
+    else {
+        bail!("withdrawal transaction {tx_hash} is not included in an L1 batch yet")
+    };
+
+    let log = receipt
+        .logs
+        .iter()
+        .filter(|log| {
+            log.address == L1_MESSENGER_ADDRESS
+                && log.topics.first() == Some(&IL1Messenger::L1MessageSent::SIGNATURE_HASH)
+        })
+        .nth(index)
+        .ok_or_else(|| eyre!("transaction {tx_hash} has no withdrawal with index {index}"))?;
+    let message = Bytes::abi_decode(&log.data)?;
+
+    let log_index = receipt
+        .l2_to_l1_logs
+        .iter()
+        .enumerate()
+        .filter(|(_, log)| log.sender == L1_MESSENGER_ADDRESS)
+        .nth(index)
+        .map(|(log_index, _)| log_index)
+        .ok_or_else(|| eyre!("transaction {tx_hash} has no L2 to L1 log with index {index}"))?;
+
+    let proof: Option<L2ToL1LogProof> =
+        l2_provider.raw_request("zks_getL2ToL1LogProof".into(), (tx_hash, log_index)).await?;
+    let proof = proof.ok_or_else(|| {
+        eyre!(
+            "the withdrawal proof is not available yet, L1 batch {l1_batch_number} has to be \
+             executed on L1 first"
+        )
+    })?;
+
+    let bridges: BridgeContracts =
+        l2_provider.raw_request("zks_getBridgeContracts".into(), ()).await?;
+    let shared_bridge = bridges
+        .l1_shared_default_bridge
+        .ok_or_eyre("the L2 node did not return an L1 shared bridge address")?;
+
+    let data = IL1SharedBridge::finalizeWithdrawalCall {
+        chainId: U256::from(l2_provider.get_chain_id().await?),
+        l2BatchNumber: U256::from(l1_batch_number),
+        l2MessageIndex: U256::from(proof.id),
+        l2TxNumberInBatch: u16::try_from(l1_batch_tx_index.to::<u64>())?,
+        message,
+        merkleProof: proof.proof,
+    }
+    .abi_encode();
+
+    Ok(BridgeTransaction {
+        to: shared_bridge,
+        value: U256::ZERO,
+        data: data.into(),
+        gas_price: None,
+    })
+}
+
+/// Performs an `eth_call` of `call` on `to`.
+async fn call<N: Network>(
+    provider: &impl Provider<N>,
+    to: Address,
+    call: impl SolCall,
+) -> Result<Bytes> {
+    let tx = N::TransactionRequest::default().with_to(to).with_input(call.abi_encode());
+    Ok(provider.call(tx).await?)
+}
+
+/// Request of `zks_estimateGasL1ToL2`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct L1ToL2CallRequest {
+    from: Address,
+    to: Address,
+    value: U256,
+    data: Bytes,
+    eip712_meta: Eip712Meta,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Eip712Meta {
+    gas_per_pubdata: U256,
+}
+
+/// The fields of a ZKsync transaction receipt needed to finalize a withdrawal.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WithdrawalReceipt {
+    l1_batch_number: Option<U64>,
+    l1_batch_tx_index: Option<U64>,
+    logs: Vec<ReceiptLog>,
+    l2_to_l1_logs: Vec<L2ToL1Log>,
+}
+
+#[derive(Deserialize)]
+struct ReceiptLog {
+    address: Address,
+    topics: Vec<B256>,
+    data: Bytes,
+}
+
+#[derive(Deserialize)]
+struct L2ToL1Log {
+    sender: Address,
+}
+
+/// Response of `zks_getL2ToL1LogProof`.
+#[derive(Deserialize)]
+struct L2ToL1LogProof {
+    id: u64,
+    proof: Vec<B256>,
+}
+
+/// Response of `zks_getBridgeContracts`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BridgeContracts {
+    l1_shared_default_bridge: Option<Address>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_network::Ethereum;
+    use alloy_provider::ProviderBuilder;
+    use alloy_transport::mock::Asserter;
+
+    fn mocked_provider<N: Network>(asserter: &Asserter) -> impl Provider<N> {
+        ProviderBuilder::<_, _, N>::default().connect_mocked_client(asserter.clone())
+    }
+
+    #[tokio::test]
+    async fn builds_deposit() {
+        let bridgehub = address!("00000000000000000000000000000000000000bb");
+        let from = address!("00000000000000000000000000000000000000aa");
+        let to = address!("00000000000000000000000000000000000000cc");
+
+        let l1 = Asserter::new();
+        l1.push_success(&Bytes::from(ETH_TOKEN_ADDRESS.abi_encode()));
+        l1.push_success(&U256::from(100));
+        l1.push_success(&Bytes::from(U256::from(1000).abi_encode()));
+        let l2 = Asserter::new();
+        l2.push_success(&U64::from(324));
+        l2.push_success(&Some(bridgehub));
+
+        let request = DepositRequest {
+            from,
+            to,
+            amount: U256::from(1),
+            l2_gas_limit: Some(U256::from(500_000)),
+            refund_recipient: None,
+        };
+        let tx = deposit_transaction(
+            &mocked_provider::<Ethereum>(&l1),
+            &mocked_provider::<Zksync>(&l2),
+            &request,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(tx.to, bridgehub);
+        assert_eq!(tx.value, U256::from(1001));
+        // the deposit is sent with the gas price its base cost is computed for
+        assert_eq!(tx.gas_price, Some(150));
+
+        let request =
+            IBridgehub::requestL2TransactionDirectCall::abi_decode(&tx.data).unwrap().request;
+        assert_eq!(request.chainId, U256::from(324));
+        assert_eq!(request.mintValue, U256::from(1001));
+        assert_eq!(request.l2Contract, to);
+        assert_eq!(request.l2Value, U256::from(1));
+        assert_eq!(request.l2GasLimit, U256::from(500_000));
+        assert_eq!(request.refundRecipient, from);
+    }
+
+    #[tokio::test]
+    async fn rejects_deposit_of_custom_base_token() {
+        let l1 = Asserter::new();
+        l1.push_success(&Bytes::from(
+            address!("00000000000000000000000000000000000000dd").abi_encode(),
+        ));
+        let l2 = Asserter::new();
+        l2.push_success(&U64::from(324));
+        l2.push_success(&Some(address!("00000000000000000000000000000000000000bb")));
+
+        let request = DepositRequest {
+            from: Address::ZERO,
+            to: Address::ZERO,
+            amount: U256::from(1),
+            l2_gas_limit: None,
+            refund_recipient: None,
+        };
+        let err = deposit_transaction(
+            &mocked_provider::<Ethereum>(&l1),
+            &mocked_provider::<Zksync>(&l2),
+            &request,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("only chains with ETH as base token"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn builds_withdrawal_finalization() {
+        let tx_hash = B256::repeat_byte(1);
+        let shared_bridge = address!("00000000000000000000000000000000000000bb");
+        let messages = [Bytes::from_static(b"first"), Bytes::from_static(b"second")];
+        let message_log = |message: &Bytes| {
+            serde_json::json!({
+                "address": L1_MESSENGER_ADDRESS,
+                "topics": [IL1Messenger::L1MessageSent::SIGNATURE_HASH, B256::ZERO, B256::ZERO],
+                "data": Bytes::from(message.abi_encode()),
+            })
+        };
+        let proof = vec![B256::repeat_byte(2), B256::repeat_byte(3)];
+
+        let l2 = Asserter::new();
+        l2.push_success(&serde_json::json!({
+            "l1BatchNumber": "0x5",
+            "l1BatchTxIndex": "0x2",
+            "logs": [message_log(&messages[0]), message_log(&messages[1])],
+            "l2ToL1Logs": [
+                { "sender": L2_BASE_TOKEN_ADDRESS.to_address() },
+                { "sender": L1_MESSENGER_ADDRESS },
+                { "sender": L1_MESSENGER_ADDRESS },
+            ],
+        }));
+        l2.push_success(&serde_json::json!({ "id": 7, "proof": proof }));
+        l2.push_success(&serde_json::json!({ "l1SharedDefaultBridge": shared_bridge }));
+        l2.push_success(&U64::from(324));
+
+        let tx = finalize_withdrawal_transaction(&mocked_provider::<Zksync>(&l2), tx_hash, 1)
+            .await
+            .unwrap();
+
+        assert_eq!(tx.to, shared_bridge);
+        assert_eq!(tx.value, U256::ZERO);
+        assert_eq!(tx.gas_price, None);
+
+        let call = IL1SharedBridge::finalizeWithdrawalCall::abi_decode(&tx.data).unwrap();
+        assert_eq!(call.chainId, U256::from(324));
+        assert_eq!(call.l2BatchNumber, U256::from(5));
+        assert_eq!(call.l2MessageIndex, U256::from(7));
+        assert_eq!(call.l2TxNumberInBatch, 2);
+        assert_eq!(call.message, messages[1]);
+        assert_eq!(call.merkleProof, proof);
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_withdrawal() {
+        let l2 = Asserter::new();
+        l2.push_success(&serde_json::json!({
+            "l1BatchNumber": "0x5",
+            "l1BatchTxIndex": "0x2",
+            "logs": [],
+            "l2ToL1Logs": [],
+        }));
+
+        let err = finalize_withdrawal_transaction(&mocked_provider::<Zksync>(&l2), B256::ZERO, 0)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("has no withdrawal with index 0"), "{err}");
+    }
+
+    #[test]
+    fn encodes_withdrawal() {
+        let to = address!("00000000000000000000000000000000000000aa");
+        let tx = withdraw_transaction(to, U256::from(1));
+
+        assert_eq!(tx.to, address!("000000000000000000000000000000000000800a"));
+        assert_eq!(tx.value, U256::from(1));
+        assert_eq!(IL2BaseToken::withdrawCall::abi_decode(&tx.data).unwrap().l1Receiver, to);
+    }
+
+    #[test]
+    fn applies_l1_to_l2_alias() {
+        assert_eq!(
+            apply_l1_to_l2_alias(address!("00000000000000000000000000000000000000aa")),
+            address!("11110000000000000000000000000000000011bb")
+        );
+        assert_eq!(
+            apply_l1_to_l2_alias(address!("ffffffffffffffffffffffffffffffffffffffff")),
+            address!("1111000000000000000000000000000000001110")
+        );
+    }
+}
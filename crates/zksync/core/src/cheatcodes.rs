@@ -10,8 +10,9 @@
 };
 use tracing::info;
 use zksync_types::{
-    ACCOUNT_CODE_STORAGE_ADDRESS, CURRENT_VIRTUAL_BLOCK_INFO_POSITION, KNOWN_CODES_STORAGE_ADDRESS,
-    L2_BASE_TOKEN_ADDRESS, NONCE_HOLDER_ADDRESS, SYSTEM_CONTEXT_ADDRESS,
+    ACCOUNT_CODE_STORAGE_ADDRESS, CURRENT_VIRTUAL_BLOCK_INFO_POSITION,
+    IMMUTABLE_SIMULATOR_STORAGE_ADDRESS, KNOWN_CODES_STORAGE_ADDRESS, L2_BASE_TOKEN_ADDRESS,
+    NONCE_HOLDER_ADDRESS, SYSTEM_CONTEXT_ADDRESS,
     block::{pack_block_info, unpack_block_info},
     get_nonce_key,
     utils::{decompose_full_nonce, storage_key_for_eth_balance},
@@ -20,7 +21,7 @@
 use crate::{
     EMPTY_CODE,
     convert::{ConvertAddress, ConvertH160, ConvertH256, ConvertRU256, ConvertU256},
-    hash_bytecode,
+    get_immutable_slot_key, hash_bytecode,
 };
 
 /// Sets `block.timestamp`.
@@ -130,6 +131,39 @@ pub fn get_full_nonce<DB>(address: Address, ecx: &mut EthEvmContext<DB>) -> (rU2
     (tx_nonce.to_ru256(), deploy_nonce.to_ru256())
 }
 
+/// Gets the immutable at `index` of a specific address, as stored in the `ImmutableSimulator`.
+pub fn get_immutable<DB>(address: Address, index: rU256, ecx: &mut EthEvmContext<DB>) -> B256
+where
+    DB: Database,
+    <DB as Database>::Error: Debug,
+{
+    info!(?address, ?index, "cheatcode zkGetImmutable");
+
+    let immutable_addr = IMMUTABLE_SIMULATOR_STORAGE_ADDRESS.to_address();
+    ecx.journaled_state.load_account(immutable_addr).expect("account could not be loaded");
+    let immutable_key = get_immutable_slot_key(address, index).to_ru256();
+    let value = ecx.journaled_state.sload(immutable_addr, immutable_key).unwrap_or_default();
+
+    B256::from(value.data.to_be_bytes())
+}
+
+/// Sets the immutable at `index` of a specific address, as stored in the `ImmutableSimulator`.
+pub fn set_immutable<DB>(address: Address, index: rU256, value: B256, ecx: &mut EthEvmContext<DB>)
+where
+    DB: Database,
+    <DB as Database>::Error: Debug,
+{
+    info!(?address, ?index, ?value, "cheatcode zkSetImmutable");
+
+    let immutable_addr = IMMUTABLE_SIMULATOR_STORAGE_ADDRESS.to_address();
+    ecx.journaled_state.load_account(immutable_addr).expect("account could not be loaded");
+    ecx.journaled_state.touch(immutable_addr);
+    let immutable_key = get_immutable_slot_key(address, index).to_ru256();
+    ecx.journaled_state
+        .sstore(immutable_addr, immutable_key, value.into())
+        .expect("failed storing value");
+}
+
 /// Sets code for a specific address.
 pub fn etch<DB>(address: Address, bytecode: &[u8], ecx: &mut EthEvmContext<DB>)
 where
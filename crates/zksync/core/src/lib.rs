@@ -3,6 +3,9 @@
 //! Main Foundry ZKSync implementation.
 #![warn(missing_docs)]
 
+/// Contains L1 <-> L2 bridging helpers.
+pub mod bridge;
+
 /// Contains cheatcode implementations.
 pub mod cheatcodes;
 
@@ -19,6 +22,7 @@
 pub mod state;
 
 use alloy_evm::eth::EthEvmContext;
+use alloy_json_abi::JsonAbi;
 use alloy_network::TransactionBuilder;
 use alloy_primitives::{Address, Bytes, U256 as rU256, address, hex, keccak256};
 use alloy_zksync::{
@@ -39,6 +43,7 @@
 pub use utils::MAX_L2_GAS_LIMIT;
 pub use vm::{SELECTOR_CONTRACT_DEPLOYER_CREATE, SELECTOR_CONTRACT_DEPLOYER_CREATE2};
 pub use zksync_multivm::interface::{Call, CallType};
+pub use zksync_types::zk_evm_types::FarCallOpcode;
 pub use zksync_types::{
     ACCOUNT_CODE_STORAGE_ADDRESS, CONTRACT_DEPLOYER_ADDRESS, H256,
     IMMUTABLE_SIMULATOR_STORAGE_ADDRESS, KNOWN_CODES_STORAGE_ADDRESS, L2_BASE_TOKEN_ADDRESS,
@@ -54,6 +59,22 @@
 /// Default ZKsync protocol version.
 pub const DEFAULT_PROTOCOL_VERSION: ProtocolVersionId = ProtocolVersionId::Version29;
 
+/// Oldest ZKsync protocol version the zkVM can be run with.
+pub const MIN_SUPPORTED_PROTOCOL_VERSION: ProtocolVersionId = ProtocolVersionId::Version26;
+
+/// Returns the [ProtocolVersionId] for `version`, if the zkVM can be run with it.
+pub fn protocol_version(version: u16) -> Result<ProtocolVersionId> {
+    let protocol_version = ProtocolVersionId::try_from(version).map_err(|err| eyre!(err))?;
+    if !(MIN_SUPPORTED_PROTOCOL_VERSION..=DEFAULT_PROTOCOL_VERSION).contains(&protocol_version) {
+        eyre::bail!(
+            "unsupported protocol version {version}, supported versions are {} to {}",
+            MIN_SUPPORTED_PROTOCOL_VERSION as u16,
+            DEFAULT_PROTOCOL_VERSION as u16
+        );
+    }
+    Ok(protocol_version)
+}
+
 /// Represents an empty code
 pub const EMPTY_CODE: [u8; 32] = [0; 32];
 
@@ -99,6 +120,16 @@ pub struct ZkPaymasterData {
     pub input: Bytes,
 }
 
+/// Represents a custom account that executes the next ZK transaction through the bootloader's
+/// account validation and payment flow.
+#[derive(Clone, Debug, Default)]
+pub struct ZkAccountData {
+    /// Account address.
+    pub address: Address,
+    /// Signature passed to the account's `validateTransaction`.
+    pub signature: Bytes,
+}
+
 /// Key used to set transaction metadata in other fields of [WithOtherFields<TransactionRequest>].
 /// This is used when broadcasting in a test, and when a script reads the broadcasted transactions.
 pub const ZKSYNC_TRANSACTION_OTHER_FIELDS_KEY: &str = "zksync";
@@ -159,6 +190,89 @@ pub fn is_system_address(address: Address) -> bool {
     address.to_h256().to_ru256().lt(&rU256::from(MIN_VALID_ADDRESS))
 }
 
+/// Returns labels for the system contracts, to identify them in EraVM call traces.
+pub fn system_contract_labels() -> Vec<(Address, String)> {
+    use zksync_types::{
+        BOOTLOADER_ADDRESS, BOOTLOADER_UTILITIES_ADDRESS, CODE_ORACLE_ADDRESS, COMPRESSOR_ADDRESS,
+        EVENT_WRITER_ADDRESS, KECCAK256_PRECOMPILE_ADDRESS, L1_MESSENGER_ADDRESS,
+        MSG_VALUE_SIMULATOR_ADDRESS, SYSTEM_CONTEXT_ADDRESS,
+    };
+
+    [
+        (BOOTLOADER_ADDRESS, "Bootloader"),
+        (ACCOUNT_CODE_STORAGE_ADDRESS, "AccountCodeStorage"),
+        (NONCE_HOLDER_ADDRESS, "NonceHolder"),
+        (KNOWN_CODES_STORAGE_ADDRESS, "KnownCodesStorage"),
+        (IMMUTABLE_SIMULATOR_STORAGE_ADDRESS, "ImmutableSimulator"),
+        (CONTRACT_DEPLOYER_ADDRESS, "ContractDeployer"),
+        (L1_MESSENGER_ADDRESS, "L1Messenger"),
+        (MSG_VALUE_SIMULATOR_ADDRESS, "MsgValueSimulator"),
+        (L2_BASE_TOKEN_ADDRESS, "L2BaseToken"),
+        (SYSTEM_CONTEXT_ADDRESS, "SystemContext"),
+        (BOOTLOADER_UTILITIES_ADDRESS, "BootloaderUtilities"),
+        (EVENT_WRITER_ADDRESS, "EventWriter"),
+        (COMPRESSOR_ADDRESS, "Compressor"),
+        (KECCAK256_PRECOMPILE_ADDRESS, "Keccak256"),
+        (CODE_ORACLE_ADDRESS, "CodeOracle"),
+    ]
+    .into_iter()
+    .map(|(address, label)| (address.to_address(), label.to_string()))
+    .collect()
+}
+
+/// Returns the ABI of the system contract functions called while processing a transaction, to
+/// decode them in EraVM call traces.
+pub fn system_contracts_abi() -> JsonAbi {
+    // Fields of the `Transaction` struct passed to accounts and paymasters.
+    const TRANSACTION: &str = "(uint256,uint256,uint256,uint256,uint256,uint256,uint256,uint256,\
+        uint256,uint256,uint256[4],bytes,bytes,bytes32[],bytes,bytes)";
+
+    let account_functions = [
+        format!("function validateTransaction(bytes32,bytes32,{TRANSACTION}) returns (bytes4)"),
+        format!("function executeTransaction(bytes32,bytes32,{TRANSACTION})"),
+        format!("function payForTransaction(bytes32,bytes32,{TRANSACTION})"),
+        format!("function prepareForPaymaster(bytes32,bytes32,{TRANSACTION})"),
+        format!(
+            "function validateAndPayForPaymasterTransaction(bytes32,bytes32,{TRANSACTION}) \
+             returns (bytes4,bytes)"
+        ),
+    ];
+    let system_functions = [
+        // ContractDeployer
+        "function create(bytes32,bytes32,bytes) returns (address)",
+        "function create2(bytes32,bytes32,bytes) returns (address)",
+        "function createAccount(bytes32,bytes32,bytes,uint8) returns (address)",
+        "function create2Account(bytes32,bytes32,bytes,uint8) returns (address)",
+        // NonceHolder
+        "function incrementMinNonceIfEquals(uint256)",
+        "function incrementDeploymentNonce(address) returns (uint256)",
+        "function getMinNonce(address) returns (uint256)",
+        "function getRawNonce(address) returns (uint256)",
+        "function validateNonceUsage(address,uint256,bool)",
+        // AccountCodeStorage
+        "function getRawCodeHash(address) returns (bytes32)",
+        "function getCodeHash(uint256) returns (bytes32)",
+        "function storeAccountConstructingCodeHash(address,bytes32)",
+        "function storeAccountConstructedCodeHash(address,bytes32)",
+        "function markAccountCodeHashAsConstructed(address)",
+        // KnownCodesStorage
+        "function markFactoryDeps(bool,bytes32[])",
+        "function getMarker(bytes32) returns (uint256)",
+        // ImmutableSimulator
+        "function setImmutables(address,(uint256,bytes32)[])",
+        "function getImmutable(address,uint256) returns (bytes32)",
+        // L2BaseToken
+        "function balanceOf(uint256) returns (uint256)",
+        "function transferFromTo(address,address,uint256)",
+        "function withdraw(address)",
+        // L1Messenger
+        "function sendToL1(bytes) returns (bytes32)",
+    ];
+
+    JsonAbi::parse(account_functions.iter().map(String::as_str).chain(system_functions))
+        .expect("invalid system contracts ABI")
+}
+
 /// Creates a safe address from the input address, by offsetting a reserved address
 /// byt [MIN_VALID_ADDRESS] so it is above the system reserved address space of 2^16.
 pub fn to_safe_address(address: Address) -> Address {
@@ -373,4 +487,22 @@ fn test_get_immutable_slot_key() {
                 .expect("invalid h256");
         assert_eq!(expected_key, actual_key)
     }
+
+    #[test]
+    fn test_protocol_version() {
+        assert_eq!(protocol_version(27).unwrap(), ProtocolVersionId::Version27);
+        assert_eq!(
+            protocol_version(DEFAULT_PROTOCOL_VERSION as u16).unwrap(),
+            DEFAULT_PROTOCOL_VERSION
+        );
+        assert!(protocol_version(MIN_SUPPORTED_PROTOCOL_VERSION as u16 - 1).is_err());
+        assert!(protocol_version(u16::MAX).is_err());
+    }
+
+    #[test]
+    fn test_system_contracts_abi() {
+        let abi = system_contracts_abi();
+        assert!(abi.function("create2").is_some());
+        assert_eq!(abi.function("validateTransaction").unwrap()[0].inputs.len(), 3);
+    }
 }
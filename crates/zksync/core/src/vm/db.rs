@@ -4,7 +4,7 @@
 /// in the Database object.
 /// This code doesn't do any mutatios to Database: after each transaction run, the Revm
 /// is usually collecting all the diffs - and applies them to database itself.
-use std::{collections::HashMap as sHashMap, fmt::Debug, sync::LazyLock};
+use std::{collections::HashMap as sHashMap, fmt::Debug, path::Path};
 
 use alloy_evm::eth::EthEvmContext;
 use alloy_primitives::{Address, U256 as rU256, map::HashMap};
@@ -13,61 +13,26 @@
 use revm::{Database, context::JournalTr};
 use zksync_basic_types::{H160, H256, L2ChainId, U256};
 use zksync_types::{
-    AccountTreeId, CREATE2_FACTORY_ADDRESS, StorageKey, StorageLog, StorageValue, get_code_key,
+    AccountTreeId, ProtocolVersionId, StorageKey, StorageLog, StorageValue, get_code_key,
     get_nonce_key, get_system_context_init_logs, h256_to_u256,
     utils::{decompose_full_nonce, storage_key_for_eth_balance},
 };
 use zksync_vm_interface::storage::ReadStorage;
 
 use crate::{
-    DEFAULT_PROTOCOL_VERSION,
     convert::{ConvertAddress, ConvertH160, ConvertH256, ConvertRU256, ConvertU256},
     hash_bytecode,
     state::FullNonce,
 };
-use anvil_zksync_core::deps::system_contracts::NON_KERNEL_CONTRACT_LOCATIONS;
 
-use super::storage_recorder::{AccountAccess, AccountAccesses, CallType, StorageAccessRecorder};
+use super::{
+    storage_recorder::{AccountAccess, AccountAccesses, CallType, StorageAccessRecorder},
+    system_contracts::deployed_system_contracts,
+};
 
 /// Default chain id
 pub(crate) const DEFAULT_CHAIN_ID: u32 = 31337;
 
-// NOTE: we use vec instead of hashmap because the loaded [BOOTLOADER] and [0 address] share the
-// same bytecode, thus they would share the same bytecode hash (key in map) resulting in the first
-// `DeployedContract` to be discarded from the resulting map. This is a problem when we compute the
-// override keys as the discarded contract won't have an associated generated override
-// TL;DR: we want to keep _all_ instances of `DeployedContract` even if they share the same bytecode
-// hash
-struct DeployedSystemContract {
-    deployed_contract: zksync_types::block::DeployedContract,
-    deployed_contract_hash: H256,
-}
-
-static DEPLOYED_SYSTEM_CONTRACTS: LazyLock<Vec<DeployedSystemContract>> = LazyLock::new(|| {
-    let contracts = anvil_zksync_core::deps::system_contracts::get_deployed_contracts(
-        anvil_zksync_config::types::SystemContractsOptions::BuiltInWithoutSecurity,
-        DEFAULT_PROTOCOL_VERSION,
-        None,
-    );
-
-    let filtered = contracts.into_iter().filter(|contract| {
-        let addr = contract.account_id.address();
-
-        if *addr == CREATE2_FACTORY_ADDRESS {
-            return true;
-        }
-
-        // Drop anything that matches a non-kernel contract location.
-        !NON_KERNEL_CONTRACT_LOCATIONS.iter().any(|(_name, a, _ver)| *a == *addr)
-    });
-
-    filtered
-        .map(|contract| DeployedSystemContract {
-            deployed_contract_hash: hash_bytecode(&contract.bytecode),
-            deployed_contract: contract,
-        })
-        .collect()
-});
 pub struct ZKVMData<'a, DB: Database> {
     ecx: &'a mut EthEvmContext<DB>,
     pub factory_deps: HashMap<H256, Vec<u8>>,
@@ -126,15 +91,20 @@ pub fn new(ecx: &'a mut EthEvmContext<DB>) -> Self {
     }
 
     /// Create a new instance of [ZKEVMData] with system contracts.
+    ///
+    /// Fails if the system contracts cannot be loaded.
     pub fn new_with_system_contracts(
         ecx: &'a mut EthEvmContext<DB>,
         chain_id: L2ChainId,
+        protocol_version: ProtocolVersionId,
+        system_contracts_path: Option<&Path>,
         evm_interpreter: bool,
-    ) -> Self {
+    ) -> eyre::Result<Self> {
         let system_context_init_log = get_system_context_init_logs(chain_id);
+        let system_contracts = deployed_system_contracts(protocol_version, system_contracts_path)?;
 
         let mut override_keys = HashMap::default();
-        DEPLOYED_SYSTEM_CONTRACTS
+        system_contracts
             .iter()
             .map(|c| {
                 let deployer_code_key = get_code_key(c.deployed_contract.account_id.address());
@@ -145,7 +115,7 @@ pub fn new_with_system_contracts(
                 (log.is_write()).then_some(override_keys.insert(log.key, log.value));
             });
 
-        let system_factory_deps = DEPLOYED_SYSTEM_CONTRACTS
+        let system_factory_deps = system_contracts
             .iter()
             .map(|c| (c.deployed_contract_hash, c.deployed_contract.bytecode.clone()));
 
@@ -178,13 +148,13 @@ pub fn new_with_system_contracts(
             override_keys.insert(contract_deployer_mode_key, rU256::from(1).to_h256());
         }
 
-        Self {
+        Ok(Self {
             ecx,
             factory_deps,
             override_keys,
             accesses: None,
             account_accesses: Default::default(),
-        }
+        })
     }
 
     /// Extends the currently known factory deps with the provided input
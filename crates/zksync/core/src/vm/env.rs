@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use zksync_basic_types::{AccountTreeId, H160, L1BatchNumber, L2BlockNumber, L2ChainId};
 use zksync_contracts::BaseSystemContracts;
 use zksync_multivm::{
@@ -5,13 +7,15 @@
     vm_latest::{constants::BATCH_COMPUTATIONAL_GAS_LIMIT, utils::l2_blocks::load_last_l2_block},
 };
 use zksync_types::{
-    SYSTEM_CONTEXT_ADDRESS, SYSTEM_CONTEXT_BLOCK_INFO_POSITION, StorageKey,
+    ProtocolVersionId, SYSTEM_CONTEXT_ADDRESS, SYSTEM_CONTEXT_BLOCK_INFO_POSITION, StorageKey,
     block::{L2BlockHasher, unpack_block_info},
     fee_model::PubdataIndependentBatchFeeModelInput,
     h256_to_u256,
 };
 use zksync_vm_interface::storage::{ReadStorage, StoragePtr};
 
+use crate::DEFAULT_PROTOCOL_VERSION;
+
 // https://github.com/matter-labs/era-contracts/blob/aafee035db892689df3f7afe4b89fd6467a39313/system-contracts/bootloader/bootloader.yul#L86
 const MAX_L2_GAS_PER_PUBDATA: u64 = 50000;
 
@@ -24,24 +28,40 @@ pub struct ZkEnv {
     pub fair_l2_gas_price: u64,
     /// fair pubdata price
     pub fair_pubdata_price: u64,
+    /// protocol version the system contracts and the bootloader are selected for, the latest vm is
+    /// always used to execute them
+    pub protocol_version: ProtocolVersionId,
+    /// directory to load compiled system contracts from, instead of the built-in ones
+    pub system_contracts_path: Option<PathBuf>,
 }
 
 impl Default for ZkEnv {
     fn default() -> Self {
         // TODO: fair pubdata price of 0 yields division by 0 error somewhere in
         // some cases. Should investigate this edge case further
-        Self { l1_gas_price: 0, fair_l2_gas_price: 0, fair_pubdata_price: 1000 }
+        Self {
+            l1_gas_price: 0,
+            fair_l2_gas_price: 0,
+            fair_pubdata_price: 1000,
+            protocol_version: DEFAULT_PROTOCOL_VERSION,
+            system_contracts_path: None,
+        }
     }
 }
 
 impl ZkEnv {
-    /// Compute gas per pubdata
-    pub fn gas_per_pubdata(&self) -> u64 {
+    /// Compute the base fee of the batch
+    pub fn base_fee(&self) -> u64 {
         // source: https://github.com/matter-labs/era-contracts/blob/aafee035db892689df3f7afe4b89fd6467a39313/system-contracts/bootloader/bootloader.yul#L59
-        let base_fee = std::cmp::max(
+        std::cmp::max(
             self.fair_l2_gas_price,
             self.fair_pubdata_price.div_ceil(MAX_L2_GAS_PER_PUBDATA),
-        );
+        )
+    }
+
+    /// Compute gas per pubdata
+    pub fn gas_per_pubdata(&self) -> u64 {
+        let base_fee = self.base_fee();
         if base_fee == 0 { 0 } else { self.fair_pubdata_price.div_ceil(base_fee) }
     }
 }
@@ -103,12 +123,11 @@ pub(crate) fn create_l1_batch_env<ST: ReadStorage>(
 pub(crate) fn create_system_env(
     base_system_contracts: BaseSystemContracts,
     chain_id: L2ChainId,
+    protocol_version: ProtocolVersionId,
 ) -> SystemEnv {
     SystemEnv {
         zk_porter_available: false,
-        // TODO: when forking, we could consider taking the protocol version id from the fork
-        // itself.
-        version: zksync_types::ProtocolVersionId::latest(),
+        version: protocol_version,
         base_system_smart_contracts: base_system_contracts,
         bootloader_gas_limit: BATCH_COMPUTATIONAL_GAS_LIMIT,
         execution_mode: TxExecutionMode::VerifyExecute,
@@ -0,0 +1,130 @@
+use std::fmt;
+
+use zksync_multivm::interface::Halt;
+
+/// The kind of an EraVM halt.
+///
+/// Unlike reverts, halts are raised by the bootloader or the VM itself and carry no revert data,
+/// so they are mapped to stable reasons that can be matched in tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZkHaltReason {
+    /// Account validation failed.
+    ValidationFailed,
+    /// Paymaster validation failed.
+    PaymasterValidationFailed,
+    /// Preparing the paymaster call failed.
+    PrePaymasterPreparationFailed,
+    /// Paying for the transaction failed.
+    PayForTxFailed,
+    /// Marking the factory dependencies as known failed.
+    FailedToMarkFactoryDependencies,
+    /// Charging the fee failed.
+    FailedToChargeFee,
+    /// The sender is not an account.
+    FromIsNotAnAccount,
+    /// The bootloader failed to execute the transaction.
+    InnerTxError,
+    /// The bootloader ran out of gas.
+    BootloaderOutOfGas,
+    /// Account or paymaster validation ran out of gas.
+    ValidationOutOfGas,
+    /// The gas limit of the transaction is too big.
+    TooBigGasLimit,
+    /// The gas limit of the transaction does not cover its intrinsic cost.
+    NotEnoughGasProvided,
+    /// The limit of calls to the same contract within the same transaction was reached.
+    MissingInvocationLimitReached,
+    /// The VM panicked.
+    VmPanic,
+    /// Any other halt.
+    Other,
+}
+
+impl ZkHaltReason {
+    /// Returns the reason as matched by `zkExpectHalt`.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::ValidationFailed => "validation failed",
+            Self::PaymasterValidationFailed => "paymaster validation failed",
+            Self::PrePaymasterPreparationFailed => "pre-paymaster preparation failed",
+            Self::PayForTxFailed => "pay for tx failed",
+            Self::FailedToMarkFactoryDependencies => "failed to mark factory dependencies",
+            Self::FailedToChargeFee => "failed to charge fee",
+            Self::FromIsNotAnAccount => "from is not an account",
+            Self::InnerTxError => "inner tx error",
+            Self::BootloaderOutOfGas => "bootloader out of gas",
+            Self::ValidationOutOfGas => "validation out of gas",
+            Self::TooBigGasLimit => "too big gas limit",
+            Self::NotEnoughGasProvided => "not enough gas provided",
+            Self::MissingInvocationLimitReached => "missing invocation limit reached",
+            Self::VmPanic => "vm panic",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for ZkHaltReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&Halt> for ZkHaltReason {
+    fn from(halt: &Halt) -> Self {
+        match halt {
+            Halt::ValidationFailed(_) => Self::ValidationFailed,
+            Halt::PaymasterValidationFailed(_) => Self::PaymasterValidationFailed,
+            Halt::PrePaymasterPreparationFailed(_) => Self::PrePaymasterPreparationFailed,
+            Halt::PayForTxFailed(_) => Self::PayForTxFailed,
+            Halt::FailedToMarkFactoryDependencies(_) => Self::FailedToMarkFactoryDependencies,
+            Halt::FailedToChargeFee(_) => Self::FailedToChargeFee,
+            Halt::FromIsNotAnAccount => Self::FromIsNotAnAccount,
+            Halt::InnerTxError => Self::InnerTxError,
+            Halt::BootloaderOutOfGas => Self::BootloaderOutOfGas,
+            Halt::ValidationOutOfGas => Self::ValidationOutOfGas,
+            Halt::TooBigGasLimit => Self::TooBigGasLimit,
+            Halt::NotEnoughGasProvided => Self::NotEnoughGasProvided,
+            Halt::MissingInvocationLimitReached => Self::MissingInvocationLimitReached,
+            Halt::VMPanic => Self::VmPanic,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// A decoded EraVM halt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZkHalt {
+    /// The kind of halt.
+    pub reason: ZkHaltReason,
+    /// The full halt message, including the revert reason returned by the account or paymaster,
+    /// if any.
+    pub message: String,
+}
+
+impl From<&Halt> for ZkHalt {
+    fn from(halt: &Halt) -> Self {
+        Self { reason: halt.into(), message: halt.to_string() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_multivm::interface::VmRevertReason;
+
+    use super::*;
+
+    #[test]
+    fn decodes_halt_reasons() {
+        let revert = || VmRevertReason::General { msg: "nope".to_string(), data: vec![] };
+
+        let halt = ZkHalt::from(&Halt::ValidationFailed(revert()));
+        assert_eq!(halt.reason.as_str(), "validation failed");
+        assert!(halt.message.contains("nope"));
+
+        let halt = ZkHalt::from(&Halt::PaymasterValidationFailed(revert()));
+        assert_eq!(halt.reason.as_str(), "paymaster validation failed");
+
+        assert_eq!(ZkHaltReason::from(&Halt::BootloaderOutOfGas), ZkHaltReason::BootloaderOutOfGas);
+        assert_eq!(ZkHaltReason::from(&Halt::TracerCustom("x".into())), ZkHaltReason::Other);
+    }
+}
@@ -1,9 +1,6 @@
 use alloy_evm::eth::EthEvmContext;
 use alloy_primitives::{FixedBytes, Log, hex};
-use anvil_zksync_config::types::SystemContractsOptions as Options;
-use anvil_zksync_core::{
-    formatter::transaction::summary::TransactionSummary, system_contracts::SystemContracts,
-};
+use anvil_zksync_core::formatter::transaction::summary::TransactionSummary;
 use anvil_zksync_traces::{
     build_call_trace_arena, decode_trace_arena, filter_call_trace_arena,
     identifier::SignaturesIdentifier, render_trace_arena_inner,
@@ -23,6 +20,7 @@
     database::states::StorageSlot,
     primitives::{Address, B256, Bytes, HashMap as rHashMap, Log as rLog, U256 as rU256},
 };
+use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
     fmt::Debug,
@@ -39,34 +37,38 @@
     vm_latest::{HistoryDisabled, ToTracerPointer, Vm},
 };
 use zksync_types::{
-    ACCOUNT_CODE_STORAGE_ADDRESS, CONTRACT_DEPLOYER_ADDRESS, PackedEthSignature, StorageKey,
-    Transaction, bytecode::BytecodeHash, get_nonce_key, h256_to_address, l2::L2Tx,
-    transaction_request::PaymasterParams,
+    ACCOUNT_CODE_STORAGE_ADDRESS, CONTRACT_DEPLOYER_ADDRESS, ExecuteTransactionCommon,
+    PackedEthSignature, StorageKey, Transaction, bytecode::BytecodeHash, get_nonce_key,
+    h256_to_address, l2::L2Tx, transaction_request::PaymasterParams,
 };
 use zksync_vm_interface::storage::{ReadStorage, StoragePtr, WriteStorage};
 
 use crate::{
-    DEFAULT_PROTOCOL_VERSION,
     convert::{ConvertAddress, ConvertH160, ConvertH256, ConvertRU256},
     fix_l2_gas_limit, fix_l2_gas_price, increment_tx_nonce, is_system_address,
     state::{FullNonce, new_full_nonce, parse_full_nonce},
     vm::{
         db::{DEFAULT_CHAIN_ID, ZKVMData},
         decoder::CallTraceDecoderBuilder,
-        env::{create_l1_batch_env, create_system_env},
+        env::{ZkEnv, create_l1_batch_env, create_system_env},
+        halt::ZkHalt,
         storage_recorder::{AccountAccess, StorageAccessRecorder},
         storage_view::StorageView,
         tracers::{
             bootloader::{BootloaderDebug, BootloaderDebugTracer},
+            call_tree::{CallTreeTracer, ZkFrame},
             cheatcode::{CallContext, CheatcodeTracer, CheatcodeTracerContext},
+            coverage::{CoverageTracer, ZkBytecodeCoverage},
+            edge_coverage::EdgeCoverageTracer,
             error::ErrorTracer,
+            step::{StepTracer, ZkVmStep},
         },
     },
 };
 
 use foundry_evm_abi::console::{self, ds::Console};
 
-use super::HARDHAT_CONSOLE_ADDRESS;
+use super::{HARDHAT_CONSOLE_ADDRESS, system_contracts::baseline_contracts};
 
 /// Represents the result of execution a [`L2Tx`] on EraVM
 #[derive(Debug)]
@@ -81,6 +83,52 @@ pub struct ZKVMExecutionResult {
     pub recorded_immutables: rHashMap<H160, rHashMap<rU256, FixedBytes<32>>>,
     /// Recorded account accesses.
     pub account_accesses: Vec<AccountAccess>,
+    /// EraVM coverage keyed by bytecode hash, only populated if requested.
+    pub coverage: HashMap<H256, ZkBytecodeCoverage>,
+    /// Hit counts of the branches taken, keyed by edge id, only populated if requested.
+    pub edge_coverage: HashMap<u64, u32>,
+    /// Pubdata published by the execution.
+    pub pubdata: ZkPubdataUsage,
+    /// Executed EraVM instructions, only populated if requested.
+    pub steps: Vec<ZkVmStep>,
+    /// Near calls and pubdata of the far call frames, in the depth-first order of
+    /// `call_traces`, only populated if requested.
+    pub frames: Vec<ZkFrame>,
+    /// The decoded halt, if the execution halted.
+    pub halt: Option<ZkHalt>,
+}
+
+/// Pubdata published by a transaction executed on EraVM.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZkPubdataUsage {
+    /// Number of pubdata bytes published.
+    pub published: u64,
+    /// Gas charged per published pubdata byte.
+    pub gas_per_pubdata: u64,
+    /// Price of a pubdata byte on L1, in wei.
+    pub pubdata_price: u64,
+    /// Price of a unit of gas charged by the batch, in wei.
+    pub gas_price: u64,
+}
+
+impl ZkPubdataUsage {
+    /// Returns the gas spent on publishing pubdata.
+    pub fn gas(&self) -> u64 {
+        self.published.saturating_mul(self.gas_per_pubdata)
+    }
+
+    /// Returns the L1 cost of publishing pubdata, in wei.
+    pub fn l1_cost(&self) -> u128 {
+        self.published as u128 * self.pubdata_price as u128
+    }
+
+    /// Adds the pubdata published by `other` to `self`, priced at the prices of `other`.
+    pub fn merge(&mut self, other: Self) {
+        self.published = self.published.saturating_add(other.published);
+        self.gas_per_pubdata = other.gas_per_pubdata;
+        self.pubdata_price = other.pubdata_price;
+        self.gas_price = other.gas_price;
+    }
 }
 
 /// Revm-style result with ZKVM Execution
@@ -101,6 +149,7 @@ pub fn inspect_as_batch<DB, E>(
 where
     DB: Database,
     <DB as Database>::Error: Debug,
+    E: From<eyre::Report>,
 {
     let txns = split_tx_by_factory_deps(tx);
     let total_txns = txns.len();
@@ -115,6 +164,7 @@ pub fn inspect_as_batch<DB, E>(
             ecx,
             tx.common_data.initiator_address.to_address(),
             &tx.common_data.paymaster_params,
+            &ccx.zk_env,
         );
         tx.common_data.fee.gas_limit = new_gas_limit;
 
@@ -130,6 +180,12 @@ pub fn inspect_as_batch<DB, E>(
                     execution_result: exec,
                     recorded_immutables: result.recorded_immutables,
                     account_accesses: result.account_accesses,
+                    coverage: result.coverage,
+                    edge_coverage: result.edge_coverage,
+                    pubdata: result.pubdata,
+                    steps: result.steps,
+                    frames: result.frames,
+                    halt: result.halt,
                 });
             }
             (None, exec) => {
@@ -139,6 +195,12 @@ pub fn inspect_as_batch<DB, E>(
                     execution_result: exec,
                     recorded_immutables: result.recorded_immutables,
                     account_accesses: result.account_accesses,
+                    coverage: result.coverage,
+                    edge_coverage: result.edge_coverage,
+                    pubdata: result.pubdata,
+                    steps: result.steps,
+                    frames: result.frames,
+                    halt: result.halt,
                 });
             }
             (Some(zk_result), reth_result) => {
@@ -150,13 +212,23 @@ pub fn inspect_as_batch<DB, E>(
                 zk_result.call_traces.append(&mut result.call_traces);
                 zk_result.recorded_immutables.extend(result.recorded_immutables);
                 zk_result.account_accesses.extend(result.account_accesses);
+                for (hash, coverage) in result.coverage {
+                    zk_result.coverage.entry(hash).or_default().merge(coverage);
+                }
+                for (edge, hits) in result.edge_coverage {
+                    *zk_result.edge_coverage.entry(edge).or_default() += hits;
+                }
+                zk_result.pubdata.merge(result.pubdata);
+                zk_result.steps.append(&mut result.steps);
+                zk_result.frames.append(&mut result.frames);
                 zk_result.execution_result = reth_result;
             }
         }
 
         // Increment the nonce manually if there are other transactions
-        // to be executed after the current one
-        if pending_txs > 1 {
+        // to be executed after the current one. Custom accounts keep the nonce
+        // incremented by the VM.
+        if pending_txs > 1 && !call_ctx.is_custom_account {
             increment_tx_nonce(initiator_address.to_address(), ecx);
         }
     }
@@ -176,6 +248,32 @@ pub fn inspect<DB, E>(
 where
     DB: Database,
     <DB as Database>::Error: Debug,
+    E: From<eyre::Report>,
+{
+    if tx.common_data.signature.is_empty() {
+        // FIXME: This is a hack to make sure that the signature is not empty.
+        // Fails without a signature here: https://github.com/matter-labs/zksync-era/blob/73a1e8ff564025d06e02c2689da238ae47bb10c3/core/lib/types/src/transaction_request.rs#L381
+        tx.common_data.signature = PackedEthSignature::default().serialize_packed().into();
+    }
+
+    inspect_transaction(tx.into(), ecx, ccx, call_ctx)
+}
+
+/// Processes a [`Transaction`] with EraVM, either an [`L2Tx`] or a priority [`L1Tx`].
+///
+/// See [`inspect`].
+///
+/// [`L1Tx`]: zksync_types::l1::L1Tx
+pub(crate) fn inspect_transaction<DB, E>(
+    tx: Transaction,
+    ecx: &mut EthEvmContext<DB>,
+    ccx: &mut CheatcodeTracerContext,
+    call_ctx: CallContext,
+) -> ZKVMResult<E>
+where
+    DB: Database,
+    <DB as Database>::Error: Debug,
+    E: From<eyre::Report>,
 {
     let chain_id = if ecx.cfg.chain_id <= u32::MAX as u64 {
         L2ChainId::from(ecx.cfg.chain_id as u32)
@@ -190,24 +288,28 @@ pub fn inspect<DB, E>(
         .map(|factory_deps| (*factory_deps).clone())
         .unwrap_or_default();
 
-    let mut era_db = ZKVMData::new_with_system_contracts(ecx, chain_id, call_ctx.evm_interpreter)
-        .with_extra_factory_deps(persisted_factory_deps)
-        .with_storage_accesses(ccx.accesses.take());
+    let mut era_db = ZKVMData::new_with_system_contracts(
+        ecx,
+        chain_id,
+        ccx.zk_env.protocol_version,
+        ccx.zk_env.system_contracts_path.as_deref(),
+        call_ctx.evm_interpreter,
+    )?
+    .with_extra_factory_deps(persisted_factory_deps)
+    .with_storage_accesses(ccx.accesses.take());
 
     info!(?call_ctx, "executing transaction in zk vm");
 
-    let initiator_address = tx.common_data.initiator_address;
-
-    if tx.common_data.signature.is_empty() {
-        // FIXME: This is a hack to make sure that the signature is not empty.
-        // Fails without a signature here: https://github.com/matter-labs/zksync-era/blob/73a1e8ff564025d06e02c2689da238ae47bb10c3/core/lib/types/src/transaction_request.rs#L381
-        tx.common_data.signature = PackedEthSignature::default().serialize_packed().into();
-    }
+    let initiator_address = tx.initiator_account();
+    let is_l2_tx = matches!(tx.common_data, ExecuteTransactionCommon::L2(_));
 
     let modified_storage_keys = era_db.override_keys.clone();
-    let storage_ptr =
-        StorageView::new(&mut era_db, modified_storage_keys, tx.common_data.initiator_address)
-            .into_rc_ptr();
+    let storage_ptr = StorageView::new(
+        &mut era_db,
+        modified_storage_keys,
+        (!call_ctx.is_custom_account).then_some(initiator_address),
+    )
+    .into_rc_ptr();
     let InnerZkVmResult {
         tx_result,
         bytecodes,
@@ -216,7 +318,12 @@ pub fn inspect<DB, E>(
         recorded_immutables,
         create_outcome,
         gas_usage,
-    } = inspect_inner(tx, storage_ptr, chain_id, ccx, call_ctx.clone());
+        coverage,
+        edge_coverage,
+        pubdata,
+        steps,
+        frames,
+    } = inspect_inner(tx, storage_ptr, chain_id, ccx, call_ctx.clone())?;
 
     info!(
         reserved=?gas_usage.bootloader_debug.reserved_gas, limit=?gas_usage.limit, execution=?gas_usage.execution, pubdata=?gas_usage.pubdata, refunded=?gas_usage.refunded,
@@ -279,6 +386,12 @@ pub fn inspect<DB, E>(
                 },
                 recorded_immutables,
                 account_accesses,
+                coverage,
+                edge_coverage,
+                pubdata,
+                steps,
+                frames,
+                halt: None,
             }
         }
         ExecutionResult::Revert { output } => {
@@ -297,12 +410,20 @@ pub fn inspect<DB, E>(
                 },
                 recorded_immutables,
                 account_accesses,
+                coverage,
+                edge_coverage,
+                pubdata,
+                steps,
+                frames,
+                halt: None,
             }
         }
         ExecutionResult::Halt { reason } => {
             error!("tx execution halted: {}", reason);
             let mapped_reason = match reason {
-                Halt::NotEnoughGasProvided => HaltReason::OutOfGas(OutOfGasError::Basic),
+                Halt::NotEnoughGasProvided
+                | Halt::BootloaderOutOfGas
+                | Halt::ValidationOutOfGas => HaltReason::OutOfGas(OutOfGasError::Basic),
                 _ => HaltReason::PrecompileError,
             };
 
@@ -315,6 +436,12 @@ pub fn inspect<DB, E>(
                 },
                 recorded_immutables,
                 account_accesses,
+                coverage,
+                edge_coverage,
+                pubdata,
+                steps,
+                frames,
+                halt: Some(ZkHalt::from(&reason)),
             }
         }
     };
@@ -336,7 +463,12 @@ pub fn inspect<DB, E>(
     // NOTE(zk): We need to revert the tx nonce of the initiator as we intercept and dispatch
     // CALLs and CREATEs to the zkEVM. The CREATEs always increment the deployment nonce
     // which must be persisted, but the tx nonce increase must be reverted.
-    if let Some(initiator_nonce) = modified_storage.get_mut(&initiator_nonce_key) {
+    // Custom accounts keep the increase, as they send a real transaction. Priority transactions
+    // don't use the tx nonce at all.
+    if is_l2_tx
+        && !call_ctx.is_custom_account
+        && let Some(initiator_nonce) = modified_storage.get_mut(&initiator_nonce_key)
+    {
         let FullNonce { tx_nonce, deploy_nonce } = parse_full_nonce(initiator_nonce.to_ru256());
         let new_tx_nonce = tx_nonce.saturating_sub(1);
         trace!(address=?initiator_address, from=?tx_nonce, to=?new_tx_nonce, deploy_nonce, "reverting initiator tx nonce for CALL");
@@ -405,6 +537,7 @@ pub fn gas_params<DB>(
     ecx: &mut EthEvmContext<DB>,
     caller: Address,
     paymaster_params: &PaymasterParams,
+    zk_env: &ZkEnv,
 ) -> (U256, U256)
 where
     DB: Database,
@@ -421,7 +554,12 @@ pub fn gas_params<DB>(
     };
     let balance = ZKVMData::new(ecx).get_balance(payer);
     let dev_mode = gas_price.is_zero();
-    let effective_gas_price = if dev_mode { fix_l2_gas_price(gas_price) } else { gas_price };
+    // the gas price must cover the base fee of the batch, which can be raised via cheatcodes
+    let effective_gas_price = if dev_mode {
+        fix_l2_gas_price(gas_price).max(U256::from(zk_env.base_fee()))
+    } else {
+        gas_price
+    };
     let gas_limit = fix_l2_gas_limit(gas_limit, effective_gas_price, value, balance);
     (gas_limit, effective_gas_price)
 }
@@ -464,35 +602,33 @@ struct InnerZkVmResult {
     create_outcome: Option<InnerCreateOutcome>,
     gas_usage: ZkVmGasUsage,
     recorded_immutables: rHashMap<H160, rHashMap<rU256, FixedBytes<32>>>,
+    coverage: HashMap<H256, ZkBytecodeCoverage>,
+    edge_coverage: HashMap<u64, u32>,
+    pubdata: ZkPubdataUsage,
+    steps: Vec<ZkVmStep>,
+    frames: Vec<ZkFrame>,
 }
 
-static BASELINE_CONTRACTS: LazyLock<zksync_contracts::BaseSystemContracts> = LazyLock::new(|| {
-    SystemContracts::from_options(
-        Options::BuiltInWithoutSecurity,
-        None,
-        DEFAULT_PROTOCOL_VERSION,
-        true,
-        Default::default(),
-    )
-    .contracts(zksync_vm_interface::TxExecutionMode::VerifyExecute, false)
-    .clone()
-});
-
 fn inspect_inner<S: ReadStorage + StorageAccessRecorder>(
-    l2_tx: L2Tx,
+    tx: Transaction,
     storage: StoragePtr<StorageView<S>>,
     chain_id: L2ChainId,
     ccx: &mut CheatcodeTracerContext,
     call_ctx: CallContext,
-) -> InnerZkVmResult {
+) -> eyre::Result<InnerZkVmResult> {
     let batch_env = create_l1_batch_env(storage.clone(), &ccx.zk_env);
     let l2_gas_price = batch_env.fee_input.fair_l2_gas_price();
+    let pubdata_price = batch_env.fee_input.fair_pubdata_price();
 
-    let system_env = create_system_env(BASELINE_CONTRACTS.clone(), chain_id);
+    let protocol_version = ccx.zk_env.protocol_version;
+    let base_system_contracts =
+        baseline_contracts(protocol_version, ccx.zk_env.system_contracts_path.as_deref())?;
+    let system_env = create_system_env(base_system_contracts, chain_id, protocol_version);
 
-    let mut vm: Vm<_, HistoryDisabled> = Vm::new(batch_env, system_env, storage.clone());
+    // The protocol version only selects the system contracts and the bootloader, which the latest
+    // VM runs for every supported version, as the tracers are implemented for it only.
 
-    let tx: Transaction = l2_tx.into();
+    let mut vm: Vm<_, HistoryDisabled> = Vm::new(batch_env, system_env, storage.clone());
 
     let call_tracer_result = Arc::default();
     let cheatcode_tracer_result = Arc::default();
@@ -505,7 +641,11 @@ fn inspect_inner<S: ReadStorage + StorageAccessRecorder>(
     let is_static = call_ctx.is_static;
     let is_create = call_ctx.is_create;
     let bootloader_debug_tracer_result = Arc::new(RwLock::new(Err("result uninitialized".into())));
-    let tracers = vec![
+    let coverage_tracer_result = Arc::default();
+    let edge_coverage_tracer_result = Arc::default();
+    let step_tracer_result = Arc::default();
+    let call_tree_tracer_result = Arc::default();
+    let mut tracers = vec![
         ErrorTracer.into_tracer_pointer(),
         CallTracer::new(Arc::clone(&call_tracer_result)).into_tracer_pointer(),
         BootloaderDebugTracer { result: Arc::clone(&bootloader_debug_tracer_result) }
@@ -518,6 +658,22 @@ fn inspect_inner<S: ReadStorage + StorageAccessRecorder>(
         )
         .into_tracer_pointer(),
     ];
+    if ccx.record_coverage {
+        tracers
+            .push(CoverageTracer::new(Arc::clone(&coverage_tracer_result)).into_tracer_pointer());
+    }
+    if ccx.record_edge_coverage {
+        tracers.push(
+            EdgeCoverageTracer::new(Arc::clone(&edge_coverage_tracer_result)).into_tracer_pointer(),
+        );
+    }
+    if ccx.record_steps {
+        tracers.push(StepTracer::new(Arc::clone(&step_tracer_result)).into_tracer_pointer());
+    }
+    if ccx.record_call_tree {
+        tracers
+            .push(CallTreeTracer::new(Arc::clone(&call_tree_tracer_result)).into_tracer_pointer());
+    }
 
     let compressed_bytecodes = vm.push_transaction(tx.clone()).compressed_bytecodes.into_owned();
     let mut tx_result = vm.inspect(&mut tracers.into(), InspectExecutionMode::OneTx);
@@ -564,6 +720,13 @@ fn inspect_inner<S: ReadStorage + StorageAccessRecorder>(
         .gas_per_pubdata
         .saturating_mul(tx_result.statistics.pubdata_published.into());
 
+    let pubdata = ZkPubdataUsage {
+        published: tx_result.statistics.pubdata_published.into(),
+        gas_per_pubdata: bootloader_debug.gas_per_pubdata.low_u64(),
+        pubdata_price,
+        gas_price: ccx.zk_env.base_fee(),
+    };
+
     let gas_usage = ZkVmGasUsage {
         limit: total_gas_limit,
         execution: gas_used_tx_execution,
@@ -654,6 +817,26 @@ fn inspect_inner<S: ReadStorage + StorageAccessRecorder>(
         .collect::<HashMap<_, _>>();
     let modified_storage = storage.borrow().modified_storage_keys().clone();
 
+    // resolve the executed bytecodes for the recorded coverage.
+    let coverage = Arc::try_unwrap(coverage_tracer_result)
+        .unwrap()
+        .take()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(hash, hits)| {
+            let bytecode = bytecodes
+                .get(&hash)
+                .cloned()
+                .or_else(|| storage.borrow_mut().load_factory_dep(hash))?;
+            Some((hash, ZkBytecodeCoverage { bytecode, hits }))
+        })
+        .collect();
+
+    let edge_coverage =
+        Arc::try_unwrap(edge_coverage_tracer_result).unwrap().take().unwrap_or_default();
+    let steps = Arc::try_unwrap(step_tracer_result).unwrap().take().unwrap_or_default();
+    let frames = Arc::try_unwrap(call_tree_tracer_result).unwrap().take().unwrap_or_default();
+
     // patch CREATE traces.
     for call in call_traces.iter_mut() {
         call_traces_patch_create(&deployed_bytecode_hashes, &bytecodes, storage.clone(), call);
@@ -679,7 +862,7 @@ fn inspect_inner<S: ReadStorage + StorageAccessRecorder>(
         None
     };
 
-    if is_static {
+    let result = if is_static {
         InnerZkVmResult {
             tx_result,
             bytecodes: Default::default(),
@@ -688,6 +871,11 @@ fn inspect_inner<S: ReadStorage + StorageAccessRecorder>(
             create_outcome,
             gas_usage,
             recorded_immutables: Default::default(),
+            coverage,
+            edge_coverage,
+            pubdata,
+            steps,
+            frames,
         }
     } else {
         InnerZkVmResult {
@@ -698,8 +886,15 @@ fn inspect_inner<S: ReadStorage + StorageAccessRecorder>(
             create_outcome,
             gas_usage,
             recorded_immutables,
+            coverage,
+            edge_coverage,
+            pubdata,
+            steps,
+            frames,
         }
-    }
+    };
+
+    Ok(result)
 }
 
 fn contract_address_from_tx_result(execution_result: &VmExecutionResultAndLogs) -> Option<H160> {
@@ -883,7 +1078,7 @@ fn split_tx_by_factory_deps(mut tx: L2Tx) -> Vec<L2Tx> {
 
     let mut txs = Vec::with_capacity(batched.len() + 1);
     for deps in batched.into_iter() {
-        txs.push(L2Tx::new(
+        let mut deps_tx = L2Tx::new(
             Some(H160::zero()),
             Vec::default(),
             tx.common_data.nonce,
@@ -892,7 +1087,10 @@ fn split_tx_by_factory_deps(mut tx: L2Tx) -> Vec<L2Tx> {
             Default::default(),
             deps,
             tx.common_data.paymaster_params.clone(),
-        ));
+        );
+        // every transaction goes through the validation of the initiator account
+        deps_tx.common_data.signature = tx.common_data.signature.clone();
+        txs.push(deps_tx);
         tx.common_data.nonce = Nonce(tx.common_data.nonce.0.saturating_add(1));
     }
 
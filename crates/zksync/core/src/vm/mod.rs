@@ -2,24 +2,34 @@
 mod decoder;
 mod env;
 mod farcall;
+mod halt;
 mod inspect;
 mod runner;
 mod storage_recorder;
 mod storage_view;
+mod system_contracts;
 mod tracers;
 
 use alloy_primitives::{Address, address};
 pub use env::ZkEnv;
 pub use farcall::{SELECTOR_CONTRACT_DEPLOYER_CREATE, SELECTOR_CONTRACT_DEPLOYER_CREATE2};
+pub use halt::{ZkHalt, ZkHaltReason};
 pub use inspect::{
-    ZKVMExecutionResult, ZKVMResult, batch_factory_dependencies, inspect, inspect_as_batch,
+    ZKVMExecutionResult, ZKVMResult, ZkPubdataUsage, batch_factory_dependencies, inspect,
+    inspect_as_batch,
 };
 pub use runner::{
-    ZkCreateInputs, balance, call, code_hash, create, deploy_nonce, encode_create_params, transact,
-    tx_nonce,
+    ZkCreateInputs, ZkL1TxInputs, balance, call, code_hash, create, deploy_nonce,
+    encode_create_params, execute_l1_tx, transact, tx_nonce,
 };
 pub use storage_recorder::{AccountAccess, AccountAccessKind, StorageAccess};
-pub use tracers::cheatcode::CheatcodeTracerContext;
+pub use system_contracts::validate_system_contracts;
+pub use tracers::{
+    call_tree::{ZkCallTraces, ZkFrame, ZkNearCall},
+    cheatcode::CheatcodeTracerContext,
+    coverage::ZkBytecodeCoverage,
+    step::ZkVmStep,
+};
 
 /// The Hardhat console address.
 ///
@@ -11,7 +11,10 @@
 use tracing::{debug, info};
 use zksync_basic_types::H256;
 use zksync_types::{
-    CONTRACT_DEPLOYER_ADDRESS, CREATE2_FACTORY_ADDRESS, U256, ethabi, fee::Fee, l2::L2Tx,
+    CONTRACT_DEPLOYER_ADDRESS, CREATE2_FACTORY_ADDRESS, Execute, U256, ethabi,
+    fee::Fee,
+    l1::{L1Tx, L1TxCommonData},
+    l2::L2Tx,
     transaction_request::PaymasterParams,
 };
 use zksync_vm_interface::Call;
@@ -20,17 +23,21 @@
 use std::{cmp::min, fmt::Debug};
 
 use crate::{
+    bridge::{REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_LIMIT, apply_l1_to_l2_alias},
     convert::{ConvertAddress, ConvertH160, ConvertRU256, ConvertU256},
     vm::{
         db::ZKVMData,
-        inspect::{ZKVMExecutionResult, ZKVMResult, gas_params, inspect, inspect_as_batch},
+        inspect::{
+            ZKVMExecutionResult, ZKVMResult, ZkPubdataUsage, gas_params, inspect, inspect_as_batch,
+            inspect_transaction,
+        },
         tracers::cheatcode::{CallContext, CheatcodeTracerContext},
     },
 };
 
 use super::ZkEnv;
 
-/// Transacts
+/// Transacts, returning the pubdata published by the transaction along with its result.
 #[allow(clippy::too_many_arguments)]
 pub fn transact<'a, DB>(
     persisted_factory_deps: Option<&'a mut HashMap<H256, Vec<u8>>>,
@@ -41,7 +48,7 @@ pub fn transact<'a, DB>(
     zk_env: &ZkEnv,
     db: &'a mut DB,
     evm_interpreter: bool,
-) -> eyre::Result<(ResultAndState, Vec<Call>)>
+) -> eyre::Result<(ResultAndState, Vec<Call>, ZkPubdataUsage)>
 where
     DB: Database + ?Sized,
     <DB as Database>::Error: Debug,
@@ -74,7 +81,7 @@ pub fn transact<'a, DB>(
         TransactTo::Create => (CONTRACT_DEPLOYER_ADDRESS, true),
     };
 
-    let (gas_limit, max_fee_per_gas) = gas_params(&mut ecx, caller, &paymaster_params);
+    let (gas_limit, max_fee_per_gas) = gas_params(&mut ecx, caller, &paymaster_params, zk_env);
     debug!(?gas_limit, ?max_fee_per_gas, "tx gas parameters");
     let tx = L2Tx::new(
         Some(transact_to),
@@ -106,6 +113,7 @@ pub fn transact<'a, DB>(
         is_static: false,
         record_storage_accesses: false,
         evm_interpreter,
+        is_custom_account: false,
     };
 
     let mut ccx = CheatcodeTracerContext {
@@ -114,10 +122,12 @@ pub fn transact<'a, DB>(
         ..Default::default()
     };
 
-    match inspect::<_, DB::Error>(tx, &mut ecx, &mut ccx, call_ctx) {
-        Ok(ZKVMExecutionResult { execution_result: result, call_traces, .. }) => {
-            Ok((ResultAndState { result, state: ecx.journaled_state.finalize() }, call_traces))
-        }
+    match inspect::<_, eyre::Report>(tx, &mut ecx, &mut ccx, call_ctx) {
+        Ok(ZKVMExecutionResult { execution_result: result, call_traces, pubdata, .. }) => Ok((
+            ResultAndState { result, state: ecx.journaled_state.finalize() },
+            call_traces,
+            pubdata,
+        )),
         Err(err) => eyre::bail!("zk backend: failed while inspecting: {err:?}"),
     }
 }
@@ -183,13 +193,15 @@ pub fn create<DB, E>(
 where
     DB: Database,
     <DB as Database>::Error: Debug,
+    E: From<eyre::Report>,
 {
     let ZkCreateInputs { create_input, factory_deps, value, msg_sender } = inputs;
 
     info!("create tx {}", hex::encode(&create_input));
     // We're using `tx.origin` as the initiator so the zkEVM validation does not fail when using
     // `msg.sender` as it's not EOA. The nonce and balance changes thus need to be adapted.
-    let caller = ecx.tx.caller;
+    // Custom accounts are the initiator themselves, and go through their own validation.
+    let caller = ccx.account_data.as_ref().map_or(ecx.tx.caller, |account| account.address);
     let nonce = ZKVMData::new(ecx).get_tx_nonce(caller);
 
     let paymaster_params = if let Some(paymaster_data) = &ccx.paymaster_data {
@@ -201,10 +213,10 @@ pub fn create<DB, E>(
         PaymasterParams::default()
     };
 
-    let (gas_limit, max_fee_per_gas) = gas_params(ecx, caller, &paymaster_params);
+    let (gas_limit, max_fee_per_gas) = gas_params(ecx, caller, &paymaster_params, &ccx.zk_env);
     info!(?gas_limit, ?max_fee_per_gas, "tx gas parameters");
 
-    let tx = if ccx.evm_interpreter {
+    let mut tx = if ccx.evm_interpreter {
         let mut tx = L2Tx::new(
             None,
             create_input,
@@ -240,10 +252,12 @@ pub fn create<DB, E>(
             paymaster_params,
         )
     };
+    set_account_signature(&mut tx, &ccx);
 
+    let is_custom_account = ccx.account_data.is_some();
     let call_ctx = CallContext {
-        tx_caller: ecx.tx.caller,
-        msg_sender,
+        tx_caller: caller,
+        msg_sender: if is_custom_account { caller } else { msg_sender },
         contract: CONTRACT_DEPLOYER_ADDRESS.to_address(),
         input: None,
         delegate_as: None,
@@ -255,6 +269,7 @@ pub fn create<DB, E>(
         is_static: false,
         record_storage_accesses: ccx.record_storage_accesses,
         evm_interpreter: ccx.evm_interpreter,
+        is_custom_account,
     };
 
     inspect_as_batch(tx, ecx, &mut ccx, call_ctx)
@@ -270,12 +285,14 @@ pub fn call<DB, E>(
 where
     DB: Database,
     <DB as Database>::Error: Debug,
+    E: From<eyre::Report>,
 {
     let input = call.input.bytes(ecx);
     info!(?call, "call tx {}", hex::encode(&input));
     // We're using `tx.origin` as the initiator so the zkEVM validation does not fail when using
     // `msg.sender` as it's not EOA. The nonce and balance changes thus need to be adapted.
-    let caller = ecx.tx.caller;
+    // Custom accounts are the initiator themselves, and go through their own validation.
+    let caller = ccx.account_data.as_ref().map_or(ecx.tx.caller, |account| account.address);
     let nonce = ZKVMData::new(ecx).get_tx_nonce(caller);
 
     let paymaster_params = if let Some(paymaster_data) = &ccx.paymaster_data {
@@ -287,10 +304,10 @@ pub fn call<DB, E>(
         PaymasterParams::default()
     };
 
-    let (gas_limit, max_fee_per_gas) = gas_params(ecx, caller, &paymaster_params);
+    let (gas_limit, max_fee_per_gas) = gas_params(ecx, caller, &paymaster_params, &ccx.zk_env);
     info!(?gas_limit, ?max_fee_per_gas, "tx gas parameters");
 
-    let tx = if ccx.evm_interpreter {
+    let mut tx = if ccx.evm_interpreter {
         let mut tx = L2Tx::new(
             Some(call.bytecode_address.to_h160()),
             input.to_vec(),
@@ -332,14 +349,16 @@ pub fn call<DB, E>(
             paymaster_params,
         )
     };
+    set_account_signature(&mut tx, &ccx);
 
     // address and caller are specific to the type of call:
     // Call | StaticCall => { address: to, caller: contract.address }
     // CallCode          => { address: contract.address, caller: contract.address }
     // DelegateCall      => { address: contract.address, caller: contract.caller }
+    let is_custom_account = ccx.account_data.is_some();
     let call_ctx = CallContext {
-        tx_caller: ecx.tx.caller,
-        msg_sender: call.caller,
+        tx_caller: caller,
+        msg_sender: if is_custom_account { caller } else { call.caller },
         contract: call.bytecode_address,
         input: Some(input),
         delegate_as: match call.scheme {
@@ -354,11 +373,91 @@ pub fn call<DB, E>(
         is_static: call.is_static,
         record_storage_accesses: ccx.record_storage_accesses,
         evm_interpreter: ccx.evm_interpreter,
+        is_custom_account,
     };
 
     inspect(tx, ecx, &mut ccx, call_ctx)
 }
 
+/// A priority transaction requested by an L1 contract.
+pub struct ZkL1TxInputs {
+    /// The L1 contract requesting the transaction, aliased on L2
+    pub l1_sender: Address,
+    /// The called L2 contract
+    pub to: Address,
+    /// The calldata
+    pub data: Vec<u8>,
+    /// Value sent with the call
+    pub value: U256,
+    /// The L2 gas limit of the transaction
+    pub l2_gas_limit: U256,
+}
+
+/// Executes a priority (L1 to L2) transaction on the EraVM.
+///
+/// The transaction is sent by the L2 alias of `l1_sender`, and mints the value and the fee it pays
+/// for, as if they were deposited on L1. The unused fee is refunded to the aliased sender.
+pub fn execute_l1_tx<DB, E>(
+    inputs: ZkL1TxInputs,
+    ecx: &mut EthEvmContext<DB>,
+    mut ccx: CheatcodeTracerContext,
+) -> ZKVMResult<E>
+where
+    DB: Database,
+    <DB as Database>::Error: Debug,
+    E: From<eyre::Report>,
+{
+    let ZkL1TxInputs { l1_sender, to, data, value, l2_gas_limit } = inputs;
+    info!(?l1_sender, ?to, "l1 tx {}", hex::encode(&data));
+
+    let sender = apply_l1_to_l2_alias(l1_sender);
+    let max_fee_per_gas = U256::from(ccx.zk_env.base_fee());
+    let tx = L1Tx {
+        execute: Execute {
+            contract_address: Some(to.to_h160()),
+            calldata: data.clone(),
+            value,
+            factory_deps: Default::default(),
+        },
+        common_data: L1TxCommonData {
+            sender: sender.to_h160(),
+            gas_limit: l2_gas_limit,
+            max_fee_per_gas,
+            gas_per_pubdata_limit: U256::from(REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_LIMIT),
+            to_mint: l2_gas_limit.saturating_mul(max_fee_per_gas).saturating_add(value),
+            refund_recipient: sender.to_h160(),
+            ..Default::default()
+        },
+        received_timestamp_ms: 0,
+    };
+
+    let call_ctx = CallContext {
+        tx_caller: sender,
+        msg_sender: sender,
+        contract: to,
+        input: Some(data.into()),
+        delegate_as: None,
+        block_number: rU256::from(ecx.block.number),
+        block_timestamp: rU256::from(ecx.block.timestamp),
+        block_hashes: get_historical_block_hashes(ecx),
+        block_basefee: min(max_fee_per_gas.to_ru256(), rU256::from(ecx.block.basefee)),
+        is_create: false,
+        is_static: false,
+        record_storage_accesses: ccx.record_storage_accesses,
+        evm_interpreter: ccx.evm_interpreter,
+        is_custom_account: false,
+    };
+
+    inspect_transaction(tx.into(), ecx, &mut ccx, call_ctx)
+}
+
+/// Sets the signature of the custom account executing the transaction, if any.
+fn set_account_signature(tx: &mut L2Tx, ccx: &CheatcodeTracerContext) {
+    if let Some(account) = &ccx.account_data {
+        tx.common_data.signature = account.signature.to_vec();
+    }
+}
+
 /// Prepares calldata to invoke deployer contract.
 pub fn encode_create_params(
     scheme: &CreateScheme,
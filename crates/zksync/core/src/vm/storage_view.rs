@@ -31,8 +31,9 @@ pub(crate) struct StorageView<S> {
     pub(crate) read_storage_keys: HashMap<StorageKey, StorageValue>,
     /// Cache for `contains_key()` checks. The cache is only valid within one L1 batch execution.
     initial_writes_cache: HashMap<StorageKey, bool>,
-    /// The tx caller.
-    caller: H160,
+    /// The tx caller, whose account code is read as empty so it's treated as an EOA. `None` for
+    /// custom accounts, which must run their own code.
+    caller: Option<H160>,
     /// Call tracker for recording storage accesses.
     /// Track `FarCalls`s to allow matching them with their respective `Ret` opcodes.
     /// zkEVM erases the `msg.sender` and `code_address` for certain calls like to MsgSimulator,
@@ -45,7 +46,7 @@ impl<S: ReadStorage + fmt::Debug> StorageView<S> {
     pub(crate) fn new(
         storage_handle: S,
         modified_storage_keys: HashMap<StorageKey, StorageValue>,
-        caller: H160,
+        caller: Option<H160>,
     ) -> Self {
         Self {
             storage_handle,
@@ -85,7 +86,9 @@ fn read_value(&mut self, key: &StorageKey) -> StorageValue {
         let value = self.get_value_no_log(key);
 
         // We override the caller's account code storage to allow for calls
-        if key.address() == &ACCOUNT_CODE_STORAGE_ADDRESS && key.key() == &self.caller.to_h256() {
+        if key.address() == &ACCOUNT_CODE_STORAGE_ADDRESS
+            && self.caller.is_some_and(|caller| key.key() == &caller.to_h256())
+        {
             let value = StorageValue::zero();
             tracing::trace!(
                 hashed_key = ?key.hashed_key(),
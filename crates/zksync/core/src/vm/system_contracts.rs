@@ -0,0 +1,265 @@
+//! System contracts the zkVM is run with.
+//!
+//! System contracts are either the built-in set shipped for a protocol version, or compiled system
+//! contracts loaded from a local directory, laid out as in `era-contracts`. Both are loaded once and
+//! cached per protocol version and directory.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use anvil_zksync_config::types::SystemContractsOptions as Options;
+use anvil_zksync_core::{
+    deps::system_contracts::NON_KERNEL_CONTRACT_LOCATIONS, system_contracts::SystemContracts,
+};
+use eyre::{Result, bail, eyre};
+use zksync_basic_types::H256;
+use zksync_contracts::{BaseSystemContracts, SystemContractCode};
+use zksync_types::{
+    CREATE2_FACTORY_ADDRESS, ProtocolVersionId,
+    block::DeployedContract,
+    bytecode::{BytecodeHash, BytecodeMarker, validate_bytecode},
+};
+
+use crate::hash_bytecode;
+
+/// Identifies a set of system contracts: the protocol version and an optional local directory.
+type SystemContractsKey = (ProtocolVersionId, Option<PathBuf>);
+
+// NOTE: we use vec instead of hashmap because the loaded [BOOTLOADER] and [0 address] share the
+// same bytecode, thus they would share the same bytecode hash (key in map) resulting in the first
+// `DeployedContract` to be discarded from the resulting map. This is a problem when we compute the
+// override keys as the discarded contract won't have an associated generated override
+// TL;DR: we want to keep _all_ instances of `DeployedContract` even if they share the same bytecode
+// hash
+pub(crate) struct DeployedSystemContract {
+    pub(crate) deployed_contract: DeployedContract,
+    pub(crate) deployed_contract_hash: H256,
+}
+
+/// Deployed system contracts, loaded once per protocol version and directory.
+static DEPLOYED_SYSTEM_CONTRACTS: LazyLock<
+    RwLock<HashMap<SystemContractsKey, Arc<Vec<DeployedSystemContract>>>>,
+> = LazyLock::new(Default::default);
+
+/// Base system contracts, loaded once per protocol version and directory.
+static BASELINE_CONTRACTS: LazyLock<RwLock<HashMap<SystemContractsKey, BaseSystemContracts>>> =
+    LazyLock::new(Default::default);
+
+/// Validates the compiled system contracts in `path` for `protocol_version`, and caches them for
+/// later executions.
+///
+/// Fails if the directory doesn't exist, if any of the built-in system contracts of the protocol
+/// version is missing, or if any contract is not valid EraVM bytecode.
+pub fn validate_system_contracts(path: &Path, protocol_version: ProtocolVersionId) -> Result<()> {
+    deployed_system_contracts(protocol_version, Some(path))?;
+    baseline_contracts(protocol_version, Some(path))?;
+    Ok(())
+}
+
+/// Returns the system contracts deployed for `protocol_version`, loaded from `path` if set.
+pub(crate) fn deployed_system_contracts(
+    protocol_version: ProtocolVersionId,
+    path: Option<&Path>,
+) -> Result<Arc<Vec<DeployedSystemContract>>> {
+    let key = (protocol_version, path.map(Path::to_path_buf));
+    if let Some(contracts) = DEPLOYED_SYSTEM_CONTRACTS.read().unwrap().get(&key) {
+        return Ok(contracts.clone());
+    }
+
+    let contracts = Arc::new(load_deployed_system_contracts(protocol_version, path)?);
+    DEPLOYED_SYSTEM_CONTRACTS.write().unwrap().insert(key, contracts.clone());
+    Ok(contracts)
+}
+
+/// Returns the base system contracts (bootloader and default account) for `protocol_version`,
+/// loaded from `path` if set.
+pub(crate) fn baseline_contracts(
+    protocol_version: ProtocolVersionId,
+    path: Option<&Path>,
+) -> Result<BaseSystemContracts> {
+    let key = (protocol_version, path.map(Path::to_path_buf));
+    if let Some(contracts) = BASELINE_CONTRACTS.read().unwrap().get(&key) {
+        return Ok(contracts.clone());
+    }
+
+    let contracts = load_baseline_contracts(protocol_version, path)?;
+    BASELINE_CONTRACTS.write().unwrap().insert(key, contracts.clone());
+    Ok(contracts)
+}
+
+fn options(path: Option<&Path>) -> Options {
+    if path.is_some() { Options::Local } else { Options::BuiltInWithoutSecurity }
+}
+
+fn ensure_dir(path: Option<&Path>) -> Result<()> {
+    if let Some(path) = path
+        && !path.is_dir()
+    {
+        bail!("system contracts directory {} does not exist", path.display());
+    }
+    Ok(())
+}
+
+fn load_deployed_system_contracts(
+    protocol_version: ProtocolVersionId,
+    path: Option<&Path>,
+) -> Result<Vec<DeployedSystemContract>> {
+    ensure_dir(path)?;
+    let contracts =
+        kernel_contracts(anvil_zksync_core::deps::system_contracts::get_deployed_contracts(
+            options(path),
+            protocol_version,
+            path,
+        ));
+
+    // Local contracts must replace every built-in kernel contract of the protocol version.
+    if path.is_some() {
+        let expected = deployed_system_contracts(protocol_version, None)?;
+        validate_deployed_contracts(&contracts, expected.iter().map(|c| &c.deployed_contract))?;
+    }
+
+    contracts
+        .into_iter()
+        .map(|contract| {
+            validate_bytecode(&contract.bytecode).map_err(|err| {
+                eyre!(
+                    "invalid bytecode for system contract {:?}: {err}",
+                    contract.account_id.address()
+                )
+            })?;
+            Ok(DeployedSystemContract {
+                deployed_contract_hash: hash_bytecode(&contract.bytecode),
+                deployed_contract: contract,
+            })
+        })
+        .collect()
+}
+
+/// Drops the non-kernel contracts, which are not deployed at genesis.
+fn kernel_contracts(contracts: Vec<DeployedContract>) -> Vec<DeployedContract> {
+    contracts
+        .into_iter()
+        .filter(|contract| {
+            let addr = contract.account_id.address();
+
+            if *addr == CREATE2_FACTORY_ADDRESS {
+                return true;
+            }
+
+            // Drop anything that matches a non-kernel contract location.
+            !NON_KERNEL_CONTRACT_LOCATIONS.iter().any(|(_name, a, _ver)| *a == *addr)
+        })
+        .collect()
+}
+
+/// Checks that `contracts` contains a contract for each of the `expected` addresses.
+fn validate_deployed_contracts<'a>(
+    contracts: &[DeployedContract],
+    expected: impl IntoIterator<Item = &'a DeployedContract>,
+) -> Result<()> {
+    let missing = expected
+        .into_iter()
+        .map(|contract| *contract.account_id.address())
+        .filter(|addr| !contracts.iter().any(|contract| contract.account_id.address() == addr))
+        .map(|addr| format!("{addr:?}"))
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        bail!("missing system contracts: {}", missing.join(", "));
+    }
+    Ok(())
+}
+
+fn load_baseline_contracts(
+    protocol_version: ProtocolVersionId,
+    path: Option<&Path>,
+) -> Result<BaseSystemContracts> {
+    ensure_dir(path)?;
+    let contracts = SystemContracts::from_options(
+        options(path),
+        path.map(Path::to_path_buf),
+        protocol_version,
+        true,
+        Default::default(),
+    )
+    .contracts(zksync_vm_interface::TxExecutionMode::VerifyExecute, false)
+    .clone();
+
+    validate_contract_code("bootloader", &contracts.bootloader)?;
+    validate_contract_code("default account", &contracts.default_aa)?;
+    if let Some(evm_emulator) = &contracts.evm_emulator {
+        validate_contract_code("EVM emulator", evm_emulator)?;
+    }
+
+    Ok(contracts)
+}
+
+/// Checks that `contract` is valid EraVM bytecode, and that its hash is a versioned EraVM bytecode
+/// hash of the same length.
+fn validate_contract_code(name: &str, contract: &SystemContractCode) -> Result<()> {
+    validate_bytecode(&contract.code).map_err(|err| eyre!("invalid {name} bytecode: {err}"))?;
+    let hash = BytecodeHash::try_from(contract.hash)
+        .map_err(|err| eyre!("invalid {name} bytecode hash {:?}: {err}", contract.hash))?;
+    if hash.marker() != BytecodeMarker::EraVm || hash.len_in_bytes() != contract.code.len() {
+        bail!(
+            "invalid {name} bytecode hash {:?}: not an EraVM hash of {} bytes",
+            contract.hash,
+            contract.code.len()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_PROTOCOL_VERSION;
+
+    #[test]
+    fn loads_built_in_system_contracts() {
+        let deployed = deployed_system_contracts(DEFAULT_PROTOCOL_VERSION, None).unwrap();
+        assert!(!deployed.is_empty());
+        for contract in deployed.iter() {
+            assert_eq!(
+                contract.deployed_contract_hash,
+                hash_bytecode(&contract.deployed_contract.bytecode)
+            );
+        }
+        baseline_contracts(DEFAULT_PROTOCOL_VERSION, None).unwrap();
+    }
+
+    #[test]
+    fn rejects_missing_directory() {
+        let err =
+            validate_system_contracts(Path::new("missing"), DEFAULT_PROTOCOL_VERSION).unwrap_err();
+        assert!(err.to_string().contains("missing does not exist"), "{err}");
+    }
+
+    #[test]
+    fn rejects_missing_kernel_contract() {
+        let expected = deployed_system_contracts(DEFAULT_PROTOCOL_VERSION, None).unwrap();
+        let mut contracts =
+            expected.iter().map(|c| c.deployed_contract.clone()).collect::<Vec<_>>();
+        validate_deployed_contracts(&contracts, expected.iter().map(|c| &c.deployed_contract))
+            .unwrap();
+
+        let removed = contracts.pop().unwrap();
+        let err =
+            validate_deployed_contracts(&contracts, expected.iter().map(|c| &c.deployed_contract))
+                .unwrap_err();
+        assert!(err.to_string().contains(&format!("{:?}", removed.account_id.address())), "{err}");
+    }
+
+    #[test]
+    fn rejects_mismatched_code_hash() {
+        let baseline = baseline_contracts(DEFAULT_PROTOCOL_VERSION, None).unwrap();
+        validate_contract_code("bootloader", &baseline.bootloader).unwrap();
+
+        let mut code = baseline.default_aa.clone();
+        code.code.extend_from_slice(&[0; 64]);
+        let err = validate_contract_code("default account", &code).unwrap_err();
+        assert!(err.to_string().contains("invalid default account bytecode hash"), "{err}");
+    }
+}
@@ -0,0 +1,162 @@
+use std::{cell::OnceCell, sync::Arc};
+
+use zksync_multivm::{
+    interface::tracer::VmExecutionStopReason,
+    tracers::dynamic::vm_1_5_2::DynTracer,
+    vm_latest::{BootloaderState, HistoryMode, SimpleMemory, VmTracer, ZkSyncVmState},
+    zk_evm_latest::{
+        tracing::{AfterExecutionData, BeforeExecutionData, VmLocalStateData},
+        zkevm_opcode_defs::{Opcode, RetOpcode},
+    },
+};
+use zksync_vm_interface::storage::{StoragePtr, WriteStorage};
+
+use crate::Call;
+
+/// A near call made by a far call frame.
+///
+/// Near calls don't switch context, so they are not part of the call traces. The far calls made
+/// while a near call is active are identified by their position among the subcalls of the
+/// enclosing frame.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ZkNearCall {
+    /// Number of near calls of the same frame enclosing this one.
+    pub depth: usize,
+    /// Index of the first far call made within the near call, among the subcalls of the frame.
+    pub first_call: usize,
+    /// Number of far calls made within the near call, including nested near calls.
+    pub calls: usize,
+    /// Ergs passed to the near call.
+    pub gas: u64,
+    /// Ergs spent by the near call.
+    pub gas_used: u64,
+    /// Whether the near call reverted or panicked.
+    pub reverted: bool,
+}
+
+/// Details of a far call frame that are not part of the call traces.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ZkFrame {
+    /// Pubdata bytes spent by the frame, including its successful subcalls.
+    pub pubdata: u64,
+    /// Near calls made by the frame, in the order they were made.
+    pub near_calls: Vec<ZkNearCall>,
+}
+
+/// EraVM call traces along with the details of their frames, to be recorded by the trace
+/// inspector.
+#[derive(Debug, Default)]
+pub struct ZkCallTraces {
+    /// Far calls made during execution.
+    pub calls: Vec<Call>,
+    /// Details of the far calls, in depth-first order, only populated for the full call tree.
+    pub frames: Vec<ZkFrame>,
+}
+
+/// A far call frame that has not returned yet.
+#[derive(Debug, Default)]
+struct OpenFrame {
+    /// Index of the frame in the recorded frames.
+    index: usize,
+    /// Number of far calls made so far by the frame.
+    subcalls: usize,
+    /// Indices of the active near calls of the frame.
+    near_calls: Vec<usize>,
+}
+
+/// A tracer that records the near calls and the pubdata spent by every far call frame, to
+/// render the full EraVM call tree.
+///
+/// Frames are recorded in the order the far calls were made, which is the order of a
+/// depth-first traversal of the call traces.
+#[derive(Debug)]
+pub struct CallTreeTracer {
+    /// Result to send back.
+    pub result: Arc<OnceCell<Vec<ZkFrame>>>,
+    /// Recorded frames, the first one being the bootloader frame.
+    frames: Vec<ZkFrame>,
+    /// Frames that have not returned yet.
+    stack: Vec<OpenFrame>,
+}
+
+impl CallTreeTracer {
+    /// Create an instance of [CallTreeTracer].
+    pub fn new(result: Arc<OnceCell<Vec<ZkFrame>>>) -> Self {
+        Self { result, frames: vec![ZkFrame::default()], stack: vec![OpenFrame::default()] }
+    }
+}
+
+impl<S, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for CallTreeTracer {
+    fn before_execution(
+        &mut self,
+        state: VmLocalStateData<'_>,
+        data: BeforeExecutionData,
+        _memory: &SimpleMemory<H>,
+        _storage: StoragePtr<S>,
+    ) {
+        let Opcode::Ret(ret) = data.opcode.variant.opcode else { return };
+        let current = state.vm_local_state.callstack.current;
+        let Some(frame) = self.stack.last_mut() else { return };
+
+        // Returns close the innermost near call first, same as the call tracer.
+        if let Some(index) = frame.near_calls.pop() {
+            let near_call = &mut self.frames[frame.index].near_calls[index];
+            near_call.gas_used = near_call.gas.saturating_sub(current.ergs_remaining as u64);
+            near_call.reverted = !matches!(ret, RetOpcode::Ok);
+            return;
+        }
+
+        // The bootloader frame is never closed.
+        if self.stack.len() > 1
+            && let Some(frame) = self.stack.pop()
+        {
+            self.frames[frame.index].pubdata = current.total_pubdata_spent.0.max(0) as u64;
+        }
+    }
+
+    fn after_execution(
+        &mut self,
+        state: VmLocalStateData<'_>,
+        data: AfterExecutionData,
+        _memory: &SimpleMemory<H>,
+        _storage: StoragePtr<S>,
+    ) {
+        match data.opcode.variant.opcode {
+            Opcode::NearCall(_) => {
+                let Some(frame) = self.stack.last_mut() else { return };
+                let near_calls = &mut self.frames[frame.index].near_calls;
+                near_calls.push(ZkNearCall {
+                    depth: frame.near_calls.len(),
+                    first_call: frame.subcalls,
+                    gas: state.vm_local_state.callstack.current.ergs_remaining as u64,
+                    ..Default::default()
+                });
+                frame.near_calls.push(near_calls.len() - 1);
+            }
+            Opcode::FarCall(_) => {
+                if let Some(frame) = self.stack.last_mut() {
+                    for &index in &frame.near_calls {
+                        self.frames[frame.index].near_calls[index].calls += 1;
+                    }
+                    frame.subcalls += 1;
+                }
+
+                self.stack.push(OpenFrame { index: self.frames.len(), ..Default::default() });
+                self.frames.push(ZkFrame::default());
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for CallTreeTracer {
+    fn after_vm_execution(
+        &mut self,
+        _state: &mut ZkSyncVmState<S, H>,
+        _bootloader_state: &BootloaderState,
+        _stop_reason: VmExecutionStopReason,
+    ) {
+        // Skip the bootloader frame, which is not part of the call traces.
+        self.result.set(self.frames.drain(1..).collect()).unwrap();
+    }
+}
@@ -29,7 +29,7 @@
 use zksync_vm_interface::storage::{ReadStorage, StoragePtr, WriteStorage};
 
 use crate::{
-    EMPTY_CODE, ZkPaymasterData, compute_create_address, compute_create2_address,
+    EMPTY_CODE, ZkAccountData, ZkPaymasterData, compute_create_address, compute_create2_address,
     convert::{ConvertAddress, ConvertH160, ConvertH256, ConvertU256},
     hash_bytecode,
     state::{FullNonce, parse_full_nonce},
@@ -104,12 +104,22 @@ pub struct CheatcodeTracerContext<'a> {
     pub persisted_factory_deps: Option<&'a mut HashMap<H256, Vec<u8>>>,
     /// Paymaster data
     pub paymaster_data: Option<ZkPaymasterData>,
+    /// Custom account executing the transaction
+    pub account_data: Option<ZkAccountData>,
     /// Era Vm environment
     pub zk_env: ZkEnv,
     /// Whether to record storage accesses.
     pub record_storage_accesses: bool,
     /// Whether to use EVM interpreter.
     pub evm_interpreter: bool,
+    /// Whether to record EraVM coverage.
+    pub record_coverage: bool,
+    /// Whether to record the branches taken in EraVM, for coverage-guided fuzzing.
+    pub record_edge_coverage: bool,
+    /// Whether to record every executed EraVM instruction for the debugger.
+    pub record_steps: bool,
+    /// Whether to record the near calls and pubdata of every frame, for the full call tree.
+    pub record_call_tree: bool,
 }
 
 /// Tracer result to return back to foundry.
@@ -152,6 +162,9 @@ pub struct CallContext {
     pub record_storage_accesses: bool,
     /// Whether to use EVM interpreter.
     pub evm_interpreter: bool,
+    /// Whether the transaction is executed by a custom account, going through its validation and
+    /// payment flow instead of being treated as sent by an EOA.
+    pub is_custom_account: bool,
 }
 
 /// A tracer to allow for foundry-specific functionality.
@@ -435,6 +448,7 @@ fn after_execution(
         // Mark the caller as EOA to avoid panic. This is probably not needed anymore
         // since we manually override the ACCOUNT_CODE_STORAGE to return `0` for the caller.
         // TODO remove this and verify once we are stable.
+        // Custom accounts rely on it as well, so they need not be deployed via `createAccount`.
         if let Opcode::FarCall(_call) = data.opcode.variant.opcode {
             let current = state.vm_local_state.callstack.get_current_stack();
 
@@ -454,7 +468,10 @@ fn after_execution(
         // The same cannot be done for `validateTransaction` due to the many safeguards around
         // correct nonce update in the bootloader. So we handle it by modifying the storage
         // post-execution.
-        if let Opcode::FarCall(_call) = data.opcode.variant.opcode {
+        // Custom accounts are called by the bootloader as is, and make the call themselves.
+        if let Opcode::FarCall(_call) = data.opcode.variant.opcode
+            && !self.call_context.is_custom_account
+        {
             let current = state.vm_local_state.callstack.current;
 
             if current.msg_sender == BOOTLOADER_ADDRESS
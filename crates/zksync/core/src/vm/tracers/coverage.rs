@@ -0,0 +1,107 @@
+use std::{cell::OnceCell, collections::HashMap, sync::Arc};
+
+use zksync_multivm::{
+    interface::tracer::VmExecutionStopReason,
+    tracers::dynamic::vm_1_5_2::DynTracer,
+    vm_latest::{BootloaderState, HistoryMode, SimpleMemory, VmTracer, ZkSyncVmState},
+    zk_evm_latest::tracing::{BeforeExecutionData, VmLocalStateData},
+};
+use zksync_types::{H160, H256, get_code_key};
+use zksync_vm_interface::storage::{ReadStorage, StoragePtr, WriteStorage};
+
+use crate::{convert::ConvertH160, is_system_address};
+
+/// Version byte of an EraVM versioned bytecode hash.
+const ERAVM_BYTECODE_VERSION: u8 = 1;
+
+/// Program counter hits recorded during execution, keyed by the EraVM bytecode hash.
+pub type CoverageTracerResult = HashMap<H256, HashMap<u32, u32>>;
+
+/// Coverage recorded for a single EraVM bytecode.
+#[derive(Debug, Default, Clone)]
+pub struct ZkBytecodeCoverage {
+    /// The executed EraVM bytecode.
+    pub bytecode: Vec<u8>,
+    /// Number of hits per program counter (instruction index).
+    pub hits: HashMap<u32, u32>,
+}
+
+impl ZkBytecodeCoverage {
+    /// Merges the hits of `other` into `self`.
+    pub fn merge(&mut self, other: Self) {
+        if self.bytecode.is_empty() {
+            self.bytecode = other.bytecode;
+        }
+        for (pc, hits) in other.hits {
+            *self.hits.entry(pc).or_default() += hits;
+        }
+    }
+}
+
+/// A tracer that records the executed program counters of non-system contracts.
+#[derive(Debug, Default)]
+pub struct CoverageTracer {
+    /// Result to send back.
+    pub result: Arc<OnceCell<CoverageTracerResult>>,
+    /// Recorded hits.
+    hits: CoverageTracerResult,
+    /// Cache of the bytecode hash deployed at each executed address.
+    code_hashes: HashMap<H160, Option<H256>>,
+}
+
+impl CoverageTracer {
+    /// Create an instance of [CoverageTracer].
+    pub fn new(result: Arc<OnceCell<CoverageTracerResult>>) -> Self {
+        Self { result, ..Default::default() }
+    }
+
+    /// Returns the normalized EraVM bytecode hash deployed at `address`, if any.
+    ///
+    /// Account code storage marks contracts that are still being constructed in the second
+    /// byte of the versioned hash, so it's cleared to have a single hash for both the
+    /// constructor and the runtime code.
+    fn code_hash<S: ReadStorage>(
+        &mut self,
+        storage: &StoragePtr<S>,
+        address: H160,
+    ) -> Option<H256> {
+        *self.code_hashes.entry(address).or_insert_with(|| {
+            let mut hash = storage.borrow_mut().read_value(&get_code_key(&address));
+            if hash.is_zero() || hash.0[0] != ERAVM_BYTECODE_VERSION {
+                return None;
+            }
+            hash.0[1] = 0;
+            Some(hash)
+        })
+    }
+}
+
+impl<S: ReadStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for CoverageTracer {
+    fn before_execution(
+        &mut self,
+        state: VmLocalStateData<'_>,
+        _data: BeforeExecutionData,
+        _memory: &SimpleMemory<H>,
+        storage: StoragePtr<S>,
+    ) {
+        let current = state.vm_local_state.callstack.current;
+        if is_system_address(current.code_address.to_address()) {
+            return;
+        }
+
+        if let Some(hash) = self.code_hash(&storage, current.code_address) {
+            *self.hits.entry(hash).or_default().entry(current.pc as u32).or_default() += 1;
+        }
+    }
+}
+
+impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for CoverageTracer {
+    fn after_vm_execution(
+        &mut self,
+        _state: &mut ZkSyncVmState<S, H>,
+        _bootloader_state: &BootloaderState,
+        _stop_reason: VmExecutionStopReason,
+    ) {
+        self.result.set(std::mem::take(&mut self.hits)).unwrap();
+    }
+}
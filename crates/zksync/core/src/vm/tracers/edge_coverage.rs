@@ -0,0 +1,88 @@
+use std::{
+    cell::OnceCell,
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::Arc,
+};
+
+use zksync_multivm::{
+    interface::tracer::VmExecutionStopReason,
+    tracers::dynamic::vm_1_5_2::DynTracer,
+    vm_latest::{BootloaderState, HistoryMode, SimpleMemory, VmTracer, ZkSyncVmState},
+    zk_evm_latest::{
+        tracing::{BeforeExecutionData, VmLocalStateData},
+        zkevm_opcode_defs::Opcode,
+    },
+};
+use zksync_types::H160;
+use zksync_vm_interface::storage::{StoragePtr, WriteStorage};
+
+use crate::{convert::ConvertH160, is_system_address};
+
+/// Hit counts of the branches taken during execution, keyed by edge id.
+pub type EdgeCoverageTracerResult = HashMap<u64, u32>;
+
+/// A tracer that records the branches taken by non-system contracts, to guide coverage-guided
+/// fuzzing.
+///
+/// An edge is a jump instruction together with the instruction executed after it, so both the
+/// taken and the fall-through side of a conditional jump are recorded.
+#[derive(Debug, Default)]
+pub struct EdgeCoverageTracer {
+    /// Result to send back.
+    pub result: Arc<OnceCell<EdgeCoverageTracerResult>>,
+    /// Recorded hits.
+    hits: EdgeCoverageTracerResult,
+    /// Address and program counter of the jump executed in the previous step, if any.
+    last_jump: Option<(H160, u16)>,
+}
+
+impl EdgeCoverageTracer {
+    /// Create an instance of [EdgeCoverageTracer].
+    pub fn new(result: Arc<OnceCell<EdgeCoverageTracerResult>>) -> Self {
+        Self { result, ..Default::default() }
+    }
+}
+
+impl<S, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for EdgeCoverageTracer {
+    fn before_execution(
+        &mut self,
+        state: VmLocalStateData<'_>,
+        data: BeforeExecutionData,
+        _memory: &SimpleMemory<H>,
+        _storage: StoragePtr<S>,
+    ) {
+        let current = state.vm_local_state.callstack.current;
+        if let Some((address, pc)) = self.last_jump.take()
+            && address == current.code_address
+        {
+            *self.hits.entry(edge_id(address, pc, current.pc)).or_default() += 1;
+        }
+
+        if matches!(data.opcode.variant.opcode, Opcode::Jump(_))
+            && !is_system_address(current.code_address.to_address())
+        {
+            self.last_jump = Some((current.code_address, current.pc));
+        }
+    }
+}
+
+impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for EdgeCoverageTracer {
+    fn after_vm_execution(
+        &mut self,
+        _state: &mut ZkSyncVmState<S, H>,
+        _bootloader_state: &BootloaderState,
+        _stop_reason: VmExecutionStopReason,
+    ) {
+        self.result.set(std::mem::take(&mut self.hits)).unwrap();
+    }
+}
+
+/// Returns the id of the edge from the jump at `from` to the instruction at `to`.
+fn edge_id(address: H160, from: u16, to: u16) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    address.hash(&mut hasher);
+    from.hash(&mut hasher);
+    to.hash(&mut hasher);
+    hasher.finish()
+}
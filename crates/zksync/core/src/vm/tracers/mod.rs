@@ -3,5 +3,9 @@
 pub mod bootloader {
     pub use anvil_zksync_core::bootloader_debug::{BootloaderDebug, BootloaderDebugTracer};
 }
+pub mod call_tree;
 pub mod cheatcode;
+pub mod coverage;
+pub mod edge_coverage;
 pub mod error;
+pub mod step;
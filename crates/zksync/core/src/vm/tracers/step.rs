@@ -0,0 +1,229 @@
+use std::{cell::OnceCell, sync::Arc};
+
+use alloy_primitives::{Address, Bytes, U256 as rU256};
+use zksync_multivm::{
+    interface::tracer::VmExecutionStopReason,
+    tracers::dynamic::vm_1_5_2::DynTracer,
+    vm_latest::{BootloaderState, HistoryMode, SimpleMemory, VmTracer, ZkSyncVmState},
+    zk_evm_latest::{
+        tracing::{BeforeExecutionData, VmLocalStateData},
+        vm_state,
+    },
+};
+use zksync_vm_interface::storage::{StoragePtr, WriteStorage};
+
+use crate::{
+    convert::{ConvertH160, ConvertU256},
+    is_system_address,
+};
+
+/// Maximum number of heap and aux heap bytes recorded per step.
+const MAX_HEAP_BYTES: u32 = 1024;
+
+/// Maximum number of stack items recorded per step.
+const MAX_STACK_ITEMS: u16 = 32;
+
+/// Maximum number of bytes allocated for heap and aux heap snapshots over a whole execution.
+/// Steps recorded once it is reached have empty heaps.
+const MAX_SNAPSHOT_BYTES: usize = 64 * 1024 * 1024;
+
+/// A single executed EraVM instruction.
+#[derive(Debug, Clone)]
+pub struct ZkVmStep {
+    /// Depth of the callstack, including near calls.
+    pub depth: usize,
+    /// Address of the executed code.
+    pub contract: Address,
+    /// Program counter (instruction index).
+    pub pc: u16,
+    /// Mnemonic of the executed opcode, e.g. `far_call.normal`.
+    pub opcode: String,
+    /// Values of the general purpose registers `r1` to `r15`.
+    pub registers: Vec<rU256>,
+    /// Topmost items of the stack, bottom first.
+    pub stack: Vec<rU256>,
+    /// Start of the heap of the current frame, shared with the previous step if unchanged.
+    pub heap: Bytes,
+    /// Start of the aux heap of the current frame, shared with the previous step if unchanged.
+    pub aux_heap: Bytes,
+    /// Ergs remaining in the current frame.
+    pub ergs_remaining: u32,
+}
+
+/// A tracer that records every instruction executed by non-system contracts, to be stepped
+/// through in the debugger.
+#[derive(Debug, Default)]
+pub struct StepTracer {
+    /// Result to send back.
+    pub result: Arc<OnceCell<Vec<ZkVmStep>>>,
+    /// Recorded steps.
+    steps: Vec<ZkVmStep>,
+    /// Last heap snapshot taken.
+    last_heap: Bytes,
+    /// Last aux heap snapshot taken.
+    last_aux_heap: Bytes,
+    /// Number of bytes allocated for heap snapshots so far.
+    snapshot_bytes: usize,
+}
+
+impl StepTracer {
+    /// Create an instance of [StepTracer].
+    pub fn new(result: Arc<OnceCell<Vec<ZkVmStep>>>) -> Self {
+        Self { result, ..Default::default() }
+    }
+}
+
+impl<S, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for StepTracer {
+    fn before_execution(
+        &mut self,
+        state: VmLocalStateData<'_>,
+        data: BeforeExecutionData,
+        memory: &SimpleMemory<H>,
+        _storage: StoragePtr<S>,
+    ) {
+        let current = state.vm_local_state.callstack.current;
+        if is_system_address(current.code_address.to_address()) {
+            return;
+        }
+
+        let heap = share_snapshot(
+            &mut self.last_heap,
+            memory.read_unaligned_bytes(
+                vm_state::heap_page_from_base(current.base_memory_page).0 as usize,
+                0,
+                current.heap_bound.min(MAX_HEAP_BYTES) as usize,
+            ),
+            &mut self.snapshot_bytes,
+        );
+        let aux_heap = share_snapshot(
+            &mut self.last_aux_heap,
+            memory.read_unaligned_bytes(
+                vm_state::aux_heap_page_from_base(current.base_memory_page).0 as usize,
+                0,
+                current.aux_heap_bound.min(MAX_HEAP_BYTES) as usize,
+            ),
+            &mut self.snapshot_bytes,
+        );
+
+        // The stack pointer points to the next free slot, each slot is a 32 byte word.
+        let stack_len = current.sp.min(MAX_STACK_ITEMS);
+        let stack = memory
+            .read_unaligned_bytes(
+                vm_state::stack_page_from_base(current.base_memory_page).0 as usize,
+                (current.sp - stack_len) as usize * 32,
+                stack_len as usize * 32,
+            )
+            .chunks_exact(32)
+            .map(rU256::from_be_slice)
+            .collect();
+
+        self.steps.push(ZkVmStep {
+            depth: state.vm_local_state.callstack.depth(),
+            contract: current.code_address.to_address(),
+            pc: current.pc,
+            opcode: mnemonic(&format!("{:?}", data.opcode.variant.opcode)),
+            registers: state
+                .vm_local_state
+                .registers
+                .iter()
+                .map(|register| register.value.to_ru256())
+                .collect(),
+            stack,
+            heap,
+            aux_heap,
+            ergs_remaining: current.ergs_remaining,
+        });
+    }
+}
+
+impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for StepTracer {
+    fn after_vm_execution(
+        &mut self,
+        _state: &mut ZkSyncVmState<S, H>,
+        _bootloader_state: &BootloaderState,
+        _stop_reason: VmExecutionStopReason,
+    ) {
+        self.result.set(std::mem::take(&mut self.steps)).unwrap();
+    }
+}
+
+/// Returns a snapshot of `bytes`, reusing `last` if they are unchanged so that consecutive steps
+/// share the same allocation. New snapshots are counted in `allocated`, and are left empty once
+/// [MAX_SNAPSHOT_BYTES] is reached.
+fn share_snapshot(last: &mut Bytes, bytes: Vec<u8>, allocated: &mut usize) -> Bytes {
+    if last[..] == bytes[..] {
+        return last.clone();
+    }
+    if *allocated + bytes.len() > MAX_SNAPSHOT_BYTES {
+        return Bytes::new();
+    }
+    *allocated += bytes.len();
+    *last = Bytes::from(bytes);
+    last.clone()
+}
+
+/// Converts the debug representation of an EraVM opcode into its assembly mnemonic, e.g.
+/// `FarCall(Normal)` into `far_call.normal`.
+fn mnemonic(opcode: &str) -> String {
+    fn snake_case(s: &str) -> String {
+        let mut out = String::with_capacity(s.len() + 4);
+        for (i, c) in s.chars().enumerate() {
+            if c.is_ascii_uppercase() {
+                if i > 0 {
+                    out.push('_');
+                }
+                out.push(c.to_ascii_lowercase());
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    let (name, variant) = match opcode.split_once('(') {
+        Some((name, variant)) => (name, variant.trim_end_matches(')')),
+        None => (opcode, ""),
+    };
+    let name = snake_case(name);
+    let variant = snake_case(variant);
+    if variant.is_empty() || variant == name { name } else { format!("{name}.{variant}") }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_eravm_mnemonics() {
+        assert_eq!(mnemonic("FarCall(Normal)"), "far_call.normal");
+        assert_eq!(mnemonic("Ret(Revert)"), "ret.revert");
+        assert_eq!(mnemonic("Add(Add)"), "add");
+        assert_eq!(mnemonic("Nop(Nop)"), "nop");
+        assert_eq!(mnemonic("Invalid"), "invalid");
+    }
+
+    #[test]
+    fn shares_unchanged_snapshots() {
+        let mut last = Bytes::new();
+        let mut allocated = 0;
+
+        let first = share_snapshot(&mut last, vec![1; 64], &mut allocated);
+        let second = share_snapshot(&mut last, vec![1; 64], &mut allocated);
+        assert_eq!(first.as_ptr(), second.as_ptr());
+        assert_eq!(allocated, 64);
+
+        let third = share_snapshot(&mut last, vec![2; 64], &mut allocated);
+        assert_eq!(third, Bytes::from(vec![2; 64]));
+        assert_eq!(allocated, 128);
+    }
+
+    #[test]
+    fn stops_snapshotting_once_the_budget_is_reached() {
+        let mut last = Bytes::new();
+        let mut allocated = MAX_SNAPSHOT_BYTES - 32;
+
+        assert_eq!(share_snapshot(&mut last, vec![1; 64], &mut allocated), Bytes::new());
+        assert_eq!(share_snapshot(&mut last, vec![1; 32], &mut allocated).len(), 32);
+        assert_eq!(allocated, MAX_SNAPSHOT_BYTES);
+    }
+}
@@ -1,19 +1,21 @@
-use alloy_primitives::{Address, Bytes, Log, U256};
+use alloy_primitives::{Address, Bytes, Log, U256, map::HashMap};
 use foundry_evm_core::InspectorExt;
 use foundry_evm_traces::{
     CallTraceArena, GethTraceBuilder, ParityTraceBuilder, TracingInspector, TracingInspectorConfig,
+    ZkDebugStep, ZkPubdata, ZkTraceFrame,
 };
 use foundry_zksync_core::{
-    Call,
+    Call, CallType, FarCallOpcode,
     convert::{ConvertH160, ConvertU256},
+    vm::{ZkCallTraces, ZkFrame, ZkNearCall, ZkPubdataUsage, ZkVmStep},
 };
 use revm::{
     Inspector,
     context::{ContextTr, CreateScheme},
     inspector::JournalExt,
     interpreter::{
-        CallInputs, CallOutcome, CreateInputs, CreateOutcome, Gas, InstructionResult, Interpreter,
-        InterpreterResult,
+        CallInputs, CallOutcome, CallScheme, CreateInputs, CreateOutcome, Gas, InstructionResult,
+        Interpreter, InterpreterResult,
     },
 };
 
@@ -21,12 +23,26 @@
 #[derive(Clone, Debug, Default)]
 pub struct TraceCollector {
     inner: TracingInspector,
+    /// Pubdata published by zkEVM calls, keyed by trace node index.
+    zk_pubdata: HashMap<usize, ZkPubdata>,
+    /// Instructions executed by zkEVM calls, keyed by trace node index.
+    zk_steps: HashMap<usize, Vec<ZkDebugStep>>,
+    /// Frames of the full zkEVM call tree, keyed by trace node index.
+    zk_frames: HashMap<usize, ZkTraceFrame>,
+    /// Whether to record the full zkEVM call tree.
+    record_zk_call_tree: bool,
 }
 
 impl TraceCollector {
     /// Returns a new instance for the given config
     pub fn new(config: TracingInspectorConfig) -> Self {
-        Self { inner: TracingInspector::new(config) }
+        Self {
+            inner: TracingInspector::new(config),
+            zk_pubdata: Default::default(),
+            zk_steps: Default::default(),
+            zk_frames: Default::default(),
+            record_zk_call_tree: false,
+        }
     }
 
     /// Returns the inner [`TracingInspector`]
@@ -41,13 +57,22 @@ pub fn inner(&mut self) -> &mut TracingInspector {
     /// Note that this method has no effect on the allocated capacity of the vector.
     #[inline]
     pub fn fuse(&mut self) {
-        self.inner.fuse()
+        self.inner.fuse();
+        self.zk_pubdata.clear();
+        self.zk_steps.clear();
+        self.zk_frames.clear();
     }
 
     /// Resets the inspector to it's initial state of [Self::new].
     #[inline]
     pub fn fused(self) -> Self {
-        Self { inner: self.inner.fused() }
+        Self {
+            inner: self.inner.fused(),
+            zk_pubdata: Default::default(),
+            zk_steps: Default::default(),
+            zk_frames: Default::default(),
+            record_zk_call_tree: self.record_zk_call_tree,
+        }
     }
 
     /// Returns the config of the inspector.
@@ -83,6 +108,46 @@ pub fn into_traces(self) -> CallTraceArena {
         self.inner.into_traces()
     }
 
+    /// Gets a reference to the pubdata published by zkEVM calls, keyed by trace node index.
+    pub const fn zk_pubdata(&self) -> &HashMap<usize, ZkPubdata> {
+        &self.zk_pubdata
+    }
+
+    /// Gets a reference to the instructions executed by zkEVM calls, keyed by trace node index.
+    pub const fn zk_steps(&self) -> &HashMap<usize, Vec<ZkDebugStep>> {
+        &self.zk_steps
+    }
+
+    /// Gets a reference to the frames of the full zkEVM call tree, keyed by trace node index.
+    pub const fn zk_frames(&self) -> &HashMap<usize, ZkTraceFrame> {
+        &self.zk_frames
+    }
+
+    /// Consumes the inspector and returns the recorded call traces along with the pubdata
+    /// published, the instructions executed and the call tree frames of zkEVM calls.
+    #[allow(clippy::type_complexity)]
+    pub fn into_parts(
+        self,
+    ) -> (
+        CallTraceArena,
+        HashMap<usize, ZkPubdata>,
+        HashMap<usize, Vec<ZkDebugStep>>,
+        HashMap<usize, ZkTraceFrame>,
+    ) {
+        (self.inner.into_traces(), self.zk_pubdata, self.zk_steps, self.zk_frames)
+    }
+
+    /// Returns whether the full EraVM call tree is recorded, including the bootloader, account
+    /// and system contract frames that are otherwise hidden, along with near calls.
+    pub const fn records_zk_call_tree(&self) -> bool {
+        self.record_zk_call_tree
+    }
+
+    /// Sets whether the full EraVM call tree is recorded.
+    pub fn set_record_zk_call_tree(&mut self, yes: bool) {
+        self.record_zk_call_tree = yes;
+    }
+
     /// Manually the gas used of the root trace.
     ///
     /// This is useful if the root trace's gasUsed should mirror the actual gas used by the
@@ -98,7 +163,7 @@ pub fn set_transaction_gas_used(&mut self, gas_used: u64) {
     /// type.
     #[inline]
     pub fn with_transaction_gas_used(self, gas_used: u64) -> Self {
-        Self { inner: self.inner.with_transaction_gas_used(gas_used) }
+        Self { inner: self.inner.with_transaction_gas_used(gas_used), ..self }
     }
 
     /// Consumes the Inspector and returns a [ParityTraceBuilder].
@@ -161,152 +226,324 @@ fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
 }
 
 impl InspectorExt for TraceCollector {
+    fn pubdata_zksync(&mut self, pubdata: Box<dyn std::any::Any>) {
+        let pubdata = *pubdata
+            .downcast::<ZkPubdataUsage>()
+            .expect("TraceCollector::pubdata_zksync expected pubdata to be a ZkPubdataUsage");
+
+        // The zkEVM call is intercepted after the EVM call span was started, so it's the last
+        // recorded node.
+        let Some(idx) = self.inner.traces().nodes().len().checked_sub(1) else { return };
+        let entry = self.zk_pubdata.entry(idx).or_default();
+        entry.bytes = entry.bytes.saturating_add(pubdata.published);
+        entry.l1_cost = entry.l1_cost.saturating_add(pubdata.l1_cost());
+    }
+
+    fn zksync_record_steps(&self) -> bool {
+        // Only the debugger records every step along with memory snapshots.
+        let config = self.config();
+        config.record_steps
+            && config.record_memory_snapshots
+            && config.record_opcodes_filter.is_none()
+    }
+
+    fn steps_zksync(&mut self, steps: Box<dyn std::any::Any>) {
+        let steps = *steps
+            .downcast::<Vec<ZkVmStep>>()
+            .expect("TraceCollector::steps_zksync expected steps to be a Vec<ZkVmStep>");
+
+        // Same as for pubdata, the intercepted EVM call is the last recorded node.
+        let Some(idx) = self.inner.traces().nodes().len().checked_sub(1) else { return };
+        self.zk_steps.entry(idx).or_default().extend(steps.into_iter().map(|step| ZkDebugStep {
+            depth: step.depth as u64,
+            contract: step.contract,
+            pc: step.pc as usize,
+            opcode: step.opcode,
+            registers: step.registers,
+            stack: step.stack,
+            heap: step.heap,
+            aux_heap: step.aux_heap,
+            gas_remaining: step.ergs_remaining as u64,
+        }));
+    }
+
+    fn zksync_record_call_tree(&self) -> bool {
+        self.record_zk_call_tree
+    }
+
     fn trace_zksync(
         &mut self,
         context: foundry_evm_core::Ecx,
         call_traces: Box<dyn std::any::Any>,
         record_top_call: bool,
     ) {
-        let call_traces = *call_traces
-            .downcast::<Vec<Call>>()
-            .expect("TraceCollector::trace_zksync expected call traces to be a Vec<Call>");
-
-        fn trace_call_recursive(
-            tracer: &mut TracingInspector,
-            context: foundry_evm_core::Ecx,
-            call: Call,
-            suppressed_top_call: bool,
-        ) -> u64 {
-            let inputs = &mut CallInputs {
-                input: revm::interpreter::CallInput::Bytes(call.input.into()),
-                gas_limit: call.gas,
-                scheme: revm::interpreter::CallScheme::Call,
-                caller: call.from.to_address(),
-                value: revm::interpreter::CallValue::Transfer(call.value.to_ru256()),
-                target_address: call.to.to_address(),
-                bytecode_address: call.to.to_address(),
-                is_static: false,
-                return_memory_offset: Default::default(),
-                known_bytecode: None,
-            };
-            let is_first_non_system_call = if !suppressed_top_call {
-                !foundry_zksync_core::is_system_address(inputs.caller)
-                    && !foundry_zksync_core::is_system_address(inputs.target_address)
-            } else {
-                false
-            };
-
-            // We ignore traces from system addresses, the default account abstraction calls on
-            // caller address, and the original call (identified when neither `to` or
-            // `from` are system addresses) since it is already included in EVM trace.
-            let record_trace = !is_first_non_system_call
-                && !foundry_zksync_core::is_system_address(inputs.target_address)
-                && inputs.target_address != context.tx.caller;
-
-            let mut outcome = if let Some(reason) = &call.revert_reason {
-                CallOutcome {
-                    result: InterpreterResult {
-                        result: InstructionResult::Revert,
-                        output: reason.as_bytes().to_owned().into(),
-                        gas: Gas::new_spent(call.gas_used),
-                    },
-                    memory_offset: Default::default(),
-                    was_precompile_called: false,
-                    precompile_call_logs: vec![],
-                }
-            } else {
-                CallOutcome {
+        let ZkCallTraces { calls, frames } = *call_traces
+            .downcast::<ZkCallTraces>()
+            .expect("TraceCollector::trace_zksync expected call traces to be a ZkCallTraces");
+        let full_call_tree = self.records_zk_call_tree();
+        // Frames are recorded in the same depth-first order the calls are traversed in.
+        let mut frames = frames.into_iter();
+
+        struct ZkTraceContext<'a, I> {
+            tracer: &'a mut TracingInspector,
+            zk_frames: &'a mut HashMap<usize, ZkTraceFrame>,
+            frames: &'a mut I,
+            full_call_tree: bool,
+        }
+
+        impl<I: Iterator<Item = ZkFrame>> ZkTraceContext<'_, I> {
+            /// Returns the index of the last recorded trace node.
+            fn last_node(&self) -> usize {
+                self.tracer.traces().nodes().len().saturating_sub(1)
+            }
+
+            /// Starts the span of a near call made by `address`.
+            fn start_near_call(
+                &mut self,
+                context: foundry_evm_core::Ecx,
+                address: Address,
+                near_call: &ZkNearCall,
+            ) -> CallInputs {
+                let mut inputs = CallInputs {
+                    input: revm::interpreter::CallInput::Bytes(Default::default()),
+                    gas_limit: near_call.gas,
+                    scheme: CallScheme::Call,
+                    caller: address,
+                    value: revm::interpreter::CallValue::Transfer(U256::ZERO),
+                    target_address: address,
+                    bytecode_address: address,
+                    is_static: false,
+                    return_memory_offset: Default::default(),
+                    known_bytecode: None,
+                };
+                self.tracer.call(context, &mut inputs);
+                self.zk_frames
+                    .insert(self.last_node(), ZkTraceFrame { near_call: true, pubdata: 0 });
+                context.journaled_state.depth = context.journaled_state.depth.saturating_add(1);
+                inputs
+            }
+
+            /// Ends the span of a near call started with [Self::start_near_call].
+            fn end_near_call(
+                &mut self,
+                context: foundry_evm_core::Ecx,
+                inputs: &CallInputs,
+                near_call: &ZkNearCall,
+            ) {
+                context.journaled_state.depth = context.journaled_state.depth.saturating_sub(1);
+                let mut outcome = CallOutcome {
                     result: InterpreterResult {
-                        result: InstructionResult::Return,
-                        output: call.output.clone().into(),
-                        gas: Gas::new_spent(call.gas_used),
+                        result: if near_call.reverted {
+                            InstructionResult::Revert
+                        } else {
+                            InstructionResult::Return
+                        },
+                        output: Default::default(),
+                        gas: Gas::new_spent(near_call.gas_used),
                     },
                     memory_offset: Default::default(),
                     was_precompile_called: false,
                     precompile_call_logs: vec![],
+                };
+                self.tracer.call_end(context, inputs, &mut outcome);
+            }
+
+            fn trace_call_recursive(
+                &mut self,
+                context: foundry_evm_core::Ecx,
+                mut call: Call,
+                suppressed_top_call: bool,
+            ) -> u64 {
+                let full_call_tree = self.full_call_tree;
+                let frame = self.frames.next().unwrap_or_default();
+                let scheme = match call.r#type {
+                    CallType::Call(FarCallOpcode::Delegate) => CallScheme::DelegateCall,
+                    _ => CallScheme::Call,
+                };
+                // Halts carry no revert reason, only an error.
+                if full_call_tree && call.revert_reason.is_none() {
+                    call.revert_reason = call.error.take();
                 }
-            };
-
-            let is_create = matches!(call.r#type, foundry_zksync_core::CallType::Create);
-            let mut create_inputs = if is_create {
-                Some(CreateInputs::new(
-                    inputs.caller,
-                    CreateScheme::Create,
-                    inputs.value.get(),
-                    inputs.input.bytes(context),
-                    inputs.gas_limit,
-                ))
-            } else {
-                None
-            };
-
-            // start span
-            if record_trace {
-                if let Some(inputs) = &mut create_inputs {
-                    tracer.create(context, inputs);
+
+                let inputs = &mut CallInputs {
+                    input: revm::interpreter::CallInput::Bytes(call.input.into()),
+                    gas_limit: call.gas,
+                    scheme,
+                    caller: call.from.to_address(),
+                    value: revm::interpreter::CallValue::Transfer(call.value.to_ru256()),
+                    target_address: call.to.to_address(),
+                    bytecode_address: call.to.to_address(),
+                    is_static: false,
+                    return_memory_offset: Default::default(),
+                    known_bytecode: None,
+                };
+                let is_first_non_system_call = if !suppressed_top_call {
+                    !foundry_zksync_core::is_system_address(inputs.caller)
+                        && !foundry_zksync_core::is_system_address(inputs.target_address)
                 } else {
-                    tracer.call(context, inputs);
-                }
-            }
+                    false
+                };
+
+                // We ignore traces from system addresses, the default account abstraction calls on
+                // caller address, and the original call (identified when neither `to` or
+                // `from` are system addresses) since it is already included in EVM trace.
+                // Only the original call is ignored when recording the full call tree.
+                let record_trace = if full_call_tree {
+                    !is_first_non_system_call
+                } else {
+                    !is_first_non_system_call
+                        && !foundry_zksync_core::is_system_address(inputs.target_address)
+                        && inputs.target_address != context.tx.caller
+                };
+
+                let mut outcome = if let Some(reason) = &call.revert_reason {
+                    CallOutcome {
+                        result: InterpreterResult {
+                            result: InstructionResult::Revert,
+                            output: reason.as_bytes().to_owned().into(),
+                            gas: Gas::new_spent(call.gas_used),
+                        },
+                        memory_offset: Default::default(),
+                        was_precompile_called: false,
+                        precompile_call_logs: vec![],
+                    }
+                } else {
+                    CallOutcome {
+                        result: InterpreterResult {
+                            result: InstructionResult::Return,
+                            output: call.output.clone().into(),
+                            gas: Gas::new_spent(call.gas_used),
+                        },
+                        memory_offset: Default::default(),
+                        was_precompile_called: false,
+                        precompile_call_logs: vec![],
+                    }
+                };
+
+                let is_create = matches!(call.r#type, CallType::Create);
+                let mut create_inputs = if is_create {
+                    Some(CreateInputs::new(
+                        inputs.caller,
+                        CreateScheme::Create,
+                        inputs.value.get(),
+                        inputs.input.bytes(context),
+                        inputs.gas_limit,
+                    ))
+                } else {
+                    None
+                };
 
-            // We increment the depth for inner calls as normally traces are processed
-            // during execution, where the environment takes care of updating the context
-            let (new_depth, overflow) = context.journaled_state.depth.overflowing_add(1);
-            if !overflow && record_trace {
-                context.journaled_state.depth = new_depth;
-            }
+                // start span
+                if record_trace {
+                    if let Some(inputs) = &mut create_inputs {
+                        self.tracer.create(context, inputs);
+                    } else {
+                        self.tracer.call(context, inputs);
+                    }
 
-            // recurse into inner calls
-            // record extra gas from ignored traces, to add it at end
-            let mut extra_gas = if record_trace { 0u64 } else { call.gas_used };
-            for inner_call in call.calls {
-                let inner_extra_gas = trace_call_recursive(
-                    tracer,
-                    context,
-                    inner_call,
-                    suppressed_top_call || is_first_non_system_call,
-                );
-                extra_gas = extra_gas.saturating_add(inner_extra_gas);
-            }
+                    if full_call_tree {
+                        let near_call = matches!(call.r#type, CallType::NearCall);
+                        if near_call || frame.pubdata > 0 {
+                            self.zk_frames.insert(
+                                self.last_node(),
+                                ZkTraceFrame { near_call, pubdata: frame.pubdata },
+                            );
+                        }
+                    }
+                }
 
-            // We then decrement the call depth so `call_end`/`create_end` has the correct context
-            if !overflow && record_trace {
-                context.journaled_state.depth = context.journaled_state.depth.saturating_sub(1);
-            }
+                // We increment the depth for inner calls as normally traces are processed
+                // during execution, where the environment takes care of updating the context
+                let (new_depth, overflow) = context.journaled_state.depth.overflowing_add(1);
+                if !overflow && record_trace {
+                    context.journaled_state.depth = new_depth;
+                }
 
-            // finish span
-            if record_trace {
-                if let Some(inputs) = &mut create_inputs {
-                    let mut outcome = if let Some(reason) = call.revert_reason {
-                        CreateOutcome {
-                            result: InterpreterResult {
-                                result: InstructionResult::Revert,
-                                output: reason.as_bytes().to_owned().into(),
-                                gas: Gas::new_spent(call.gas_used + extra_gas),
-                            },
-                            address: None,
+                // recurse into inner calls, within the spans of the near calls that made them
+                // record extra gas from ignored traces, to add it at end, unless every frame
+                // reports its own gas in the full call tree
+                let mut extra_gas =
+                    if record_trace || full_call_tree { 0u64 } else { call.gas_used };
+                let address = call.to.to_address();
+                let inner_calls = call.calls;
+                let total_inner_calls = inner_calls.len();
+                let mut inner_calls = inner_calls.into_iter();
+                let mut near_calls =
+                    if full_call_tree { frame.near_calls } else { vec![] }.into_iter().peekable();
+                let mut open_near_calls: Vec<(ZkNearCall, CallInputs)> = vec![];
+                for idx in 0..=total_inner_calls {
+                    // A near call ends before another one at the same or a lower depth starts.
+                    while let Some(near_call) = near_calls.next_if(|near| near.first_call == idx) {
+                        while let Some((open, inputs)) =
+                            open_near_calls.pop_if(|(open, _)| open.depth >= near_call.depth)
+                        {
+                            self.end_near_call(context, &inputs, &open);
                         }
+                        let inputs = self.start_near_call(context, address, &near_call);
+                        open_near_calls.push((near_call, inputs));
+                    }
+
+                    let Some(inner_call) = inner_calls.next() else { break };
+
+                    // Near calls that don't include the next far call end before it.
+                    while let Some((open, inputs)) =
+                        open_near_calls.pop_if(|(open, _)| open.first_call + open.calls <= idx)
+                    {
+                        self.end_near_call(context, &inputs, &open);
+                    }
+
+                    let inner_extra_gas = self.trace_call_recursive(
+                        context,
+                        inner_call,
+                        suppressed_top_call || is_first_non_system_call,
+                    );
+                    extra_gas = extra_gas.saturating_add(inner_extra_gas);
+                }
+                while let Some((open, inputs)) = open_near_calls.pop() {
+                    self.end_near_call(context, &inputs, &open);
+                }
+
+                // We then decrement the call depth so `call_end`/`create_end` has the correct
+                // context
+                if !overflow && record_trace {
+                    context.journaled_state.depth = context.journaled_state.depth.saturating_sub(1);
+                }
+
+                // finish span
+                if record_trace {
+                    if let Some(inputs) = &mut create_inputs {
+                        let mut outcome = if let Some(reason) = call.revert_reason {
+                            CreateOutcome {
+                                result: InterpreterResult {
+                                    result: InstructionResult::Revert,
+                                    output: reason.as_bytes().to_owned().into(),
+                                    gas: Gas::new_spent(call.gas_used + extra_gas),
+                                },
+                                address: None,
+                            }
+                        } else {
+                            CreateOutcome {
+                                result: InterpreterResult {
+                                    result: InstructionResult::Return,
+                                    output: Bytes::from(call.output),
+                                    gas: Gas::new_spent(call.gas_used + extra_gas),
+                                },
+                                address: Some(call.to.to_address()),
+                            }
+                        };
+
+                        self.tracer.create_end(context, inputs, &mut outcome);
                     } else {
-                        CreateOutcome {
-                            result: InterpreterResult {
-                                result: InstructionResult::Return,
-                                output: Bytes::from(call.output),
-                                gas: Gas::new_spent(call.gas_used + extra_gas),
-                            },
-                            address: Some(call.to.to_address()),
+                        if extra_gas != 0 {
+                            outcome.result.gas =
+                                Gas::new_spent(outcome.result.gas.spent() + extra_gas);
                         }
-                    };
-
-                    tracer.create_end(context, inputs, &mut outcome);
-                } else {
-                    if extra_gas != 0 {
-                        outcome.result.gas = Gas::new_spent(outcome.result.gas.spent() + extra_gas);
+                        self.tracer.call_end(context, inputs, &mut outcome);
                     }
-                    tracer.call_end(context, inputs, &mut outcome);
                 }
-            }
 
-            extra_gas
+                extra_gas
+            }
         }
 
         let (new_depth, overflow) = context.journaled_state.depth.overflowing_add(1);
@@ -315,8 +552,14 @@ fn trace_call_recursive(
             context.journaled_state.depth = new_depth;
         }
 
-        for call in call_traces {
-            trace_call_recursive(&mut self.inner, context, call, record_top_call);
+        let mut ctx = ZkTraceContext {
+            tracer: &mut self.inner,
+            zk_frames: &mut self.zk_frames,
+            frames: &mut frames,
+            full_call_tree,
+        };
+        for call in calls {
+            ctx.trace_call_recursive(context, call, record_top_call);
         }
 
         if !overflow && !record_top_call {
@@ -505,3 +505,107 @@ contract ZkCheatcodeZkVmSkipTest is Test {
         helper2.exec();
     }
 }
+
+contract ZkCheatcodesFeeTest is Test {
+    function testZkCheatcodesExecuteWithCustomFees() public {
+        FixedSlot fs = new FixedSlot();
+        uint256 defaultFee = chargedFee(fs, 10);
+
+        vm.zkSetL1GasPrice(100 gwei);
+        vm.zkSetFairL2GasPrice(1 gwei);
+        vm.zkSetPubdataPrice(50 gwei);
+        uint256 customFee = chargedFee(fs, 20);
+
+        // the fair L2 gas price raises the base fee, which the transaction gas price must cover
+        assertGt(defaultFee, 0, "no fee charged with the default prices");
+        assertGt(customFee, defaultFee, "custom prices did not raise the charged fee");
+    }
+
+    /// Returns the fee charged to the transaction initiator for updating the slot.
+    function chargedFee(FixedSlot fs, uint8 value) internal returns (uint256) {
+        uint256 balance = tx.origin.balance;
+        fs.setSlot0(value);
+        return balance - tx.origin.balance;
+    }
+
+    function testRevertWhen_ZkCheatcodesSetZeroPubdataPrice() public {
+        vm._expectCheatcodeRevert("pubdata price must not be zero");
+        vm.zkSetPubdataPrice(0);
+    }
+}
+
+contract ImmutableHolder {
+    uint256 public immutable value;
+
+    constructor(uint256 _value) {
+        value = _value;
+    }
+}
+
+contract ZkCheatcodesImmutableTest is Test {
+    function testZkCheatcodesGetAndSetImmutable() public {
+        ImmutableHolder holder = new ImmutableHolder(42);
+
+        // The immutable is stored by the ImmutableSimulator, not in the contract storage.
+        assertEq(vm.load(address(holder), bytes32(0)), bytes32(0));
+
+        uint256 index = type(uint256).max;
+        for (uint256 i = 0; i < 1024; i++) {
+            if (vm.zkGetImmutable(address(holder), i) == bytes32(uint256(42))) {
+                index = i;
+                break;
+            }
+        }
+        assertNotEq(index, type(uint256).max, "immutable not found");
+
+        vm.zkSetImmutable(address(holder), index, bytes32(uint256(1337)));
+        assertEq(vm.zkGetImmutable(address(holder), index), bytes32(uint256(1337)));
+        assertEq(holder.value(), 1337);
+    }
+}
+
+contract L1BridgeReceiver {
+    uint160 constant ALIAS_OFFSET = uint160(0x1111000000000000000000000000000000001111);
+
+    address public immutable l1Bridge;
+    uint256 public received;
+
+    constructor(address _l1Bridge) {
+        l1Bridge = _l1Bridge;
+    }
+
+    function finalizeDeposit(uint256 amount) external payable returns (uint256) {
+        unchecked {
+            require(msg.sender == address(uint160(l1Bridge) + ALIAS_OFFSET), "only L1 bridge");
+        }
+        received += amount;
+        return received;
+    }
+}
+
+contract ZkCheatcodesL1TxTest is Test {
+    address constant L1_BRIDGE = address(0xB41D6E);
+
+    function testZkCheatcodesExecuteL1Tx() public {
+        L1BridgeReceiver receiver = new L1BridgeReceiver(L1_BRIDGE);
+
+        (bool success, bytes memory returnData) = vm.zkExecuteL1Tx(
+            L1_BRIDGE, address(receiver), abi.encodeCall(receiver.finalizeDeposit, (100)), 1 ether, 10_000_000
+        );
+        assertTrue(success);
+        assertEq(abi.decode(returnData, (uint256)), 100);
+        assertEq(receiver.received(), 100);
+        assertEq(address(receiver).balance, 1 ether);
+    }
+
+    function testZkCheatcodesExecuteL1TxFromUnknownSender() public {
+        L1BridgeReceiver receiver = new L1BridgeReceiver(L1_BRIDGE);
+
+        (bool success, bytes memory returnData) = vm.zkExecuteL1Tx(
+            address(0xBAD), address(receiver), abi.encodeCall(receiver.finalizeDeposit, (100)), 0, 10_000_000
+        );
+        assertFalse(success);
+        assertEq(returnData, abi.encodeWithSignature("Error(string)", "only L1 bridge"));
+        assertEq(receiver.received(), 0);
+    }
+}
@@ -567,9 +567,19 @@ interface Vm {
     function writeLine(string calldata path, string calldata data) external;
     function writeToml(string calldata json, string calldata path) external;
     function writeToml(string calldata json, string calldata path, string calldata valueKey) external;
+    function zkExecuteAsAccount(address account, bytes calldata signature) external pure;
+    function zkExecuteL1Tx(address l1Sender, address to, bytes calldata data, uint256 value, uint256 l2GasLimit) external returns (bool success, bytes memory returnData);
+    function zkExpectHalt(string calldata reason) external;
     function zkGetDeploymentNonce(address account) external view returns (uint64 nonce);
+    function zkGetImmutable(address target, uint256 index) external view returns (bytes32 value);
     function zkGetTransactionNonce(address account) external view returns (uint64 nonce);
+    function zkL1DepositTransaction(string calldata l1UrlOrAlias, string calldata l2UrlOrAlias, address from, address to, uint256 amount) external returns (address target, uint256 value, bytes memory data, uint256 gasPrice);
+    function zkL1FinalizeWithdrawalTransaction(string calldata l2UrlOrAlias, bytes32 txHash, uint256 index) external returns (address target, uint256 value, bytes memory data);
     function zkRegisterContract(string calldata name, bytes32 evmBytecodeHash, bytes calldata evmDeployedBytecode, bytes calldata evmBytecode, bytes32 zkBytecodeHash, bytes calldata zkDeployedBytecode) external pure;
+    function zkSetFairL2GasPrice(uint64 gasPrice) external;
+    function zkSetImmutable(address target, uint256 index, bytes32 value) external;
+    function zkSetL1GasPrice(uint64 gasPrice) external;
+    function zkSetPubdataPrice(uint64 pubdataPrice) external;
     function zkUseFactoryDep(string calldata name) external pure;
     function zkUsePaymaster(address paymaster_address, bytes calldata paymaster_input) external pure;
     function zkVm(bool enable) external pure;